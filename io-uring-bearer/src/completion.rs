@@ -1,6 +1,9 @@
 //! Competion types
 
 use crate::slab::AcceptRec;
+use crate::slab::AsyncCancelRec;
+use crate::slab::CloseFixedRec;
+use crate::slab::FixedFdInstallRec;
 //use crate::slab::EpollRec;
 use crate::slab::FutexWaitRec;
 use crate::slab::ProvideBuffersRec;
@@ -28,38 +31,127 @@ pub enum Completion<C> {
     RecvMulti(RecvMultiRec),
     /// SendZc
     SendZc(SendZcRec),
+    /// AsyncCancel
+    AsyncCancel(AsyncCancelRec),
+    /// Close of a fixed filehandle
+    CloseFixed(CloseFixedRec),
+    /// Install of a fixed filehandle as regular
+    FixedFdInstall(FixedFdInstallRec),
     /// Gen + OpExtConnect impl
     #[cfg(feature = "accept_multi")]
     AcceptMulti(C),
+    /// Gen + OpExtBindListen impl
+    #[cfg(feature = "bind_listen")]
+    BindListen(C),
     /// Gen + OpExtConnect impl
     #[cfg(feature = "connect")]
     Connect(C),
     /// Gen + OpExtEpollCtl impl
     #[cfg(feature = "epoll")]
     EpollCtl(C),
+    /// Gen + OpExtFs impl
+    #[cfg(feature = "fs")]
+    Fs(C),
+    /// Gen + OpExtPath impl
+    #[cfg(feature = "path")]
+    Path(C),
+    /// Gen + OpExtRw impl
+    #[cfg(feature = "rw")]
+    Rw(C),
+    /// Gen + OpExtSend impl
+    #[cfg(feature = "send")]
+    Send(C),
     /// Gen + OpExtSocket impl
     #[cfg(feature = "socket")]
     Socket(C),
+    /// Gen + OpExtSockOpt impl
+    #[cfg(feature = "sockopt")]
+    SockOpt(C),
+    /// Gen + OpExtSplice impl
+    #[cfg(feature = "splice")]
+    Splice(C),
+    /// Gen + OpExtTimeout impl
+    #[cfg(feature = "timeout")]
+    Timeout(C),
     /// Gen Trait impl
     Op(C),
 }
 
 impl<C: OpCompletion> Completion<C> {
+    /// OpCode of the submission, None for the records pushed only through their dedicated
+    /// push methods
+    #[inline]
+    pub(crate) fn opcode(&self) -> Option<u8> {
+        use io_uring::opcode;
+        match self {
+            Completion::Recv(_) => Some(opcode::Recv::CODE),
+            Completion::RecvMulti(_) => Some(opcode::RecvMulti::CODE),
+            Completion::SendZc(_) => Some(opcode::SendZc::CODE),
+            Completion::AsyncCancel(_) => Some(opcode::AsyncCancel::CODE),
+            Completion::CloseFixed(_) => Some(opcode::Close::CODE),
+            Completion::FixedFdInstall(_) => Some(opcode::FixedFdInstall::CODE),
+            Completion::Op(r) => Some(r.opcode()),
+            #[cfg(feature = "accept_multi")]
+            Completion::AcceptMulti(r) => Some(r.opcode()),
+            #[cfg(feature = "bind_listen")]
+            Completion::BindListen(r) => Some(r.opcode()),
+            #[cfg(feature = "connect")]
+            Completion::Connect(r) => Some(r.opcode()),
+            #[cfg(feature = "epoll")]
+            Completion::EpollCtl(r) => Some(r.opcode()),
+            #[cfg(feature = "fs")]
+            Completion::Fs(r) => Some(r.opcode()),
+            #[cfg(feature = "path")]
+            Completion::Path(r) => Some(r.opcode()),
+            #[cfg(feature = "rw")]
+            Completion::Rw(r) => Some(r.opcode()),
+            #[cfg(feature = "send")]
+            Completion::Send(r) => Some(r.opcode()),
+            #[cfg(feature = "socket")]
+            Completion::Socket(r) => Some(r.opcode()),
+            #[cfg(feature = "sockopt")]
+            Completion::SockOpt(r) => Some(r.opcode()),
+            #[cfg(feature = "splice")]
+            Completion::Splice(r) => Some(r.opcode()),
+            #[cfg(feature = "timeout")]
+            Completion::Timeout(r) => Some(r.opcode()),
+            _ => None,
+        }
+    }
     #[inline]
     pub(crate) fn entry(&self) -> io_uring::squeue::Entry {
         match self {
             Completion::Recv(r) => r.entry(),
             Completion::RecvMulti(r) => r.entry(),
             Completion::SendZc(r) => r.entry(),
+            Completion::AsyncCancel(r) => r.entry(),
+            Completion::CloseFixed(r) => r.entry(),
+            Completion::FixedFdInstall(r) => r.entry(),
             Completion::Op(r) => r.entry(),
             #[cfg(feature = "accept_multi")]
             Completion::AcceptMulti(r) => r.entry(),
+            #[cfg(feature = "bind_listen")]
+            Completion::BindListen(r) => r.entry(),
             #[cfg(feature = "connect")]
             Completion::Connect(r) => r.entry(),
             #[cfg(feature = "epoll")]
             Completion::EpollCtl(r) => r.entry(),
+            #[cfg(feature = "fs")]
+            Completion::Fs(r) => r.entry(),
+            #[cfg(feature = "path")]
+            Completion::Path(r) => r.entry(),
+            #[cfg(feature = "rw")]
+            Completion::Rw(r) => r.entry(),
+            #[cfg(feature = "send")]
+            Completion::Send(r) => r.entry(),
             #[cfg(feature = "socket")]
             Completion::Socket(r) => r.entry(),
+            #[cfg(feature = "sockopt")]
+            Completion::SockOpt(r) => r.entry(),
+            #[cfg(feature = "splice")]
+            Completion::Splice(r) => r.entry(),
+            #[cfg(feature = "timeout")]
+            Completion::Timeout(r) => r.entry(),
             _ => todo!(),
         }
     }
@@ -69,15 +161,34 @@ impl<C: OpCompletion> Completion<C> {
             Self::Recv(ref recv) => recv.owner(),
             Self::RecvMulti(ref recv_multi) => recv_multi.owner(),
             Self::SendZc(ref send_zc) => send_zc.owner(),
+            Self::AsyncCancel(ref cancel) => cancel.owner(),
+            Self::CloseFixed(ref close) => close.owner(),
+            Self::FixedFdInstall(ref install) => install.owner(),
             Self::Op(ref impl_op) => impl_op.owner(),
             #[cfg(feature = "accept_multi")]
             Self::AcceptMulti(ref impl_op) => impl_op.owner(),
+            #[cfg(feature = "bind_listen")]
+            Self::BindListen(ref impl_op) => impl_op.owner(),
             #[cfg(feature = "connect")]
             Self::Connect(ref impl_op) => impl_op.owner(),
             #[cfg(feature = "epoll")]
             Self::EpollCtl(ref impl_op) => impl_op.owner(),
+            #[cfg(feature = "fs")]
+            Self::Fs(ref impl_op) => impl_op.owner(),
+            #[cfg(feature = "path")]
+            Self::Path(ref impl_op) => impl_op.owner(),
+            #[cfg(feature = "rw")]
+            Self::Rw(ref impl_op) => impl_op.owner(),
+            #[cfg(feature = "send")]
+            Self::Send(ref impl_op) => impl_op.owner(),
             #[cfg(feature = "socket")]
             Self::Socket(ref impl_op) => impl_op.owner(),
+            #[cfg(feature = "sockopt")]
+            Self::SockOpt(ref impl_op) => impl_op.owner(),
+            #[cfg(feature = "splice")]
+            Self::Splice(ref impl_op) => impl_op.owner(),
+            #[cfg(feature = "timeout")]
+            Self::Timeout(ref impl_op) => impl_op.owner(),
             _ => todo!(),
         }
     }
@@ -87,20 +198,178 @@ impl<C: OpCompletion> Completion<C> {
             Self::Recv(ref mut recv) => recv.force_owner_kernel(),
             Self::RecvMulti(ref mut recv_multi) => recv_multi.force_owner_kernel(),
             Self::SendZc(ref mut send_zc) => send_zc.force_owner_kernel(),
+            Self::AsyncCancel(ref mut cancel) => cancel.force_owner_kernel(),
+            Self::CloseFixed(ref mut close) => close.force_owner_kernel(),
+            Self::FixedFdInstall(ref mut install) => install.force_owner_kernel(),
             Self::Op(ref mut impl_op) => impl_op.force_owner_kernel(),
             #[cfg(feature = "accept_multi")]
             Self::AcceptMulti(ref mut impl_op) => impl_op.force_owner_kernel(),
+            #[cfg(feature = "bind_listen")]
+            Self::BindListen(ref mut impl_op) => impl_op.force_owner_kernel(),
             #[cfg(feature = "connect")]
             Self::Connect(ref mut impl_op) => impl_op.force_owner_kernel(),
             #[cfg(feature = "epoll")]
             Self::EpollCtl(ref mut impl_op) => impl_op.force_owner_kernel(),
+            #[cfg(feature = "fs")]
+            Self::Fs(ref mut impl_op) => impl_op.force_owner_kernel(),
+            #[cfg(feature = "path")]
+            Self::Path(ref mut impl_op) => impl_op.force_owner_kernel(),
+            #[cfg(feature = "rw")]
+            Self::Rw(ref mut impl_op) => impl_op.force_owner_kernel(),
+            #[cfg(feature = "send")]
+            Self::Send(ref mut impl_op) => impl_op.force_owner_kernel(),
             #[cfg(feature = "socket")]
             Self::Socket(ref mut impl_op) => impl_op.force_owner_kernel(),
+            #[cfg(feature = "sockopt")]
+            Self::SockOpt(ref mut impl_op) => impl_op.force_owner_kernel(),
+            #[cfg(feature = "splice")]
+            Self::Splice(ref mut impl_op) => impl_op.force_owner_kernel(),
+            #[cfg(feature = "timeout")]
+            Self::Timeout(ref mut impl_op) => impl_op.force_owner_kernel(),
             _ => todo!(),
         }
     }
+    /// Whether the record installs a filehandle upon its successful completion
+    #[inline]
+    pub(crate) fn installs_fd(&self) -> bool {
+        use io_uring::opcode;
+        match self {
+            Completion::Accept(_) => true,
+            _ => matches!(
+                self.opcode(),
+                Some(
+                    opcode::Accept::CODE
+                        | opcode::Socket::CODE
+                        | opcode::OpenAt::CODE
+                        | opcode::OpenAt2::CODE
+                        | opcode::FixedFdInstall::CODE
+                )
+            ),
+        }
+    }
+    /// The fixed filehandle targeted by the record if any
+    #[inline]
+    pub(crate) fn fixed_fd(&self) -> Option<u32> {
+        match self {
+            Self::Recv(ref recv) => Some(recv.fixed_fd()),
+            Self::RecvMulti(ref recv_multi) => Some(recv_multi.fixed_fd()),
+            Self::SendZc(ref send_zc) => Some(send_zc.fixed_fd()),
+            Self::CloseFixed(ref close) => Some(close.slot()),
+            Self::FixedFdInstall(ref install) => Some(install.slot()),
+            Self::Op(ref impl_op) => impl_op.fixed_fd(),
+            #[cfg(feature = "accept_multi")]
+            Self::AcceptMulti(ref impl_op) => impl_op.fixed_fd(),
+            #[cfg(feature = "bind_listen")]
+            Self::BindListen(ref impl_op) => impl_op.fixed_fd(),
+            #[cfg(feature = "connect")]
+            Self::Connect(ref impl_op) => impl_op.fixed_fd(),
+            #[cfg(feature = "epoll")]
+            Self::EpollCtl(ref impl_op) => impl_op.fixed_fd(),
+            #[cfg(feature = "fs")]
+            Self::Fs(ref impl_op) => impl_op.fixed_fd(),
+            #[cfg(feature = "path")]
+            Self::Path(ref impl_op) => impl_op.fixed_fd(),
+            #[cfg(feature = "rw")]
+            Self::Rw(ref impl_op) => impl_op.fixed_fd(),
+            #[cfg(feature = "send")]
+            Self::Send(ref impl_op) => impl_op.fixed_fd(),
+            #[cfg(feature = "socket")]
+            Self::Socket(ref impl_op) => impl_op.fixed_fd(),
+            #[cfg(feature = "sockopt")]
+            Self::SockOpt(ref impl_op) => impl_op.fixed_fd(),
+            #[cfg(feature = "splice")]
+            Self::Splice(ref impl_op) => impl_op.fixed_fd(),
+            #[cfg(feature = "timeout")]
+            Self::Timeout(ref impl_op) => impl_op.fixed_fd(),
+            _ => None,
+        }
+    }
+    /// Whether the record may post more than one completion (IORING_CQE_F_MORE)
+    #[inline]
+    pub(crate) fn is_multishot(&self) -> bool {
+        match self {
+            Self::RecvMulti(_) => true,
+            Self::Op(ref impl_op) => impl_op.is_multishot(),
+            #[cfg(feature = "accept_multi")]
+            Self::AcceptMulti(_) => true,
+            #[cfg(feature = "timeout")]
+            Self::Timeout(ref impl_op) => impl_op.is_multishot(),
+            _ => false,
+        }
+    }
+    /// Indices of the bearer managed buffers the record refers to, returned upon the final completion
+    #[inline]
+    pub(crate) fn buf_idxs(&self) -> &[usize] {
+        match self {
+            Self::Recv(ref recv) => recv.buf_idxs(),
+            Self::SendZc(ref send_zc) => send_zc.buf_idxs(),
+            Self::Op(ref impl_op) => impl_op.buf_idxs(),
+            #[cfg(feature = "rw")]
+            Self::Rw(ref impl_op) => impl_op.buf_idxs(),
+            #[cfg(feature = "send")]
+            Self::Send(ref impl_op) => impl_op.buf_idxs(),
+            _ => &[],
+        }
+    }
+    /// Decode the completion queue entry through the OpCompletion impl of the
+    /// original submission record. Records native to the bearer return None.
+    #[inline]
+    pub(crate) fn completion(
+        &mut self,
+        cqe: &io_uring::cqueue::Entry,
+    ) -> Option<Result<C::Output, C::Error>> {
+        match self {
+            Self::SendZc(ref mut send_zc) => {
+                send_zc.completion(cqe);
+                None
+            }
+            Self::CloseFixed(ref mut close) => {
+                close.completion(cqe);
+                None
+            }
+            Self::FixedFdInstall(ref mut install) => {
+                install.completion(cqe);
+                None
+            }
+            Self::RecvMulti(ref mut recv_multi) => {
+                recv_multi.completion(cqe);
+                None
+            }
+            Self::Op(ref mut impl_op) => Some(impl_op.completion(cqe)),
+            #[cfg(feature = "accept_multi")]
+            Self::AcceptMulti(ref mut impl_op) => Some(impl_op.completion(cqe)),
+            #[cfg(feature = "bind_listen")]
+            Self::BindListen(ref mut impl_op) => Some(impl_op.completion(cqe)),
+            #[cfg(feature = "connect")]
+            Self::Connect(ref mut impl_op) => Some(impl_op.completion(cqe)),
+            #[cfg(feature = "epoll")]
+            Self::EpollCtl(ref mut impl_op) => Some(impl_op.completion(cqe)),
+            #[cfg(feature = "fs")]
+            Self::Fs(ref mut impl_op) => Some(impl_op.completion(cqe)),
+            #[cfg(feature = "path")]
+            Self::Path(ref mut impl_op) => Some(impl_op.completion(cqe)),
+            #[cfg(feature = "rw")]
+            Self::Rw(ref mut impl_op) => Some(impl_op.completion(cqe)),
+            #[cfg(feature = "send")]
+            Self::Send(ref mut impl_op) => Some(impl_op.completion(cqe)),
+            #[cfg(feature = "socket")]
+            Self::Socket(ref mut impl_op) => Some(impl_op.completion(cqe)),
+            #[cfg(feature = "sockopt")]
+            Self::SockOpt(ref mut impl_op) => Some(impl_op.completion(cqe)),
+            #[cfg(feature = "splice")]
+            Self::Splice(ref mut impl_op) => Some(impl_op.completion(cqe)),
+            #[cfg(feature = "timeout")]
+            Self::Timeout(ref mut impl_op) => Some(impl_op.completion(cqe)),
+            _ => None,
+        }
+    }
 }
 
+/// Typed completion handed to the typed completion handlers.
+/// Records native to the bearer carry no typed result and the raw entry should be consulted.
+pub type OpCompletionResult<C> =
+    Option<Result<<C as OpCompletion>::Output, <C as OpCompletion>::Error>>;
+
 /// What to do with the submission record upon handling completion.
 /// Used within handle_completions Fn Return                       
 #[derive(Clone, Debug, PartialEq)]
@@ -112,5 +381,103 @@ pub enum SubmissionRecordStatus {
     /// Forget the associated submission record                                          
     /// For example Accept original record can be deleted upon compleiton after read.    
     /// Typically a new Accept submission is pushed without re-using any existing.       
+    /// When the kernel has more completions to post (IORING_CQE_F_MORE) the record is
+    /// forgotten upon the final one, e.g. SendZc after the notification.
     Forget,
 }
+
+/// Where the submission stands in its lifecycle upon the handled completion.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum MultishotStatus {
+    /// Single-shot submission completed
+    Single,
+    /// Multishot submission remains armed with more completions to follow (IORING_CQE_F_MORE)
+    More,
+    /// Multishot submission terminated and its record is released, re-arm is needed
+    Terminated,
+    /// Multishot submission terminated and is re-armed on the same key as per its RearmPolicy
+    /// unless the handler returns Forget. It is pushed again once the spin is over, should
+    /// that fail the record is released and the error returned by the handling call.
+    Rearmed,
+}
+
+/// What to do with a multishot submission upon its termination, see
+/// [`crate::UringBearer::set_rearm_policy`].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum RearmPolicy {
+    /// Release the record and report it as Terminated (default)
+    #[default]
+    Never,
+    /// Re-arm when the submission ran out of provided buffers, exhausted its count or
+    /// ended normally, any other error ends it for good.
+    Always,
+    /// Re-arm as per Always up to the given remaining times
+    UpTo(u32),
+}
+
+/// Whether to keep spinning the completions ring after the handled completion.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CompletionFlow {
+    /// Keep handling the further completions
+    Continue,
+    /// Stop after this completion, the rest are handed out upon the next spin
+    Stop,
+}
+
+/// Outcome of a link within a chain pushed via [`crate::UringBearer::push_chain`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LinkOutcome {
+    /// Link completed with the non-negative result, or the LinkTimeout was canceled as the
+    /// link it guarded completed in time
+    Succeeded(i32),
+    /// Link failed with the negative errno result and severed the chain
+    Failed(i32),
+    /// Link was canceled with ECANCELED by an earlier link severing the chain or timing out
+    Canceled,
+    /// LinkTimeout expired canceling the link it guarded
+    TimedOut,
+}
+
+/// Result of a linked chain once all of its links completed, see
+/// [`crate::UringBearer::chain_result`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct ChainResult {
+    pub(crate) keys: Vec<usize>,
+    pub(crate) outcomes: Vec<LinkOutcome>,
+}
+
+impl ChainResult {
+    /// Keys of the links in the chain order
+    #[inline]
+    pub fn keys(&self) -> &[usize] {
+        &self.keys
+    }
+    /// Outcomes of the links in the chain order
+    #[inline]
+    pub fn outcomes(&self) -> &[LinkOutcome] {
+        &self.outcomes
+    }
+    /// Whether every link succeeded
+    pub fn is_success(&self) -> bool {
+        self.outcomes
+            .iter()
+            .all(|o| matches!(o, LinkOutcome::Succeeded(_)))
+    }
+    /// Index and outcome of the first link that failed or timed out, if any
+    pub fn failed(&self) -> Option<(usize, LinkOutcome)> {
+        self.outcomes
+            .iter()
+            .enumerate()
+            .find(|(_, o)| matches!(o, LinkOutcome::Failed(_) | LinkOutcome::TimedOut))
+            .map(|(idx, o)| (idx, *o))
+    }
+    /// Indexes of the links canceled with ECANCELED
+    pub fn canceled(&self) -> Vec<usize> {
+        self.outcomes
+            .iter()
+            .enumerate()
+            .filter(|(_, o)| **o == LinkOutcome::Canceled)
+            .map(|(idx, _)| idx)
+            .collect()
+    }
+}
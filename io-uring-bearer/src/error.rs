@@ -6,6 +6,7 @@ use core::fmt;
 use core::fmt::Display;
 
 use io_uring_opcode::OpError;
+use io_uring_probe::{Capability, ProbeError};
 use slabbable::SlabbableError;
 
 /// Errors from the Uring Handler
@@ -42,12 +43,22 @@ pub enum UringBearerError {
     BufferTake(TakeError),
     /// Buffers are not owned by Kernel.
     BufferNotKernelOwned(usize),
+    /// Buffers are not registered (at the given index) in the fixed buffers table.
+    BufferNotFixed(usize),
+    /// Cannot destroy buffers the kernel refers to through the fixed buffers table.
+    BufferFixedPinned(usize),
+    /// Registering the fixed buffers table failed
+    RegisterBuffers(String),
+    /// Fixed buffers table is not registered or the given range is outside it
+    FixedBuffersRange(u32, usize),
     /// Cannot directly destroy futex atomics that are currently owned by the kernel. Use cancel_futex instead.
     FutexNoOwnership(usize),
     /// Futex Atomic does not exist.
     FutexNotExist(usize),
     /// Filehandle must be registered first
     FdNotRegistered(u32),
+    /// Fixed fd must be closed via close_fixed which frees its slot
+    CloseFixedViaFs(u32),
     /// Kernel has the ownership cannot push to kernel.
     InvalidOwnership(Owner, usize),
     /// Registered filehandles map is full at capacity.
@@ -58,6 +69,46 @@ pub enum UringBearerError {
     Op(OpError),
     /// Invalid TargetFd assignment
     InvalidTargetFd(u32),
+    /// Probing the kernel capabilities failed
+    Probe(ProbeError),
+    /// Required capability is not supported by the running kernel
+    NotSupported(Capability),
+    /// OpCode is not supported by the running kernel
+    OpNotSupported(u8),
+    /// Record can not be pushed as typed Completion, use its dedicated push method instead
+    TypedNotPushable,
+    /// No record exists with the given key
+    KeyNotExist(usize),
+    /// Record with the given key has no submission in-flight
+    NotInFlight(usize),
+    /// Synchronous cancel timed out before all the matched submissions were canceled
+    CancelTimeout,
+    /// Synchronous cancel failed
+    CancelSync(String),
+    /// Shutdown deadline passed with the given count of submissions still in-flight
+    ShutdownTimeout(usize),
+    /// Standalone Timeout was given where LinkTimeout was expected or vice versa
+    TimeoutLinkMismatch,
+    /// Creating the intermediate pipe pair failed
+    Pipe(String),
+    /// Chained splices must move into the pipe and then out of it
+    SplicePipeMismatch,
+    /// Splice out of the intermediate pipe must not block on the pipe left empty
+    SplicePipeBlocking,
+    /// Record at the key is not a FixedFdInstall or it has not completed yet
+    NotFdInstalled(usize),
+    /// FixedFdInstall at the key completed with the negative errno result
+    FdInstallFailed(usize, i32),
+    /// Record at the key is not a pending multishot submission
+    NotMultishot(usize),
+    /// Submission queue lacks the space needed, first is the needed and second the free entries.
+    SubmissionQueueFull(usize, usize),
+    /// Chain must have at least one link
+    ChainEmpty,
+    /// Chain links must post their completion, skipping it upon success is not supported
+    ChainSkipSuccess,
+    /// No chain is pending with the given id, it was collected or dropped uncollected
+    ChainNotExist(usize),
 }
 
 impl Display for UringBearerError {
@@ -83,6 +134,18 @@ impl Display for UringBearerError {
             Self::BufferNotKernelOwned(idx) => {
                 write!(f, "Buffer {} is not owned by the kernel.", idx)
             }
+            Self::BufferNotFixed(idx) => {
+                write!(f, "Buffer {} is not registered as fixed buffer.", idx)
+            }
+            Self::BufferFixedPinned(idx) => {
+                write!(f, "Buffer {} is referred by the fixed buffers table.", idx)
+            }
+            Self::RegisterBuffers(s) => write!(f, "Registering fixed buffers: {}", s),
+            Self::FixedBuffersRange(offset, len) => write!(
+                f,
+                "Fixed buffers range {}+{} is outside the registered table.",
+                offset, len
+            ),
             Self::BufferSelectedNotExist(sel_idx) => write!(
                 f,
                 "Selected {} does not exist within the given buffer.",
@@ -92,6 +155,9 @@ impl Display for UringBearerError {
             Self::FutexNoOwnership(idx) => write!(f, "Futex {} in invalid ownership state", idx),
             Self::FutexNotExist(idx) => write!(f, "Futex {} does not exist.", idx),
             Self::FdNotRegistered(idx) => write!(f, "Fixed fd {} is not registered.", idx),
+            Self::CloseFixedViaFs(idx) => {
+                write!(f, "Fixed fd {} must be closed via close_fixed.", idx)
+            }
             Self::InvalidOwnership(owner, idx) => {
                 write!(f, "Invalid current ownership {} of idx {}", owner, idx)
             }
@@ -101,6 +167,54 @@ impl Display for UringBearerError {
             ),
             Self::FdRegisterFail => write!(f, "Failed to register filehandle."),
             Self::Op(e) => write!(f, "OpCode: {}", e),
+            Self::Probe(e) => write!(f, "Probe: {}", e),
+            Self::NotSupported(c) => write!(f, "{} is not supported in your Kernel", c),
+            Self::OpNotSupported(code) => {
+                write!(f, "OpCode {} is not supported in your Kernel", code)
+            }
+            Self::TypedNotPushable => write!(
+                f,
+                "Record can not be pushed as typed, use its dedicated push method."
+            ),
+            Self::CancelTimeout => write!(f, "Synchronous cancel timed out."),
+            Self::CancelSync(s) => write!(f, "Synchronous cancel: {}", s),
+            Self::ShutdownTimeout(n) => {
+                write!(f, "Shutdown deadline passed with {} still in-flight.", n)
+            }
+            Self::KeyNotExist(key) => write!(f, "No submission record exists with key {}", key),
+            Self::NotInFlight(key) => write!(f, "No submission is in-flight with key {}", key),
+            Self::TimeoutLinkMismatch => write!(
+                f,
+                "LinkTimeout must follow a linked submission whilst Timeout must not."
+            ),
+            Self::Pipe(s) => write!(f, "Pipe: {}", s),
+            Self::SplicePipeMismatch => write!(
+                f,
+                "First splice must move into the pipe and the second out of it."
+            ),
+            Self::SplicePipeBlocking => write!(
+                f,
+                "Second splice must set SPLICE_F_NONBLOCK to not block on the empty pipe."
+            ),
+            Self::NotFdInstalled(key) => {
+                write!(f, "Record {} is not a completed FixedFdInstall.", key)
+            }
+            Self::FdInstallFailed(key, res) => {
+                write!(f, "FixedFdInstall {} failed with result {}.", key, res)
+            }
+            Self::NotMultishot(key) => {
+                write!(f, "Record {} is not a pending multishot submission.", key)
+            }
+            Self::SubmissionQueueFull(needed, free) => write!(
+                f,
+                "Submission queue has {} free entries but {} are needed.",
+                free, needed
+            ),
+            Self::ChainEmpty => write!(f, "Chain must have at least one link."),
+            Self::ChainSkipSuccess => {
+                write!(f, "Chain links can not skip their completion upon success.")
+            }
+            Self::ChainNotExist(id) => write!(f, "No chain is pending with id {}", id),
         }
     }
 }
@@ -111,4 +225,16 @@ impl From<OpError> for UringBearerError {
     }
 }
 
+impl From<ProbeError> for UringBearerError {
+    fn from(e: ProbeError) -> UringBearerError {
+        match e {
+            ProbeError::NotSupported(Capability::OpCode(code)) => {
+                UringBearerError::OpNotSupported(code)
+            }
+            ProbeError::NotSupported(c) => UringBearerError::NotSupported(c),
+            e => UringBearerError::Probe(e),
+        }
+    }
+}
+
 impl core::error::Error for UringBearerError {}
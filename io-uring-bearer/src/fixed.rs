@@ -3,16 +3,22 @@
 //! Keeping track of these things here.
 
 use hashbrown::HashMap as SelectedHashMap;
-use io_uring_fd::RegisteredFd;
+use io_uring_fd::{FdKind, RegisteredFd};
 use nohash_hasher::BuildNoHashHasher as SelectedHasher;
-use slab::{Slab, VacantEntry};
+
+/// Slot expected to be installed into, None for kernel auto-assigned, and its kind
+pub(crate) type InstallTracking = (Option<u32>, FdKind);
 
 #[derive(Clone, Debug)]
 pub(crate) struct FixedFdRegister {
-    #[allow(dead_code)]
     fno2fixed: SelectedHashMap<u32, u32, SelectedHasher<u32>>,
-    fixed_ord: Slab<(u32, RegisteredFd)>,
+    fixed_ord: Vec<Option<(u32, RegisteredFd)>>,
+    len: u32,
     capacity: u32,
+    /// Slots (offset, len) the kernel auto-assigns from, kept clear of add_registered_fd
+    alloc_range: Option<(u32, u32)>,
+    /// In-flight submissions by slab key installing into the manual or auto-assigned slot
+    installing: SelectedHashMap<usize, InstallTracking, SelectedHasher<usize>>,
 }
 
 impl FixedFdRegister {
@@ -25,16 +31,22 @@ impl FixedFdRegister {
         FixedFdRegister {
             fno2fixed,
             capacity: cap,
-            fixed_ord: Slab::with_capacity(cap as usize),
+            len: 0,
+            fixed_ord: vec![None; cap as usize],
+            alloc_range: None,
+            installing: SelectedHashMap::with_hasher(SelectedHasher::default()),
         }
     }
+    /// First free slot outside the kernel auto-assigned range if any
     #[inline]
-    pub(crate) fn vacant_entry(&mut self) -> VacantEntry<'_, (u32, RegisteredFd)> {
-        self.fixed_ord.vacant_entry()
+    pub(crate) fn vacant(&self) -> Option<u32> {
+        (0..self.capacity)
+            .filter(|i| !self.in_alloc_range(*i))
+            .find(|i| self.fixed_ord[*i as usize].is_none())
     }
     #[inline]
     pub(crate) fn len(&self) -> u32 {
-        self.fixed_ord.len() as u32
+        self.len
     }
     #[inline]
     pub(crate) fn capacity(&self) -> u32 {
@@ -42,10 +54,173 @@ impl FixedFdRegister {
     }
     #[inline]
     pub(crate) fn get(&self, i: u32) -> Option<&(u32, RegisteredFd)> {
-        self.fixed_ord.get(i as usize)
+        self.fixed_ord.get(i as usize)?.as_ref()
+    }
+    /// Set the slot, replacing what was there as the kernel does upon installing into it.
+    #[inline]
+    pub(crate) fn insert(&mut self, i: u32, reg_fd: RegisteredFd) -> bool {
+        if i >= self.capacity {
+            return false;
+        }
+        self.remove(i);
+        if !reg_fd.is_kernel_installed() {
+            self.fno2fixed.insert(reg_fd.raw_fd as u32, i);
+        }
+        self.fixed_ord[i as usize] = Some((i, reg_fd));
+        self.len += 1;
+        true
+    }
+    /// Free the slot, dropping the reverse mapping unless the raw fd was re-registered since.
+    #[inline]
+    pub(crate) fn remove(&mut self, i: u32) -> Option<(u32, RegisteredFd)> {
+        let removed = self.fixed_ord.get_mut(i as usize)?.take()?;
+        self.len -= 1;
+        let raw_fd = removed.1.raw_fd as u32;
+        if self.fno2fixed.get(&raw_fd) == Some(&i) {
+            self.fno2fixed.remove(&raw_fd);
+        }
+        Some(removed)
+    }
+    /// Map the regular filehandle installed out of the kernel installed slot both ways
+    #[inline]
+    pub(crate) fn installed_as_regular(&mut self, i: u32, raw_fd: i32) {
+        if let Some(Some((_, reg_fd))) = self.fixed_ord.get_mut(i as usize) {
+            if reg_fd.is_kernel_installed() {
+                reg_fd.raw_fd = raw_fd;
+                self.fno2fixed.insert(raw_fd as u32, i);
+            }
+        }
+    }
+    #[inline]
+    pub(crate) fn fixed_of(&self, raw_fd: u32) -> Option<u32> {
+        self.fno2fixed.get(&raw_fd).copied()
+    }
+    #[inline]
+    pub(crate) fn set_alloc_range(&mut self, offset: u32, len: u32) {
+        self.alloc_range = Some((offset, len));
     }
     #[inline]
-    pub(crate) fn insert(&mut self, i: (u32, RegisteredFd)) -> u32 {
-        self.fixed_ord.insert(i) as u32
+    fn in_alloc_range(&self, i: u32) -> bool {
+        matches!(self.alloc_range, Some((offset, len)) if i >= offset && i - offset < len)
+    }
+    /// Track the submission by the slab key expected to install into the manual slot or
+    /// into the kernel auto-assigned slot when None.
+    #[inline]
+    pub(crate) fn expect_install(&mut self, key: usize, manual_slot: Option<u32>, kind: FdKind) {
+        self.installing.insert(key, (manual_slot, kind));
+    }
+    /// Whether the submission by the slab key is tracked as installing into a slot
+    #[inline]
+    pub(crate) fn is_install_tracked(&self, key: usize) -> bool {
+        self.installing.contains_key(&key)
+    }
+    /// Forget all the slots as the kernel does upon unregistering the filehandles
+    #[inline]
+    pub(crate) fn clear(&mut self) {
+        self.fno2fixed.clear();
+        self.fixed_ord.iter_mut().for_each(|slot| *slot = None);
+        self.len = 0;
+        self.installing.clear();
+    }
+    /// Whether an in-flight submission is installing into the manual slot
+    #[inline]
+    pub(crate) fn is_installing(&self, i: u32) -> bool {
+        self.installing
+            .values()
+            .any(|(manual_slot, _)| *manual_slot == Some(i))
+    }
+    /// Record the slot installed by the completion of the tracked submission if any.
+    /// Manual installs return zero whereas auto-assigned return the slot.
+    /// Returns the tracking dropped upon the final completion e.g. to re-track the re-armed.
+    pub(crate) fn installed(
+        &mut self,
+        key: usize,
+        res: i32,
+        more: bool,
+    ) -> Option<InstallTracking> {
+        let (manual_slot, kind) = match more {
+            true => self.installing.get(&key)?.clone(),
+            false => self.installing.remove(&key)?,
+        };
+        if res >= 0 {
+            let slot = manual_slot.unwrap_or(res as u32);
+            self.insert(slot, RegisteredFd::kernel_installed(kind.clone()));
+        }
+        match more {
+            true => None,
+            false => Some((manual_slot, kind)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn vacant_skips_alloc_range() {
+        let mut register = FixedFdRegister::with_fixed_capacity(4);
+        assert_eq!(register.vacant(), Some(0));
+        register.set_alloc_range(0, 2);
+        assert_eq!(register.vacant(), Some(2));
+        assert!(register.insert(2, RegisteredFd::from_raw(10, FdKind::File)));
+        assert_eq!(register.vacant(), Some(3));
+        assert!(register.insert(3, RegisteredFd::from_raw(11, FdKind::File)));
+        assert_eq!(register.vacant(), None);
+    }
+
+    #[test]
+    fn insert_replaces_and_remove() {
+        let mut register = FixedFdRegister::with_fixed_capacity(2);
+        assert!(!register.insert(2, RegisteredFd::from_raw(10, FdKind::File)));
+        assert!(register.insert(0, RegisteredFd::from_raw(10, FdKind::File)));
+        assert_eq!(register.fixed_of(10), Some(0));
+        assert!(register.insert(0, RegisteredFd::from_raw(11, FdKind::File)));
+        assert_eq!(register.len(), 1);
+        assert_eq!(register.fixed_of(10), None);
+        assert_eq!(register.fixed_of(11), Some(0));
+        assert!(register.remove(0).is_some());
+        assert!(register.remove(0).is_none());
+        assert_eq!(register.len(), 0);
+        assert_eq!(register.fixed_of(11), None);
+    }
+
+    #[test]
+    fn remove_keeps_reregistered_mapping() {
+        let mut register = FixedFdRegister::with_fixed_capacity(2);
+        assert!(register.insert(0, RegisteredFd::from_raw(10, FdKind::File)));
+        assert!(register.insert(1, RegisteredFd::from_raw(10, FdKind::File)));
+        register.remove(0);
+        assert_eq!(register.fixed_of(10), Some(1));
+    }
+
+    #[test]
+    fn installed_manual_and_auto() {
+        let mut register = FixedFdRegister::with_fixed_capacity(4);
+        register.set_alloc_range(2, 2);
+        register.expect_install(7, Some(1), FdKind::Acceptor);
+        register.expect_install(8, None, FdKind::Acceptor);
+        assert!(register.is_installing(1));
+        assert!(register.is_install_tracked(8));
+
+        assert!(register.installed(7, 0, false).is_some());
+        assert!(register
+            .get(1)
+            .is_some_and(|(_, fd)| fd.is_kernel_installed()));
+        assert!(!register.is_installing(1));
+
+        assert!(register.installed(8, 3, true).is_none());
+        assert!(register.get(3).is_some());
+        assert!(register.is_install_tracked(8));
+        assert!(register.installed(8, -libc::ENFILE, false).is_some());
+        assert!(!register.is_install_tracked(8));
+        assert_eq!(register.len(), 2);
+
+        register.installed_as_regular(3, 12);
+        assert_eq!(register.fixed_of(12), Some(3));
+        register.clear();
+        assert_eq!(register.len(), 0);
+        assert!(register.get(3).is_none());
+        assert_eq!(register.fixed_of(12), None);
     }
 }
@@ -11,6 +11,8 @@
 // Re-Exports
 //***********************************************
 pub use io_uring;
+pub use io_uring_fd;
+pub use io_uring_probe;
 
 //-----------------------------------------------
 // All Errors
@@ -48,12 +50,25 @@ pub use submission::TargetFd;
 pub mod completion;
 #[doc(inline)]
 pub use completion::Completion;
+#[doc(inline)]
+pub use completion::OpCompletionResult;
+#[doc(inline)]
+pub use completion::{ChainResult, CompletionFlow, LinkOutcome, MultishotStatus, RearmPolicy};
 
 //-----------------------------------------------
 // Uring Handler -> Core Uring handler
 //-----------------------------------------------
 mod uring;
 #[doc(inline)]
+pub use uring::ChainLink;
+#[doc(inline)]
+pub use uring::Enqueue;
+#[cfg(feature = "splice")]
+#[doc(inline)]
+pub use uring::SplicePipe;
+#[doc(inline)]
+pub use uring::SubmissionBatch;
+#[doc(inline)]
 pub use uring::UringBearer;
 
 //-----------------------------------------------
@@ -13,7 +13,7 @@ pub use accept::AcceptRec;
 // on Read/Recv etc. calls
 pub(crate) mod buffer;
 #[doc(inline)]
-pub use buffer::{BuffersRec, ProvideBuffersRec};
+pub use buffer::{BuffersRec, ProvideBuffersRec, TakenImmutableBuffer, TakenMutableBuffer};
 
 pub(crate) mod futex;
 #[doc(inline)]
@@ -25,4 +25,16 @@ pub use recv::{RecvMultiRec, RecvRec};
 
 pub(crate) mod send_zc;
 #[doc(inline)]
-pub use send_zc::SendZcRec;
+pub use send_zc::{DestTo, DestToV4, DestToV6, SendZcRec};
+
+pub(crate) mod cancel;
+#[doc(inline)]
+pub use cancel::{AsyncCancelRec, CancelTarget};
+
+pub(crate) mod close;
+#[doc(inline)]
+pub use close::CloseFixedRec;
+
+pub(crate) mod install;
+#[doc(inline)]
+pub use install::FixedFdInstallRec;
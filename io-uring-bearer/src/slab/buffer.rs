@@ -12,6 +12,12 @@ pub struct BuffersRec {
     all_bufs: Vec<u8>,
     len_per_buf: i32,
     num_bufs: u16,
+    /// Index in the registered fixed buffers table if registered
+    pub(crate) fixed_index: Option<u16>,
+    /// Count of the fixed buffer table slots the kernel still refers to the buffers through
+    pub(crate) fixed_pins: u16,
+    /// Provided into a kernel buffer group which may select them until these are removed
+    pub(crate) provided: bool,
 
     _pin: PhantomPinned,
 }
@@ -25,6 +31,10 @@ impl BuffersRec {
     pub(crate) fn force_owner_kernel(&mut self) {
         self.owner = Owner::Kernel;
     }
+    /// Kernel only read the buffers which can be taken again as-is, e.g. after Send completed.
+    pub(crate) fn force_owner_reusable(&mut self) {
+        self.owner = Owner::Reusable;
+    }
     /// Get the current ownership status.
     pub fn owner(&self) -> Owner {
         self.owner.clone()
@@ -37,6 +47,25 @@ impl BuffersRec {
     pub fn num_bufs(&self) -> u16 {
         self.num_bufs
     }
+    /// Index in the registered fixed buffers table if registered
+    pub fn fixed_index(&self) -> Option<u16> {
+        self.fixed_index
+    }
+    /// Whether the kernel still refers to the buffers through the fixed buffers table
+    pub fn is_fixed_pinned(&self) -> bool {
+        self.fixed_pins > 0
+    }
+    /// Kernel no longer refers to the buffers through one of the fixed buffers table slots
+    pub(crate) fn fixed_released(&mut self) {
+        self.fixed_pins = self.fixed_pins.saturating_sub(1);
+    }
+    /// The whole allocation as iovec for registering as fixed buffer
+    pub(crate) fn as_iovec(&self) -> libc::iovec {
+        libc::iovec {
+            iov_base: self.all_bufs.as_ptr() as *mut libc::c_void,
+            iov_len: self.all_bufs.len(),
+        }
+    }
     /// All buffers.
     ///
     /// # Safety
@@ -59,6 +88,9 @@ pub(crate) fn construct_buffer(owner: Owner, len_per_buf: i32, num_bufs: u16) ->
         len_per_buf,
         num_bufs,
         all_bufs: v,
+        fixed_index: None,
+        fixed_pins: 0,
+        provided: false,
         _pin: PhantomPinned,
     }
 }
@@ -84,16 +116,40 @@ impl ProvideBuffersRec {
 
 /// Mutable Buffer is taken by something, let's provide it intermediate type.  
 #[derive(Clone, Debug)]
-#[allow(dead_code)]
-pub(crate) struct TakenMutableBuffer {
+pub struct TakenMutableBuffer {
     pub(crate) buf_idx: usize,
     pub(crate) buf_mut_u8: *mut u8,
     pub(crate) buf_size: u32,
+    pub(crate) buf_kernel_index: u16,
+}
+
+impl TakenMutableBuffer {
+    /// Index of the created buffers taken
+    #[inline]
+    pub fn buf_idx(&self) -> usize {
+        self.buf_idx
+    }
+    /// Raw pointer to the start of the taken buffer
+    #[inline]
+    pub fn as_mut_ptr(&self) -> *mut u8 {
+        self.buf_mut_u8
+    }
+    /// Length of the taken buffer
+    #[inline]
+    pub fn buf_size(&self) -> u32 {
+        self.buf_size
+    }
+    /// Index of the buffer as registered with the kernel, u16::MAX when not taken as fixed
+    #[inline]
+    pub fn buf_kernel_index(&self) -> u16 {
+        self.buf_kernel_index
+    }
 }
 
 #[inline]
 pub(crate) fn take_one_mutable_buffer_raw(
     buf_idx: usize,
+    buf_kernel_index: u16,
     buf_rec: &mut BuffersRec,
 ) -> Result<TakenMutableBuffer, TakeError> {
     if buf_rec.num_bufs() != 1 {
@@ -103,20 +159,44 @@ pub(crate) fn take_one_mutable_buffer_raw(
     Ok(TakenMutableBuffer {
         buf_idx,
         buf_size: buf_rec.len_per_buf as u32,
-        buf_mut_u8: &raw mut buf_rec.all_bufs as *mut u8,
+        buf_mut_u8: buf_rec.all_bufs.as_mut_ptr(),
+        buf_kernel_index,
     })
 }
 
 /// Buffer is taken by something for Read-Only, let's provide it intermediate type.  
 #[derive(Clone, Debug)]
 #[allow(dead_code)]
-pub(crate) struct TakenImmutableBuffer {
+pub struct TakenImmutableBuffer {
     pub(crate) buf_idx: usize,
     pub(crate) buf_const_u8: *const u8,
     pub(crate) buf_size: u32,
     pub(crate) buf_kernel_index: u16,
 }
 
+impl TakenImmutableBuffer {
+    /// Index of the created buffers taken
+    #[inline]
+    pub fn buf_idx(&self) -> usize {
+        self.buf_idx
+    }
+    /// Raw pointer to the start of the taken buffer
+    #[inline]
+    pub fn as_ptr(&self) -> *const u8 {
+        self.buf_const_u8
+    }
+    /// Length of the taken buffer
+    #[inline]
+    pub fn buf_size(&self) -> u32 {
+        self.buf_size
+    }
+    /// Index of the buffer as registered with the kernel
+    #[inline]
+    pub fn buf_kernel_index(&self) -> u16 {
+        self.buf_kernel_index
+    }
+}
+
 #[inline]
 pub(crate) fn take_one_immutable_buffer_raw(
     buf_idx: usize,
@@ -0,0 +1,57 @@
+//! AsyncCancel Slab records
+
+use io_uring::types::{CancelBuilder, Fixed};
+use io_uring_owner::Owner;
+
+/// What the AsyncCancel targets
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CancelTarget {
+    /// Submission identified by the bearer slab key
+    Key(usize),
+    /// All the submissions targeting the fixed filehandle
+    FixedFd(u32),
+    /// All the in-flight submissions
+    All,
+}
+
+/// AsyncCancel Record
+#[derive(Clone, Debug)]
+pub struct AsyncCancelRec {
+    owner: Owner,
+    target: CancelTarget,
+}
+
+impl AsyncCancelRec {
+    #[inline]
+    pub(crate) fn new(target: CancelTarget) -> Self {
+        AsyncCancelRec {
+            owner: Owner::Created,
+            target,
+        }
+    }
+    #[inline]
+    pub(crate) fn entry(&self) -> io_uring::squeue::Entry {
+        match self.target {
+            CancelTarget::Key(key) => io_uring::opcode::AsyncCancel::new(key as u64).build(),
+            CancelTarget::FixedFd(fixed_fd) => {
+                io_uring::opcode::AsyncCancel2::new(CancelBuilder::fd(Fixed(fixed_fd)).all())
+                    .build()
+            }
+            CancelTarget::All => io_uring::opcode::AsyncCancel2::new(CancelBuilder::any()).build(),
+        }
+    }
+    #[inline]
+    pub(crate) fn owner(&self) -> Owner {
+        self.owner.clone()
+    }
+    #[inline]
+    pub(crate) fn force_owner_kernel(&mut self) -> bool {
+        self.owner = Owner::Kernel;
+        true
+    }
+    /// What was targeted
+    #[inline]
+    pub fn target(&self) -> CancelTarget {
+        self.target
+    }
+}
@@ -0,0 +1,57 @@
+//! Close of a fixed filehandle Slab records
+
+use io_uring::types::Fixed;
+use io_uring_owner::Owner;
+
+/// Close Record for a registered (fixed) filehandle slot
+#[derive(Clone, Debug)]
+pub struct CloseFixedRec {
+    owner: Owner,
+    slot: u32,
+    result: Option<i32>,
+}
+
+impl CloseFixedRec {
+    #[inline]
+    pub(crate) fn new(slot: u32) -> Self {
+        CloseFixedRec {
+            owner: Owner::Created,
+            slot,
+            result: None,
+        }
+    }
+    #[inline]
+    pub(crate) fn entry(&self) -> io_uring::squeue::Entry {
+        io_uring::opcode::Close::new(Fixed(self.slot)).build()
+    }
+    #[inline]
+    pub(crate) fn owner(&self) -> Owner {
+        self.owner.clone()
+    }
+    #[inline]
+    pub(crate) fn force_owner_kernel(&mut self) -> bool {
+        self.owner = Owner::Kernel;
+        true
+    }
+    #[inline]
+    pub(crate) fn completion(&mut self, cqe: &io_uring::cqueue::Entry) {
+        self.owner = Owner::Returned;
+        self.result = Some(cqe.result());
+    }
+    /// Whether the kernel table entry is gone and the slot may be reused.
+    /// EBADF means the slot was already empty in the kernel.
+    #[inline]
+    pub(crate) fn is_slot_freed(&self) -> bool {
+        matches!(self.result, Some(res) if res >= 0 || res == -libc::EBADF)
+    }
+    /// The fixed filehandle slot closed
+    #[inline]
+    pub fn slot(&self) -> u32 {
+        self.slot
+    }
+    /// Completion result once returned from the kernel
+    #[inline]
+    pub fn result(&self) -> Option<i32> {
+        self.result
+    }
+}
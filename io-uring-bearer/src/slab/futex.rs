@@ -28,6 +28,13 @@ impl FutexRec {
             FutexRec::UnsafeReferenced(r) => r.pending_at = Owner::Kernel,
         }
     }
+    /// Kernel no longer refers to the Futex atomic, e.g. after the FutexWait was canceled.
+    pub(crate) fn force_owner_returned(&mut self) {
+        match self {
+            FutexRec::Owned(r) => r.pending_at = Owner::Returned,
+            FutexRec::UnsafeReferenced(r) => r.pending_at = Owner::Returned,
+        }
+    }
 }
 
 // TODO: Maybe provide intermediate-referenced type which could be naively tiny bit safer.
@@ -73,18 +80,37 @@ pub(crate) fn construct_unsafe_futex(f: *const u32) -> FutexRec {
 /// FutexWait Completion Rec
 #[derive(Clone, Debug, PartialEq)]
 pub struct FutexWaitRec {
+    futex_idx: usize,
     atom: *const u32,
     bitset: u64,
     val: u64,
 }
 
 #[inline]
-pub(crate) fn wait_futex_rec(bitset: u64, val: u64, futex_rec: &FutexRec) -> FutexWaitRec {
+pub(crate) fn wait_futex_rec(
+    futex_idx: usize,
+    bitset: u64,
+    val: u64,
+    futex_rec: &FutexRec,
+) -> FutexWaitRec {
     let atom = match futex_rec {
         FutexRec::Owned(owned_rec) => owned_rec.owned_atom.as_ptr() as *const u32,
         FutexRec::UnsafeReferenced(unsafe_rec) => unsafe_rec.unsafe_atom,
     };
-    FutexWaitRec { atom, bitset, val }
+    FutexWaitRec {
+        futex_idx,
+        atom,
+        bitset,
+        val,
+    }
+}
+
+impl FutexWaitRec {
+    /// Index of the waited Futex Atomic
+    #[inline]
+    pub fn futex_idx(&self) -> usize {
+        self.futex_idx
+    }
 }
 
 // From: https://github.com/torvalds/linux/blob/v6.7/include/uapi/linux/futex.h#L63
@@ -0,0 +1,65 @@
+//! FixedFdInstall Slab records
+
+use io_uring::types::Fixed;
+use io_uring_owner::Owner;
+
+/// FixedFdInstall Record turning a fixed filehandle slot into a regular filehandle
+#[derive(Clone, Debug)]
+pub struct FixedFdInstallRec {
+    owner: Owner,
+    slot: u32,
+    cloexec: bool,
+    result: Option<i32>,
+}
+
+impl FixedFdInstallRec {
+    #[inline]
+    pub(crate) fn new(slot: u32, cloexec: bool) -> Self {
+        FixedFdInstallRec {
+            owner: Owner::Created,
+            slot,
+            cloexec,
+            result: None,
+        }
+    }
+    #[inline]
+    pub(crate) fn entry(&self) -> io_uring::squeue::Entry {
+        let file_flags = match self.cloexec {
+            true => 0,
+            false => IORING_FIXED_FD_NO_CLOEXEC,
+        };
+        io_uring::opcode::FixedFdInstall::new(Fixed(self.slot), file_flags).build()
+    }
+    #[inline]
+    pub(crate) fn owner(&self) -> Owner {
+        self.owner.clone()
+    }
+    #[inline]
+    pub(crate) fn force_owner_kernel(&mut self) -> bool {
+        self.owner = Owner::Kernel;
+        true
+    }
+    #[inline]
+    pub(crate) fn completion(&mut self, cqe: &io_uring::cqueue::Entry) {
+        self.owner = Owner::Returned;
+        self.result = Some(cqe.result());
+    }
+    /// The fixed filehandle slot installed
+    #[inline]
+    pub fn slot(&self) -> u32 {
+        self.slot
+    }
+    /// Whether the installed regular filehandle is close-on-exec
+    #[inline]
+    pub fn cloexec(&self) -> bool {
+        self.cloexec
+    }
+    /// Completion result once returned from the kernel, the regular filehandle on success
+    #[inline]
+    pub fn result(&self) -> Option<i32> {
+        self.result
+    }
+}
+
+/// IORING_FIXED_FD_NO_CLOEXEC not exposed by io-uring
+const IORING_FIXED_FD_NO_CLOEXEC: u32 = 1;
@@ -34,6 +34,16 @@ impl RecvRec {
     pub(crate) fn owner(&self) -> Owner {
         self.owner.clone()
     }
+    /// Fixed Filehandle Id
+    #[inline]
+    pub fn fixed_fd(&self) -> u32 {
+        self.fixed_fd
+    }
+    /// Bearer managed buffers returned upon the final completion
+    #[inline]
+    pub(crate) fn buf_idxs(&self) -> &[usize] {
+        core::slice::from_ref(&self.buf_taken.buf_idx)
+    }
     #[inline]
     pub(crate) fn force_owner_kernel(&mut self) -> bool {
         self.owner = Owner::Kernel;
@@ -82,4 +92,11 @@ impl RecvMultiRec {
         self.owner = Owner::Kernel;
         true
     }
+    /// Kernel refers to the record until the final completion
+    #[inline]
+    pub(crate) fn completion(&mut self, cqe: &io_uring::cqueue::Entry) {
+        if !io_uring::cqueue::more(cqe.flags()) {
+            self.owner = Owner::Returned;
+        }
+    }
 }
@@ -2,6 +2,8 @@
 
 use io_uring_owner::Owner;
 
+use std::net::{SocketAddr, SocketAddrV4, SocketAddrV6};
+
 use crate::slab::buffer::TakenImmutableBuffer;
 
 /// SendZc could be either through a fixed registered buffer through it's index or
@@ -15,6 +17,7 @@ pub enum SendZcRec {
 }
 
 impl SendZcRec {
+    /// Fixed Filehandle Id
     #[inline]
     pub fn fixed_fd(&self) -> u32 {
         match self {
@@ -22,6 +25,31 @@ impl SendZcRec {
             Self::UnsafeRef(fr) => fr.fixed_fd,
         }
     }
+    /// Bearer managed buffers returned upon the notification completion
+    #[inline]
+    pub(crate) fn buf_idxs(&self) -> &[usize] {
+        match self {
+            Self::Fixed(fr) => core::slice::from_ref(&fr.buf_taken.buf_idx),
+            Self::UnsafeRef(_) => &[],
+        }
+    }
+    /// Result of the send once completed, the count of bytes sent or the negative errno.
+    /// The buffer is still referred by the kernel until the separate notification completion.
+    #[inline]
+    pub fn result(&self) -> Option<i32> {
+        match self {
+            Self::Fixed(fr) => fr.result,
+            Self::UnsafeRef(fr) => fr.result,
+        }
+    }
+    /// Destination if sent through sendto(2)
+    #[inline]
+    pub fn to_addr(&self) -> Option<&DestTo> {
+        match self {
+            Self::Fixed(fr) => fr.to_addr.as_ref(),
+            Self::UnsafeRef(fr) => fr.to_addr.as_ref(),
+        }
+    }
 }
 
 /// SendZc Fixed Record
@@ -30,8 +58,8 @@ pub struct SendZcFixedRec {
     fixed_fd: u32,
     owner: Owner,
     buf_taken: TakenImmutableBuffer,
-    #[allow(dead_code)]
     to_addr: Option<DestTo>,
+    result: Option<i32>,
 }
 
 /// SendZc UnsafeRef Record
@@ -41,19 +69,24 @@ pub struct SendZcUnsafeRefRec {
     owner: Owner,
     buf_const_u8: *const u8,
     buf_size: u32,
-    #[allow(dead_code)]
     to_addr: Option<DestTo>,
+    result: Option<i32>,
 }
 
 impl SendZcRec {
     /// Zero-Copy Send with a previously Bearer created by and managed buffer.
     #[inline]
-    pub(crate) fn with_fixed_buf(fixed_fd: u32, buf_taken: TakenImmutableBuffer) -> Self {
+    pub(crate) fn with_fixed_buf(
+        fixed_fd: u32,
+        buf_taken: TakenImmutableBuffer,
+        to_addr: Option<DestTo>,
+    ) -> Self {
         SendZcRec::Fixed(SendZcFixedRec {
             fixed_fd,
             owner: Owner::Created,
             buf_taken,
-            to_addr: None, // TODO
+            to_addr,
+            result: None,
         })
     }
     /// Zero-Copy Send with the supplied raw buffer which not managed by the bearer.
@@ -70,6 +103,7 @@ impl SendZcRec {
             buf_const_u8,
             buf_size,
             to_addr,
+            result: None,
         })
     }
     #[inline]
@@ -78,17 +112,21 @@ impl SendZcRec {
             Self::Fixed(f_rec) => (
                 f_rec.fixed_fd,
                 f_rec.buf_taken.buf_const_u8,
-                2,
-                //                f_rec.buf_taken.buf_size,
-                None,
-                //                Some(f_rec.buf_taken.buf_kernel_index),
+                f_rec.buf_taken.buf_size,
+                Some(f_rec.buf_taken.buf_kernel_index),
             ),
             Self::UnsafeRef(u_rec) => (u_rec.fixed_fd, u_rec.buf_const_u8, u_rec.buf_size, None),
         };
+        let (dest_addr, dest_addr_len) = match self.to_addr() {
+            Some(to_addr) => to_addr.as_sockaddr_len(),
+            None => (core::ptr::null(), 0),
+        };
 
         io_uring::opcode::SendZc::new(io_uring::types::Fixed(fixed_fd), buf_const_u8, buf_size)
             .buf_index(buf_index)
-            .build() // todo dest_addr(), dest_addr_len(), flags(), zc_flags()
+            .dest_addr(dest_addr)
+            .dest_addr_len(dest_addr_len)
+            .build() // todo flags(), zc_flags()
     }
     #[inline]
     pub(crate) fn owner(&self) -> Owner {
@@ -105,19 +143,90 @@ impl SendZcRec {
         }
         true
     }
+    /// Record the send result and return the ownership upon the final completion which is
+    /// the notification unless the send failed without one (IORING_CQE_F_MORE unset).
+    #[inline]
+    pub(crate) fn completion(&mut self, cqe: &io_uring::cqueue::Entry) {
+        let (owner, result) = match self {
+            Self::Fixed(f) => (&mut f.owner, &mut f.result),
+            Self::UnsafeRef(r) => (&mut r.owner, &mut r.result),
+        };
+        if !io_uring::cqueue::notif(cqe.flags()) {
+            *result = Some(cqe.result());
+        }
+        if !io_uring::cqueue::more(cqe.flags()) {
+            *owner = Owner::Returned;
+        }
+    }
 }
 
 /// When SendTo is sepcified, send(2) is turned into sendto(2)
 #[derive(Clone, Debug)]
-#[allow(dead_code)]
 pub enum DestTo {
+    /// IPv4 destination
     V4(DestToV4),
+    /// IPv6 destination
     V6(DestToV6),
 }
 
+impl DestTo {
+    /// Raw sockaddr pointer and length referring into self
+    #[inline]
+    pub(crate) fn as_sockaddr_len(&self) -> (*const libc::sockaddr, libc::socklen_t) {
+        match self {
+            Self::V4(v4) => (
+                &v4.sockaddr as *const libc::sockaddr_in as *const libc::sockaddr,
+                v4.socklen_t,
+            ),
+            Self::V6(v6) => (
+                &v6.sockaddr as *const libc::sockaddr_in6 as *const libc::sockaddr,
+                v6.socklen_t,
+            ),
+        }
+    }
+}
+
+impl From<SocketAddrV4> for DestTo {
+    fn from(addr: SocketAddrV4) -> Self {
+        // SAFETY: All-zero sockaddr_in is valid
+        let mut sockaddr: libc::sockaddr_in = unsafe { core::mem::zeroed() };
+        sockaddr.sin_family = libc::AF_INET as libc::sa_family_t;
+        sockaddr.sin_port = addr.port().to_be();
+        sockaddr.sin_addr.s_addr = u32::from_ne_bytes(addr.ip().octets());
+        Self::V4(DestToV4 {
+            sockaddr,
+            socklen_t: size_of::<libc::sockaddr_in>() as libc::socklen_t,
+        })
+    }
+}
+
+impl From<SocketAddrV6> for DestTo {
+    fn from(addr: SocketAddrV6) -> Self {
+        // SAFETY: All-zero sockaddr_in6 is valid
+        let mut sockaddr: libc::sockaddr_in6 = unsafe { core::mem::zeroed() };
+        sockaddr.sin6_family = libc::AF_INET6 as libc::sa_family_t;
+        sockaddr.sin6_port = addr.port().to_be();
+        sockaddr.sin6_flowinfo = addr.flowinfo();
+        sockaddr.sin6_addr.s6_addr = addr.ip().octets();
+        sockaddr.sin6_scope_id = addr.scope_id();
+        Self::V6(DestToV6 {
+            sockaddr,
+            socklen_t: size_of::<libc::sockaddr_in6>() as libc::socklen_t,
+        })
+    }
+}
+
+impl From<SocketAddr> for DestTo {
+    fn from(addr: SocketAddr) -> Self {
+        match addr {
+            SocketAddr::V4(v4) => v4.into(),
+            SocketAddr::V6(v6) => v6.into(),
+        }
+    }
+}
+
 /// IPv4 SendTo
 #[derive(Clone, Debug)]
-#[allow(dead_code)]
 pub struct DestToV4 {
     pub(crate) sockaddr: libc::sockaddr_in,
     pub(crate) socklen_t: libc::socklen_t,
@@ -125,7 +234,6 @@ pub struct DestToV4 {
 
 /// IPv6 SendTo
 #[derive(Clone, Debug)]
-#[allow(dead_code)]
 pub struct DestToV6 {
     pub(crate) sockaddr: libc::sockaddr_in6,
     pub(crate) socklen_t: libc::socklen_t,
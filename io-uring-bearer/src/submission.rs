@@ -49,6 +49,22 @@ impl SubmissionFlags {
         self.bits |= IoUringFlags::SKIP_SUCCESS.bits();
         self
     }
+    /// Whether either of the link flags is set
+    #[inline]
+    pub(crate) fn is_linked(&self) -> bool {
+        self.bits & (IoUringFlags::IO_LINK.bits() | IoUringFlags::IO_HARDLINK.bits()) != 0
+    }
+    /// Clear the link flags e.g. for the last link of a chain
+    #[inline]
+    pub(crate) fn off_links(mut self) -> Self {
+        self.bits &= !(IoUringFlags::IO_LINK.bits() | IoUringFlags::IO_HARDLINK.bits());
+        self
+    }
+    /// Whether the successful completion is skipped
+    #[inline]
+    pub(crate) fn is_skip_success(&self) -> bool {
+        self.bits & IoUringFlags::SKIP_SUCCESS.bits() != 0
+    }
     /// Convert to [`io-uring::squeue::Flags`]
     #[inline]
     pub fn to_io_uring_flags(&self) -> Result<io_uring::squeue::Flags, UringBearerError> {
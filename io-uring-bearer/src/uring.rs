@@ -1,28 +1,63 @@
 //! Uring Handler
 
 mod accept;
+mod batch;
 mod buffers;
+mod cancel;
+mod chain;
+mod drain;
+mod fixed_buffers;
 mod futex;
+mod multishot;
 mod recv;
 mod register;
 mod send_zc;
+mod shutdown;
+
+pub use batch::SubmissionBatch;
+pub use chain::ChainLink;
+pub use drain::Enqueue;
 
 #[cfg(feature = "accept_multi")]
 mod accept_multi;
+#[cfg(feature = "bind_listen")]
+mod bind_listen;
 #[cfg(feature = "connect")]
 mod connect;
 #[cfg(feature = "epoll")]
 mod epoll_ctl;
+#[cfg(feature = "fs")]
+mod fs;
+#[cfg(feature = "path")]
+mod path;
+#[cfg(feature = "rw")]
+mod rw;
+#[cfg(feature = "send")]
+mod send;
 #[cfg(feature = "socket")]
 mod socket;
+#[cfg(feature = "sockopt")]
+mod sockopt;
+#[cfg(feature = "splice")]
+mod splice;
+#[cfg(feature = "splice")]
+pub use splice::SplicePipe;
+#[cfg(feature = "timeout")]
+mod timeout;
 
 use crate::error::UringBearerError;
 
+use core::mem::{ManuallyDrop, MaybeUninit};
 use io_uring::IoUring;
+use std::collections::VecDeque;
 
-use crate::completion::SubmissionRecordStatus;
+use crate::completion::{
+    CompletionFlow, MultishotStatus, OpCompletionResult, SubmissionRecordStatus,
+};
 use crate::fixed::FixedFdRegister;
 use crate::SubmissionFlags;
+use chain::Chains;
+use multishot::RearmPolicies;
 
 use crate::slab::BuffersRec;
 use crate::slab::FutexRec;
@@ -30,25 +65,59 @@ use crate::Completion;
 use io_uring_owner::Owner;
 
 use io_uring_opcode::{OpCode, OpCompletion};
+use io_uring_probe::UringCapabilities;
 use slabbable::Slabbable;
 use slabbable_impl_selector::SelectedSlab;
 
+use hashbrown::HashSet as SelectedHashSet;
+use nohash_hasher::BuildNoHashHasher as SelectedHasher;
+
 use crate::BearerCapacityKind;
 use capacity::Capacity;
 use capacity::Setting as CapacitySetting;
 
+/// Completions copied out of the ring at once when spinning it without a caller batch
+pub(crate) const COMPLETION_BATCH: usize = 32;
+
 /// Manage the io_uring Submission and Completion Queues
-pub struct UringBearer<C> {
+///
+/// Upon Drop the in-flight submissions are canceled and waited for before the records
+/// are freed, see [`UringBearer::shutdown`]. The records are leaked instead of freed
+/// if the kernel may still refer to them.
+pub struct UringBearer<C: core::fmt::Debug + Clone + OpCompletion> {
     /// io_uring Managed instance
     pub(crate) io_uring: IoUring<io_uring::squeue::Entry, io_uring::cqueue::Entry>,
-    /// Completion events awaited
-    pub(crate) fd_slab: SelectedSlab<Completion<C>>,
+    /// Completion events awaited, freed only once the kernel no longer refers to them
+    pub(crate) fd_slab: ManuallyDrop<SelectedSlab<Completion<C>>>,
     /// Registred Fds with io_uring
     pub(crate) fd_register: FixedFdRegister,
-    /// Allocated Buffers
-    pub(crate) bufs: SelectedSlab<BuffersRec>,
-    /// Futexes / Atomics
-    pub(crate) futexes: SelectedSlab<FutexRec>,
+    /// Allocated Buffers, freed only once the kernel no longer refers to them
+    pub(crate) bufs: ManuallyDrop<SelectedSlab<BuffersRec>>,
+    /// Futexes / Atomics, freed only once the kernel no longer refers to them
+    pub(crate) futexes: ManuallyDrop<SelectedSlab<FutexRec>>,
+    /// Probed kernel capabilities
+    pub(crate) capabilities: UringCapabilities,
+    /// Capacity of fd_slab pending completions
+    pub(crate) fd_slab_capacity: usize,
+    /// Capacity of bufs
+    pub(crate) bufs_capacity: usize,
+    /// Capacity of futexes
+    pub(crate) futexes_capacity: usize,
+    /// Keys of the records to release upon their final completion, e.g. after cancellation
+    /// or when forgotten while the kernel still has more completions to post for them
+    pub(crate) release_on_final: SelectedHashSet<usize, SelectedHasher<usize>>,
+    /// Re-arm policies of the pending multishot submissions by key
+    pub(crate) rearm_policies: RearmPolicies,
+    /// Completions copied out of the ring but left unhandled upon stopping the spin
+    pub(crate) cq_backlog: VecDeque<io_uring::cqueue::Entry>,
+    /// Linked chains pending the completion of all their links
+    pub(crate) chains: Chains,
+    /// Keys of the pushed submissions pending their final completion
+    pub(crate) in_flight: SelectedHashSet<usize, SelectedHasher<usize>>,
+    /// Buffer group ids of the registered buffer rings
+    pub(crate) buf_rings: Vec<u16>,
+    /// Registered fixed buffers table, the created buffers index at each kernel index
+    pub(crate) fixed_bufs: Vec<Option<usize>>,
 }
 
 impl<C: core::fmt::Debug + Clone + OpCompletion> UringBearer<C> {
@@ -89,30 +158,52 @@ impl<C: core::fmt::Debug + Clone + OpCompletion> UringBearer<C> {
         iou: IoUring<io_uring::squeue::Entry, io_uring::cqueue::Entry>,
         caps: Capacity<H, BearerCapacityKind>,
     ) -> Result<Self, UringBearerError> {
+        let fd_slab_capacity = caps.of_unbounded(&BearerCapacityKind::PendingCompletions);
+        let bufs_capacity = caps.of_unbounded(&BearerCapacityKind::Buffers);
+        let futexes_capacity = caps.of_unbounded(&BearerCapacityKind::Futexes);
+
+        let capabilities = UringCapabilities::probe_opcodes(&iou)?;
         Ok(Self {
             io_uring: iou,
-            fd_slab: SelectedSlab::<Completion<C>>::with_fixed_capacity(
-                caps.of_unbounded(&BearerCapacityKind::PendingCompletions),
-            )
-            .map_err(UringBearerError::Slabbable)?,
+            fd_slab: ManuallyDrop::new(
+                SelectedSlab::<Completion<C>>::with_fixed_capacity(fd_slab_capacity)
+                    .map_err(UringBearerError::Slabbable)?,
+            ),
             fd_register: FixedFdRegister::with_fixed_capacity(
                 caps.of_unbounded(&BearerCapacityKind::RegisteredFd) as u32,
             ),
-            bufs: SelectedSlab::<BuffersRec>::with_fixed_capacity(
-                caps.of_unbounded(&BearerCapacityKind::Buffers),
-            )
-            .map_err(UringBearerError::Slabbable)?,
-            futexes: SelectedSlab::<FutexRec>::with_fixed_capacity(
-                caps.of_unbounded(&BearerCapacityKind::Futexes),
-            )
-            .map_err(UringBearerError::Slabbable)?,
+            bufs: ManuallyDrop::new(
+                SelectedSlab::<BuffersRec>::with_fixed_capacity(bufs_capacity)
+                    .map_err(UringBearerError::Slabbable)?,
+            ),
+            futexes: ManuallyDrop::new(
+                SelectedSlab::<FutexRec>::with_fixed_capacity(futexes_capacity)
+                    .map_err(UringBearerError::Slabbable)?,
+            ),
+            capabilities,
+            fd_slab_capacity,
+            bufs_capacity,
+            futexes_capacity,
+            release_on_final: SelectedHashSet::with_hasher(SelectedHasher::default()),
+            rearm_policies: RearmPolicies::with_hasher(SelectedHasher::default()),
+            cq_backlog: VecDeque::new(),
+            chains: Chains::with_max_uncollected(fd_slab_capacity),
+            in_flight: SelectedHashSet::with_hasher(SelectedHasher::default()),
+            buf_rings: Vec::new(),
+            fixed_bufs: Vec::new(),
         })
     }
+    /// Kernel supported OpCodes probed upon construction, see UringCapabilities::probe
+    /// for the setup flags and the buffer rings.
+    #[inline]
+    pub fn capabilities(&self) -> &UringCapabilities {
+        &self.capabilities
+    }
     /// Spin the completions ring with custom handling without touching the
     /// original submission record
-    pub fn completions<F, U>(&mut self, user: &mut U, func: F) -> Result<(), UringBearerError>
+    pub fn completions<F, U>(&mut self, user: &mut U, mut func: F) -> Result<(), UringBearerError>
     where
-        F: Fn(&mut U, &io_uring::cqueue::Entry, &Completion<C>),
+        F: FnMut(&mut U, &io_uring::cqueue::Entry, &Completion<C>),
     {
         // SAFETY: We Retain the original submission record and don't move it.
         unsafe {
@@ -132,39 +223,212 @@ impl<C: core::fmt::Debug + Clone + OpCompletion> UringBearer<C> {
     /// e.g. EpollEvent record must be retained if it will trigger again given
     /// kernel still refers to it upon it triggering where as upon deleting handle
     /// from EpollCtl it can be only deleted after it has been confirmed as deleted.
+    /// At most limit completions are handled if given, the rest remain on the ring.
     pub unsafe fn handle_completions<F, U>(
         &mut self,
         user: &mut U,
-        #[allow(unused_variables)] limit: Option<u32>,
-        func: F,
+        limit: Option<u32>,
+        mut func: F,
     ) -> Result<(), UringBearerError>
     where
-        F: Fn(&mut U, &io_uring::cqueue::Entry, &Completion<C>) -> SubmissionRecordStatus,
+        F: FnMut(&mut U, &io_uring::cqueue::Entry, &Completion<C>) -> SubmissionRecordStatus,
     {
-        let iou = &mut self.io_uring;
-        let mut c_queue = iou.completion();
-        while let Some(item) = c_queue.next() {
-            let key = item.user_data();
-            let a_rec_t = self
-                .fd_slab
-                .slot_get_ref(key as usize)
-                .map_err(UringBearerError::Slabbable)?;
-
-            if let Some(completed_rec) = a_rec_t {
-                let rec_status = func(user, &item, completed_rec);
-                if rec_status == SubmissionRecordStatus::Forget {
-                    self.fd_slab
-                        .mark_for_reuse(key as usize)
-                        .map_err(UringBearerError::Slabbable)?;
-                }
-            }
+        // SAFETY: Upheld by the caller as per above.
+        unsafe { self.handle_typed_completions(user, limit, |u, e, rec, _| func(u, e, rec)) }
+    }
+    /// Spin the completions ring handing out the typed result decoded through the
+    /// OpCompletion impl of the original submission record without forgetting it.
+    /// Multishot records are released upon termination, see [`UringBearer::lifecycle_completions`].
+    pub fn typed_completions<F, U>(
+        &mut self,
+        user: &mut U,
+        mut func: F,
+    ) -> Result<(), UringBearerError>
+    where
+        F: FnMut(&mut U, &io_uring::cqueue::Entry, &Completion<C>, OpCompletionResult<C>),
+    {
+        // SAFETY: We Retain the original submission record and don't move it.
+        unsafe {
+            self.handle_typed_completions(user, None, |u, e, rec, res| {
+                func(u, e, rec, res);
+                SubmissionRecordStatus::Retain
+            })
+        }
+    }
+    /// Spin the completions ring handing out the typed result decoded through the
+    /// OpCompletion impl of the original submission record. The typed result is None
+    /// for the records native to the bearer, e.g. Accept or Recv.
+    ///
+    /// # Safety
+    ///
+    /// Same as [`handle_completions`], the record must be retained if kernel still refers to it.
+    pub unsafe fn handle_typed_completions<F, U>(
+        &mut self,
+        user: &mut U,
+        limit: Option<u32>,
+        mut func: F,
+    ) -> Result<(), UringBearerError>
+    where
+        F: FnMut(
+            &mut U,
+            &io_uring::cqueue::Entry,
+            &Completion<C>,
+            OpCompletionResult<C>,
+        ) -> SubmissionRecordStatus,
+    {
+        // SAFETY: Upheld by the caller as per above.
+        unsafe {
+            self.handle_lifecycle_completions(user, limit, |u, e, rec, res, _| func(u, e, rec, res))
+        }
+    }
+    /// Spin the completions ring handing out the typed result together with where the
+    /// submission stands in its lifecycle without forgetting the single-shot records.
+    /// Multishot records are released upon termination unless re-armed, see
+    /// [`UringBearer::set_rearm_policy`].
+    pub fn lifecycle_completions<F, U>(
+        &mut self,
+        user: &mut U,
+        mut func: F,
+    ) -> Result<(), UringBearerError>
+    where
+        F: FnMut(
+            &mut U,
+            &io_uring::cqueue::Entry,
+            &Completion<C>,
+            OpCompletionResult<C>,
+            MultishotStatus,
+        ),
+    {
+        // SAFETY: We Retain the original single-shot records and multishot are retained
+        // by the bearer for as long as the kernel refers to them.
+        unsafe {
+            self.handle_lifecycle_completions(user, None, |u, e, rec, res, status| {
+                func(u, e, rec, res, status);
+                SubmissionRecordStatus::Retain
+            })
+        }
+    }
+    /// Spin the completions ring handing out the typed result together with where the
+    /// submission stands in its lifecycle. Multishot records are retained while the kernel
+    /// has more completions to post (IORING_CQE_F_MORE) and released upon the final one
+    /// reported as Terminated, unless re-armed on the same key as per their RearmPolicy.
+    /// Forget on a multishot record releases it upon the final completion without re-arming.
+    /// At most limit completions are handled if given, the rest remain on the ring.
+    ///
+    /// # Safety
+    ///
+    /// Same as [`handle_completions`], the record must be retained if kernel still refers to it.
+    pub unsafe fn handle_lifecycle_completions<F, U>(
+        &mut self,
+        user: &mut U,
+        limit: Option<u32>,
+        mut func: F,
+    ) -> Result<(), UringBearerError>
+    where
+        F: FnMut(
+            &mut U,
+            &io_uring::cqueue::Entry,
+            &Completion<C>,
+            OpCompletionResult<C>,
+            MultishotStatus,
+        ) -> SubmissionRecordStatus,
+    {
+        // SAFETY: Upheld by the caller as per above.
+        unsafe {
+            self._spin(user, limit, &mut |u, _, e, rec, res, status| {
+                Ok::<_, UringBearerError>((func(u, e, rec, res, status), CompletionFlow::Continue))
+            })
+        }
+        .map(|_| ())
+    }
+    /// Spin the completions ring copying up to the length of the given batch of completions
+    /// out of the ring at once before handling these as per [`lifecycle_completions`].
+    /// The ring is synced once per batch. Returns the count of the completions handled.
+    pub fn lifecycle_completions_batch<F, U>(
+        &mut self,
+        user: &mut U,
+        batch: &mut [MaybeUninit<io_uring::cqueue::Entry>],
+        mut func: F,
+    ) -> Result<usize, UringBearerError>
+    where
+        F: FnMut(
+            &mut U,
+            &io_uring::cqueue::Entry,
+            &Completion<C>,
+            OpCompletionResult<C>,
+            MultishotStatus,
+        ),
+    {
+        // SAFETY: We Retain the original single-shot records and multishot are retained
+        // by the bearer for as long as the kernel refers to them.
+        unsafe {
+            self.handle_lifecycle_completions_batch(user, batch, |u, e, rec, res, status| {
+                func(u, e, rec, res, status);
+                SubmissionRecordStatus::Retain
+            })
+        }
+    }
+    /// Spin the completions ring copying up to the length of the given batch of completions
+    /// out of the ring at once before handling these as per [`handle_lifecycle_completions`].
+    /// The ring is synced once per batch. Returns the count of the completions handled.
+    ///
+    /// # Safety
+    ///
+    /// Same as [`handle_completions`], the record must be retained if kernel still refers to it.
+    pub unsafe fn handle_lifecycle_completions_batch<F, U>(
+        &mut self,
+        user: &mut U,
+        batch: &mut [MaybeUninit<io_uring::cqueue::Entry>],
+        mut func: F,
+    ) -> Result<usize, UringBearerError>
+    where
+        F: FnMut(
+            &mut U,
+            &io_uring::cqueue::Entry,
+            &Completion<C>,
+            OpCompletionResult<C>,
+            MultishotStatus,
+        ) -> SubmissionRecordStatus,
+    {
+        let want = batch.len();
+        // SAFETY: Upheld by the caller as per above.
+        unsafe {
+            self._drain(user, batch, want, &mut |u, _, e, rec, res, status| {
+                Ok::<_, UringBearerError>((func(u, e, rec, res, status), CompletionFlow::Continue))
+            })
         }
-        Ok(())
     }
     /// Borrow the underlying io-uring::IoUring instance
     pub fn io_uring(&mut self) -> &mut IoUring<io_uring::squeue::Entry, io_uring::cqueue::Entry> {
         &mut self.io_uring
     }
+    /// Register a provided buffer ring, tracking the buffer group id for unregistering upon shutdown
+    ///
+    /// # Safety
+    ///
+    /// The ring memory must remain valid until unregistered via unregister_buf_ring or shutdown.
+    pub unsafe fn register_buf_ring(
+        &mut self,
+        ring_addr: u64,
+        ring_entries: u16,
+        bgid: u16,
+        flags: u16,
+    ) -> std::io::Result<()> {
+        self.io_uring.submitter().register_buf_ring_with_flags(
+            ring_addr,
+            ring_entries,
+            bgid,
+            flags,
+        )?;
+        self.buf_rings.push(bgid);
+        Ok(())
+    }
+    /// Unregister a provided buffer ring registered via register_buf_ring
+    pub fn unregister_buf_ring(&mut self, bgid: u16) -> std::io::Result<()> {
+        self.io_uring.submitter().unregister_buf_ring(bgid)?;
+        self.buf_rings.retain(|b| *b != bgid);
+        Ok(())
+    }
     /// This calls the underlying io_uring::IoUring::submit submitting all the staged commits
     pub fn submit(&self) -> Result<usize, UringBearerError> {
         self.io_uring
@@ -183,15 +447,12 @@ impl<C: core::fmt::Debug + Clone + OpCompletion> UringBearer<C> {
         op: Op,
         flags: Option<SubmissionFlags>,
     ) -> Result<usize, UringBearerError> {
-        let key = self
-            .fd_slab
-            .take_next_with(Completion::Op(op.submission()?))
-            .map_err(UringBearerError::Slabbable)?;
+        let submission = op.submission()?;
+        self._submission_supported(&submission)?;
 
-        match self._push_to_completion(key, flags) {
-            Err(e) => Err(e),
-            Ok(()) => Ok(key),
-        }
+        let key = self._take_record(Completion::Op(submission))?;
+
+        self._push_new(key, flags)
     }
     /// Push a pending typed Completion directly
     pub fn push_op_typed(
@@ -199,16 +460,93 @@ impl<C: core::fmt::Debug + Clone + OpCompletion> UringBearer<C> {
         op: Completion<C>,
         flags: Option<SubmissionFlags>,
     ) -> Result<usize, UringBearerError> {
-        let key = self
-            .fd_slab
-            .take_next_with(op)
-            .map_err(UringBearerError::Slabbable)?;
+        match op.opcode() {
+            Some(opcode) => self._op_supported(opcode)?,
+            None => return Err(UringBearerError::TypedNotPushable),
+        }
+        let key = self._take_record(op)?;
 
+        self._push_new(key, flags)
+    }
+    /// Refuse OpCodes the running kernel does not support before pushing
+    #[inline]
+    pub(crate) fn _op_supported(&self, opcode: u8) -> Result<(), UringBearerError> {
+        match self.capabilities.is_supported(opcode) {
+            true => Ok(()),
+            false => Err(UringBearerError::OpNotSupported(opcode)),
+        }
+    }
+    /// Refuse the submission if its OpCode is not supported, returning the buffers taken for it
+    #[inline]
+    pub(crate) fn _submission_supported(&mut self, submission: &C) -> Result<(), UringBearerError> {
+        let r = self._op_supported(submission.opcode());
+        if r.is_err() {
+            self._return_taken(submission.buf_idxs());
+        }
+        r
+    }
+    /// Take the record into fd_slab, returning the buffers taken for it if there is no room
+    #[inline]
+    pub(crate) fn _take_record(&mut self, rec: Completion<C>) -> Result<usize, UringBearerError> {
+        let buf_idxs = rec.buf_idxs().to_vec();
+        match self.fd_slab.take_next_with(rec) {
+            Ok(key) => Ok(key),
+            Err(e) => {
+                self._return_taken(&buf_idxs);
+                Err(UringBearerError::Slabbable(e))
+            }
+        }
+    }
+    /// Return the buffers taken for a submission that was never pushed so they can be taken again
+    #[inline]
+    pub(crate) fn _return_taken(&mut self, buf_idxs: &[usize]) {
+        for buf_idx in buf_idxs {
+            if let Ok(Some(bufs)) = self.bufs.slot_get_mut(*buf_idx) {
+                if bufs.owner() == Owner::Taken {
+                    bufs.force_owner_reusable();
+                }
+            }
+        }
+    }
+    /// Push the record just taken into fd_slab, releasing it if it could not be pushed
+    #[inline]
+    pub(crate) fn _push_new(
+        &mut self,
+        key: usize,
+        flags: Option<SubmissionFlags>,
+    ) -> Result<usize, UringBearerError> {
         match self._push_to_completion(key, flags) {
-            Err(e) => Err(e),
             Ok(()) => Ok(key),
+            Err(e) => {
+                self._release_unpushed(&[key])?;
+                Err(e)
+            }
         }
     }
+    /// Push the entry built for the record just taken into fd_slab by the records pushed
+    /// only through their dedicated push methods e.g. Accept or FutexWait, releasing the
+    /// record if it could not be pushed
+    #[inline]
+    pub(crate) fn _push_new_entry(
+        &mut self,
+        key: usize,
+        entry: io_uring::squeue::Entry,
+    ) -> Result<usize, UringBearerError> {
+        // SAFETY: The record the entry refers to is backed in the Slabbable store, see
+        // _push_to_completion.
+        let pushed = unsafe {
+            self.io_uring
+                .submission()
+                .push(&entry.user_data(key as u64))
+        };
+        if pushed.is_err() {
+            self._release_unpushed(&[key])?;
+            return Err(UringBearerError::SubmissionPush);
+        }
+        self.in_flight.insert(key);
+        Ok(key)
+    }
+    /// Push the record at the key, its ownership passing to the kernel only once pushed
     #[inline]
     pub(crate) fn _push_to_completion(
         &mut self,
@@ -229,38 +567,38 @@ impl<C: core::fmt::Debug + Clone + OpCompletion> UringBearer<C> {
             .slot_get_mut(idx)
             .map_err(UringBearerError::Slabbable)?;
 
-        let submission = match completion_rec {
+        let completion = match completion_rec {
             Some(completion) => {
                 if completion.owner() == Owner::Kernel {
                     return Err(UringBearerError::InvalidOwnership(completion.owner(), idx));
                 }
-                completion.force_owner_kernel();
-                completion.entry().flags(flags).user_data(idx as u64)
+                completion
             }
             _ => return Err(UringBearerError::SlabBugSetGet("Submisison not found?")),
         };
+        let submission = completion.entry().flags(flags).user_data(idx as u64);
         //                bufs_rec_ref.force_owner_kernel();
         // SAFETY: We are backing the buffer & submission in the Slabbable stores. BufferRec buffer must not move
         // from the referred address nor otherwise manipulated or invalidated until the ownership passes back to userspace
         // or when the buffer/s are confirmed removed via RemoveBuffers otherwise.
         match unsafe { s_queue.push(&submission) } {
-            Ok(_) => Ok(()),
+            Ok(_) => {
+                completion.force_owner_kernel();
+                for buf_idx in completion.buf_idxs() {
+                    if let Ok(Some(bufs)) = self.bufs.slot_get_mut(*buf_idx) {
+                        bufs.force_owner_kernel();
+                    }
+                }
+                self.in_flight.insert(idx);
+                Ok(())
+            }
             Err(_) => Err(UringBearerError::SubmissionPush),
         }
     }
     // TODO: Rework - this should be generic and FdKind'ed
     #[inline]
-    fn _fixed_fd_validate(&self, _try_fixed_fd: u32) -> bool {
-        // TODO: provide external entity (yaoi) better API to set things registered
-        return true;
-        /*
-            if _try_fixed_fd > self.fd_register.capacity() - 1 {
-                return false;
-            }
-            match self.fd_register.get(_try_fixed_fd) {
-                Some((_, _itm)) => true,
-                _ => false,
-        }
-            */
+    fn _fixed_fd_validate(&self, try_fixed_fd: u32) -> bool {
+        // Manual installs in-flight may be linked to the submissions using the slot
+        self.fd_register.get(try_fixed_fd).is_some() || self.fd_register.is_installing(try_fixed_fd)
     }
 }
@@ -8,6 +8,7 @@ use crate::Completion;
 use crate::RawFd;
 use crate::TargetFd;
 
+use io_uring_fd::FdKind;
 use io_uring_opcode::OpCompletion;
 
 use io_uring::types::DestinationSlot;
@@ -44,44 +45,40 @@ impl<C: core::fmt::Debug + Clone + OpCompletion> UringBearer<C> {
         v6: bool,
         target_fd: TargetFd,
     ) -> Result<(), UringBearerError> {
-        let iou = &mut self.io_uring;
-        let mut s_queue = iou.submission();
-
-        let key = match v6 {
-            true => self
-                .fd_slab
-                .take_next_with(Completion::Accept(crate::slab::accept::init_accept_rec6())),
-            false => self
-                .fd_slab
-                .take_next_with(Completion::Accept(crate::slab::accept::init_accept_rec4())),
-        }
-        .map_err(UringBearerError::Slabbable)?;
-        let a_rec_t = self
-            .fd_slab
-            .slot_get_ref(key)
-            .map_err(UringBearerError::Slabbable)?;
-        let dest_slot = match target_fd {
-            TargetFd::Unregistered => None,
-            TargetFd::AutoRegistered => Some(DestinationSlot::auto_target()),
+        let (dest_slot, install) = match target_fd {
+            TargetFd::Unregistered => (None, None),
+            TargetFd::AutoRegistered => (Some(DestinationSlot::auto_target()), Some(None)),
             TargetFd::ManualRegistered(try_slot) => {
+                self._fixed_target_validate(Some(try_slot))?;
                 let dest_slot = DestinationSlot::try_from_slot_target(try_slot)
                     .map_err(|_| UringBearerError::InvalidTargetFd(try_slot))?;
-                Some(dest_slot)
+                (Some(dest_slot), Some(Some(try_slot)))
             }
         };
+        let key = match v6 {
+            true => self._take_record(Completion::Accept(crate::slab::accept::init_accept_rec6())),
+            false => self._take_record(Completion::Accept(crate::slab::accept::init_accept_rec4())),
+        }?;
+        let a_rec_t = self
+            .fd_slab
+            .slot_get_ref(key)
+            .map_err(UringBearerError::Slabbable)?;
         let flags = libc::EFD_NONBLOCK & libc::EFD_CLOEXEC;
 
-        match a_rec_t {
+        let accept_rec = match a_rec_t {
             Some(Completion::Accept(a_rec_k)) => {
-                let accept_rec =
-                    crate::slab::accept::entry(fd, a_rec_k, dest_slot, flags).user_data(key as u64);
-                let _accept = unsafe { s_queue.push(&accept_rec) };
+                crate::slab::accept::entry(fd, a_rec_k, dest_slot, flags)
             }
             _ => {
                 return Err(UringBearerError::SlabBugSetGet(
                     "Accept not found after set?",
                 ));
             }
+        };
+        self._push_new_entry(key, accept_rec)?;
+        if let Some(manual_slot) = install {
+            self.fd_register
+                .expect_install(key, manual_slot, FdKind::RecvSend);
         }
 
         Ok(())
@@ -4,12 +4,15 @@ use super::UringBearer;
 use crate::error::UringBearerError;
 use crate::Completion;
 use crate::SubmissionFlags;
+use io_uring_fd::FdKind;
 use io_uring_opcode::OpExtAcceptMulti;
 use io_uring_opcode::{OpCode, OpCompletion};
 use slabbable::Slabbable;
 
 impl<C: core::fmt::Debug + Clone + OpCompletion> UringBearer<C> {
     /// Push a AcceptMulti implementing OpCode +  traits (see io-uring-opcode)
+    ///
+    /// The fixed slots installed upon each completion are recorded as registered filehandles.
     pub fn push_accept_multi<Op>(
         &mut self,
         op: Op,
@@ -18,14 +21,19 @@ impl<C: core::fmt::Debug + Clone + OpCompletion> UringBearer<C> {
     where
         Op: OpCode<C> + OpExtAcceptMulti,
     {
+        let accept_fixed = op.is_accept_fixed();
+        let submission = op.submission()?;
+        self._op_supported(submission.opcode())?;
+
         let key = self
             .fd_slab
-            .take_next_with(Completion::AcceptMulti(op.submission()?))
+            .take_next_with(Completion::AcceptMulti(submission))
             .map_err(UringBearerError::Slabbable)?;
 
-        match self._push_to_completion(key, flags) {
-            Err(e) => Err(e),
-            Ok(()) => Ok(key),
+        self._push_new(key, flags)?;
+        if accept_fixed {
+            self.fd_register.expect_install(key, None, FdKind::RecvSend);
         }
+        Ok(key)
     }
 }
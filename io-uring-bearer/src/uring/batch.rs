@@ -0,0 +1,182 @@
+//! Pushing many submissions at once with the submission queue space reserved up front
+
+use super::UringBearer;
+use crate::error::UringBearerError;
+use crate::Completion;
+use crate::SubmissionFlags;
+
+use io_uring_opcode::{OpCode, OpCompletion};
+use io_uring_owner::Owner;
+use slabbable::Slabbable;
+
+/// Batch of submissions pushed into the submission queue all or nothing, see
+/// [`UringBearer::submission_batch`]. The pushed submissions are staged for the next
+/// submit unless auto submit had to submit them to make room for the later ones.
+pub struct SubmissionBatch<'b, C: core::fmt::Debug + Clone + OpCompletion> {
+    bearer: &'b mut UringBearer<C>,
+    auto_submit: bool,
+    keys: Vec<usize>,
+}
+
+impl<C: core::fmt::Debug + Clone + OpCompletion> UringBearer<C> {
+    /// Begin a batch of submissions, see [`SubmissionBatch`]
+    pub fn submission_batch(&mut self) -> SubmissionBatch<'_, C> {
+        SubmissionBatch {
+            bearer: self,
+            auto_submit: false,
+            keys: Vec::new(),
+        }
+    }
+    /// Ensure the submission queue has room for the needed entries, submitting the staged
+    /// ones first to make room if auto_submit is set.
+    pub(crate) fn _sq_reserve(
+        &mut self,
+        needed: usize,
+        auto_submit: bool,
+    ) -> Result<(), UringBearerError> {
+        let free = self._sq_free();
+        if free >= needed {
+            return Ok(());
+        }
+        if auto_submit && needed <= self.io_uring.params().sq_entries() as usize {
+            self.submit()?;
+            if self._sq_free() >= needed {
+                return Ok(());
+            }
+        }
+        Err(UringBearerError::SubmissionQueueFull(
+            needed,
+            self._sq_free(),
+        ))
+    }
+    #[inline]
+    fn _sq_free(&mut self) -> usize {
+        let s_queue = self.io_uring.submission();
+        s_queue.capacity() - s_queue.len()
+    }
+    /// Push the records at the keys contiguously, none of these if any can not be pushed.
+    /// The records are not released upon error.
+    pub(crate) fn _push_records(
+        &mut self,
+        recs: &[(usize, Option<SubmissionFlags>)],
+        auto_submit: bool,
+    ) -> Result<(), UringBearerError> {
+        let mut entries = Vec::with_capacity(recs.len());
+        for (key, in_flags) in recs {
+            let flags = in_flags.unwrap_or_default().to_io_uring_flags()?;
+            match self.fd_slab.slot_get_ref(*key) {
+                Ok(Some(completion)) if completion.owner() == Owner::Kernel => {
+                    return Err(UringBearerError::InvalidOwnership(completion.owner(), *key))
+                }
+                Ok(Some(completion)) => {
+                    entries.push(completion.entry().flags(flags).user_data(*key as u64))
+                }
+                Ok(None) => return Err(UringBearerError::SlabBugSetGet("Submisison not found?")),
+                Err(e) => return Err(UringBearerError::Slabbable(e)),
+            }
+        }
+        self._sq_reserve(entries.len(), auto_submit)?;
+        // SAFETY: We are backing the buffers & submissions in the Slabbable stores, see
+        // _push_to_completion.
+        unsafe { self.io_uring.submission().push_multiple(&entries) }
+            .map_err(|_| UringBearerError::SubmissionPush)?;
+        for (key, _) in recs {
+            self.in_flight.insert(*key);
+            if let Ok(Some(completion)) = self.fd_slab.slot_get_mut(*key) {
+                completion.force_owner_kernel();
+                for buf_idx in completion.buf_idxs() {
+                    if let Ok(Some(bufs)) = self.bufs.slot_get_mut(*buf_idx) {
+                        bufs.force_owner_kernel();
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+    /// Release the records taken for the submissions that could not be pushed, returning
+    /// the buffers taken for these.
+    pub(crate) fn _release_unpushed(&mut self, keys: &[usize]) -> Result<(), UringBearerError> {
+        for key in keys {
+            if let Ok(Some(completion)) = self.fd_slab.slot_get_ref(*key) {
+                let buf_idxs = completion.buf_idxs().to_vec();
+                self._return_taken(&buf_idxs);
+            }
+            self.fd_slab
+                .mark_for_reuse(*key)
+                .map_err(UringBearerError::Slabbable)?;
+        }
+        Ok(())
+    }
+}
+
+impl<C: core::fmt::Debug + Clone + OpCompletion> SubmissionBatch<'_, C> {
+    /// Submit the staged submissions to make room when the submission queue is full
+    /// instead of failing with [`UringBearerError::SubmissionQueueFull`]
+    pub fn auto_submit(mut self, auto_submit: bool) -> Self {
+        self.auto_submit = auto_submit;
+        self
+    }
+    /// Reserve room for the given count of submissions up front, submitting the staged
+    /// ones if auto submit is set.
+    pub fn reserve(&mut self, needed: usize) -> Result<(), UringBearerError> {
+        self.bearer._sq_reserve(needed, self.auto_submit)
+    }
+    /// Push a general Op implementing OpCode trait (see io-uring-opcode)
+    pub fn push_op<Op: OpCode<C>>(
+        &mut self,
+        op: Op,
+        flags: Option<SubmissionFlags>,
+    ) -> Result<usize, UringBearerError> {
+        let keys = self.push_multiple([op], flags)?;
+        Ok(keys[0])
+    }
+    /// Push all the Ops contiguously or none of these. The records taken for the Ops are
+    /// rolled back if any of the Ops fails or the submission queue has no room for all.
+    /// Returns the keys in the order of the Ops.
+    pub fn push_multiple<Op, I>(
+        &mut self,
+        ops: I,
+        flags: Option<SubmissionFlags>,
+    ) -> Result<Vec<usize>, UringBearerError>
+    where
+        Op: OpCode<C>,
+        I: IntoIterator<Item = Op>,
+    {
+        let mut recs = Vec::new();
+        for op in ops {
+            match self._take_op(op) {
+                Ok(key) => recs.push((key, flags)),
+                Err(e) => return self._rollback(&recs, e),
+            }
+        }
+        if let Err(e) = self.bearer._push_records(&recs, self.auto_submit) {
+            return self._rollback(&recs, e);
+        }
+        let keys: Vec<usize> = recs.iter().map(|(key, _)| *key).collect();
+        self.keys.extend_from_slice(&keys);
+        Ok(keys)
+    }
+    /// Keys of all the submissions pushed through the batch so far
+    pub fn keys(&self) -> &[usize] {
+        &self.keys
+    }
+    /// Submit the batch, returning the keys of all the submissions pushed through it
+    pub fn submit(self) -> Result<Vec<usize>, UringBearerError> {
+        self.bearer.submit()?;
+        Ok(self.keys)
+    }
+    fn _take_op<Op: OpCode<C>>(&mut self, op: Op) -> Result<usize, UringBearerError> {
+        let submission = op.submission()?;
+        self.bearer._submission_supported(&submission)?;
+        self.bearer._take_record(Completion::Op(submission))
+    }
+    fn _rollback<T>(
+        &mut self,
+        recs: &[(usize, Option<SubmissionFlags>)],
+        e: UringBearerError,
+    ) -> Result<T, UringBearerError> {
+        let keys: Vec<usize> = recs.iter().map(|(key, _)| *key).collect();
+        self.bearer._release_unpushed(&keys)?;
+        Err(e)
+    }
+}
@@ -0,0 +1,35 @@
+//! Interaface for pushing Bind / Listen implementing OpExtBindListen
+
+use super::UringBearer;
+use crate::error::UringBearerError;
+use crate::Completion;
+use crate::SubmissionFlags;
+use io_uring_opcode::OpExtBindListen;
+use io_uring_opcode::{OpCode, OpCompletion};
+use slabbable::Slabbable;
+
+impl<C: core::fmt::Debug + Clone + OpCompletion> UringBearer<C> {
+    /// Push a Bind or Listen implementing OpCode + traits (see io-uring-opcode)
+    /// on the registered fixed filehandle e.g. installed by a fixed Socket.
+    pub fn push_bind_listen<Op>(
+        &mut self,
+        op: Op,
+        flags: Option<SubmissionFlags>,
+    ) -> Result<usize, UringBearerError>
+    where
+        Op: OpCode<C> + OpExtBindListen,
+    {
+        if !self._fixed_fd_validate(op.fixed_fd()) {
+            return Err(UringBearerError::FdNotRegistered(op.fixed_fd()));
+        }
+        let submission = op.submission()?;
+        self._op_supported(submission.opcode())?;
+
+        let key = self
+            .fd_slab
+            .take_next_with(Completion::BindListen(submission))
+            .map_err(UringBearerError::Slabbable)?;
+
+        self._push_new(key, flags)
+    }
+}
@@ -14,15 +14,32 @@ use std::pin::Pin;
 use crate::slab::buffer::{TakenImmutableBuffer, TakenMutableBuffer};
 use io_uring_opcode::OpCompletion;
 
+/// Kernel index carried by the buffers taken without being registered as fixed
+const UNFIXED_KERNEL_INDEX: u16 = u16::MAX;
+
 impl<C: core::fmt::Debug + Clone + OpCompletion> UringBearer<C> {
-    /// Internal API for use of Recv handing a single buffer.
+    /// Take a single buffer for the kernel to fill e.g. by Recv or Read.
+    /// The buffer is owned by the kernel once the submission referring to it through
+    /// OpCompletion::buf_idxs is pushed and the bearer returns it for re-use upon the
+    /// final completion, or right away if the push fails. Use
+    /// [`UringBearer::return_taken_buffer`] if it ends up never being pushed.
+    ///
+    /// The taken buffer carries no kernel index (u16::MAX) and is refused by the fixed
+    /// submissions, use [`UringBearer::take_one_fixed_mutable_buffer`] for those instead.
     ///
     /// # Limitation
     ///
     /// Only take num_bufs == 1 buffers as the underlying type is not designed for splitting it up.
-    pub(crate) fn take_one_mutable_buffer(
+    pub fn take_one_mutable_buffer(
         &mut self,
         buf_idx: usize,
+    ) -> Result<TakenMutableBuffer, UringBearerError> {
+        self._take_one_mutable_buffer(buf_idx, UNFIXED_KERNEL_INDEX)
+    }
+    pub(crate) fn _take_one_mutable_buffer(
+        &mut self,
+        buf_idx: usize,
+        buf_kernel_idx: u16,
     ) -> Result<TakenMutableBuffer, UringBearerError> {
         let buf_ref = match self.bufs.slot_get_mut(buf_idx) {
             Ok(Some(buf)) => buf,
@@ -30,16 +47,19 @@ impl<C: core::fmt::Debug + Clone + OpCompletion> UringBearer<C> {
             Err(e) => return Err(UringBearerError::Slabbable(e)),
         };
         Ok(
-            crate::slab::buffer::take_one_mutable_buffer_raw(buf_idx, buf_ref)
+            crate::slab::buffer::take_one_mutable_buffer_raw(buf_idx, buf_kernel_idx, buf_ref)
                 .map_err(UringBearerError::BufferTake)?,
         )
     }
-    /// Internal API for use of Send/Zc handing out a single buffer.
+    /// Take a single buffer for Read-Only use e.g. by Send, SendMsg or SendZc.
+    /// The buffer is owned by the kernel once the submission referring to it through
+    /// OpCompletion::buf_idxs is pushed and the bearer returns it for re-use upon the
+    /// final completion, or right away if the push fails.
     ///
     /// # Limitation
     ///
     /// Only take num_bufs == 1 buffers as the underlying type is not designed for splitting it up.
-    pub(crate) fn take_one_immutable_buffer(
+    pub fn take_one_immutable_buffer(
         &mut self,
         buf_idx: usize,
         buf_kernel_idx: u16,
@@ -54,6 +74,19 @@ impl<C: core::fmt::Debug + Clone + OpCompletion> UringBearer<C> {
                 .map_err(UringBearerError::BufferTake)?,
         )
     }
+    /// Give back the buffer taken via one of the take methods but never pushed within a
+    /// submission so that it can be taken again.
+    pub fn return_taken_buffer(&mut self, buf_idx: usize) -> Result<(), UringBearerError> {
+        match self.bufs.slot_get_mut(buf_idx) {
+            Ok(Some(buf)) if buf.owner() == Owner::Taken => {
+                buf.force_owner_reusable();
+                Ok(())
+            }
+            Ok(Some(_)) => Err(UringBearerError::BufferNoOwnership(buf_idx)),
+            Ok(None) => Err(UringBearerError::BufferNotExist(buf_idx)),
+            Err(e) => Err(UringBearerError::Slabbable(e)),
+        }
+    }
 }
 
 impl<C: core::fmt::Debug + Clone + OpCompletion> UringBearer<C> {
@@ -117,6 +150,7 @@ impl<C: core::fmt::Debug + Clone + OpCompletion> UringBearer<C> {
     /// Destroy earlier created buffer-set by it's index.
     pub fn destroy_buffers(&mut self, id: usize) -> Result<(), UringBearerError> {
         match self.bufs.slot_get_ref(id) {
+            Ok(Some(itm)) if itm.is_fixed_pinned() => Err(UringBearerError::BufferFixedPinned(id)),
             Ok(Some(itm)) => match itm.owner() {
                 Owner::Kernel => Err(UringBearerError::BufferNoOwnership(id)),
                 _ => {
@@ -138,7 +172,7 @@ impl<C: core::fmt::Debug + Clone + OpCompletion> UringBearer<C> {
         let bufs_rec_ref = match self.bufs.slot_get_mut(created_buf_idx) {
             Err(e) => return Err(UringBearerError::Slabbable(e)),
             Ok(Some(ret)) => match ret.owner() {
-                Owner::Created | Owner::Registered => ret,
+                Owner::Created | Owner::Registered | Owner::Reusable => ret,
                 _ => return Err(UringBearerError::BufferNoOwnership(created_buf_idx)),
             },
             Ok(None) => return Err(UringBearerError::BufferNoOwnership(created_buf_idx)),
@@ -213,6 +247,8 @@ impl<C: core::fmt::Debug + Clone + OpCompletion> UringBearer<C> {
         match unsafe { s_queue.push(&submission) } {
             Ok(_) => {
                 bufs_rec_ref.force_owner_kernel();
+                bufs_rec_ref.provided = true;
+                self.in_flight.insert(key);
                 Ok(key)
             }
             Err(_) => Err(UringBearerError::SubmissionPush),
@@ -0,0 +1,187 @@
+//! AsyncCancel API Surface
+
+use super::UringBearer;
+use crate::error::UringBearerError;
+use crate::slab::{AsyncCancelRec, CancelTarget};
+use crate::Completion;
+use crate::SubmissionFlags;
+use core::time::Duration;
+use io_uring::types::{CancelBuilder, Fixed, Timespec};
+use io_uring_opcode::OpCompletion;
+use io_uring_owner::Owner;
+use slabbable::Slabbable;
+
+impl<C: core::fmt::Debug + Clone + OpCompletion> UringBearer<C> {
+    /// Cancel the in-flight submission identified by the key returned upon pushing it.
+    /// The canceled record is released once its final completion has been handled. If the
+    /// AsyncCancel completes with -ENOENT the submission had already completed and its
+    /// record is left as it was.
+    /// Errors with NotInFlight if the submission has already completed.
+    /// Returns the key of the AsyncCancel submission.
+    pub fn cancel_by_key(
+        &mut self,
+        key: usize,
+        flags: Option<SubmissionFlags>,
+    ) -> Result<usize, UringBearerError> {
+        match self.fd_slab.slot_get_ref(key) {
+            Ok(Some(_)) if self.in_flight.contains(&key) => {}
+            Ok(Some(_)) => return Err(UringBearerError::NotInFlight(key)),
+            Ok(None) => return Err(UringBearerError::KeyNotExist(key)),
+            Err(e) => return Err(UringBearerError::Slabbable(e)),
+        }
+        let cancel_key = self._push_cancel(CancelTarget::Key(key), flags)?;
+        self.release_on_final.insert(key);
+        Ok(cancel_key)
+    }
+    /// Cancel all the in-flight submissions targeting the fixed filehandle.
+    /// Returns the key of the AsyncCancel submission.
+    pub fn cancel_by_fixed_fd(
+        &mut self,
+        fixed_fd: u32,
+        flags: Option<SubmissionFlags>,
+    ) -> Result<usize, UringBearerError> {
+        let cancel_key = self._push_cancel(CancelTarget::FixedFd(fixed_fd), flags)?;
+        self._track_canceling(|rec| rec.fixed_fd() == Some(fixed_fd));
+        Ok(cancel_key)
+    }
+    /// Cancel all the in-flight submissions.
+    /// Returns the key of the AsyncCancel submission.
+    pub fn cancel_all(
+        &mut self,
+        flags: Option<SubmissionFlags>,
+    ) -> Result<usize, UringBearerError> {
+        let cancel_key = self._push_cancel(CancelTarget::All, flags)?;
+        self._track_canceling(|_| true);
+        Ok(cancel_key)
+    }
+    /// Cancel the matched in-flight submissions synchronously, blocking until they are
+    /// canceled or the optional timeout expires. Requires Linux 6.0.
+    ///
+    /// Afterwards the canceled records are released upon their final completion, the
+    /// buffers they referred to are reconciled to Owner::Reusable so that these can be
+    /// taken again or destroyed and the futexes to Owner::Returned.
+    ///
+    /// Returns the count of the reconciled submission records.
+    pub fn cancel_sync(
+        &mut self,
+        target: CancelTarget,
+        timeout: Option<Duration>,
+    ) -> Result<usize, UringBearerError> {
+        // Only the submitted are considered for cancellation
+        self.submit()?;
+
+        let builder = match target {
+            CancelTarget::Key(key) => CancelBuilder::user_data(key as u64),
+            CancelTarget::FixedFd(fixed_fd) => CancelBuilder::fd(Fixed(fixed_fd)).all(),
+            CancelTarget::All => CancelBuilder::any(),
+        };
+        match self
+            .io_uring
+            .submitter()
+            .register_sync_cancel(timeout.map(Timespec::from), builder)
+        {
+            Ok(()) => {}
+            Err(e) if e.raw_os_error() == Some(libc::ENOENT) => {}
+            Err(e) if e.raw_os_error() == Some(libc::ETIME) => {
+                return Err(UringBearerError::CancelTimeout)
+            }
+            Err(e) => return Err(UringBearerError::CancelSync(e.to_string())),
+        }
+
+        let mut reconciled = 0;
+        for key in 0..self.fd_slab_capacity {
+            match self.fd_slab.slot_get_ref(key) {
+                Ok(Some(Completion::AsyncCancel(_))) => continue,
+                Ok(Some(rec)) => {
+                    let matching = match target {
+                        CancelTarget::Key(target_key) => key == target_key,
+                        CancelTarget::FixedFd(fixed_fd) => rec.fixed_fd() == Some(fixed_fd),
+                        CancelTarget::All => true,
+                    };
+                    // Records already completed have no final completion left to release them
+                    if !matching || !self.in_flight.contains(&key) {
+                        continue;
+                    }
+                }
+                _ => continue,
+            }
+            self._reconcile_returned(key);
+            self.release_on_final.insert(key);
+            reconciled += 1;
+        }
+
+        if target == CancelTarget::All {
+            self._reconcile_all_returned();
+        }
+        Ok(reconciled)
+    }
+    /// Return the buffers and futexes the record refers to once the kernel no longer does.
+    pub(crate) fn _reconcile_returned(&mut self, key: usize) {
+        let futex_idx = match self.fd_slab.slot_get_ref(key) {
+            Ok(Some(Completion::FutexWait(r))) => Some(r.futex_idx()),
+            Ok(Some(rec)) => {
+                for buf_idx in rec.buf_idxs() {
+                    if let Ok(Some(bufs)) = self.bufs.slot_get_mut(*buf_idx) {
+                        bufs.force_owner_reusable();
+                    }
+                }
+                None
+            }
+            _ => None,
+        };
+        if let Some(futex_idx) = futex_idx {
+            if let Ok(Some(futex)) = self.futexes.slot_get_mut(futex_idx) {
+                futex.force_owner_returned();
+            }
+        }
+    }
+    /// Return all the kernel owned buffers and futexes once nothing is in-flight anymore.
+    /// The buffers provided into a buffer group remain with the kernel which may still
+    /// select them for the next buffer select submission.
+    pub(crate) fn _reconcile_all_returned(&mut self) {
+        for buf_idx in 0..self.bufs_capacity {
+            if let Ok(Some(bufs)) = self.bufs.slot_get_mut(buf_idx) {
+                if bufs.owner() == Owner::Kernel && !bufs.provided {
+                    bufs.force_owner_reusable();
+                }
+            }
+        }
+        for futex_idx in 0..self.futexes_capacity {
+            if let Ok(Some(futex)) = self.futexes.slot_get_mut(futex_idx) {
+                if futex.owner() == Owner::Kernel {
+                    futex.force_owner_returned();
+                }
+            }
+        }
+    }
+    fn _push_cancel(
+        &mut self,
+        target: CancelTarget,
+        flags: Option<SubmissionFlags>,
+    ) -> Result<usize, UringBearerError> {
+        self._op_supported(io_uring::opcode::AsyncCancel::CODE)?;
+
+        let key = self
+            .fd_slab
+            .take_next_with(Completion::AsyncCancel(AsyncCancelRec::new(target)))
+            .map_err(UringBearerError::Slabbable)?;
+
+        self._push_new(key, flags)
+    }
+    /// Mark the in-flight records matching for release upon their final completion.
+    fn _track_canceling<F>(&mut self, matching: F)
+    where
+        F: Fn(&Completion<C>) -> bool,
+    {
+        for key in 0..self.fd_slab_capacity {
+            if let Ok(Some(rec)) = self.fd_slab.slot_get_ref(key) {
+                if self.in_flight.contains(&key)
+                    && !matches!(rec, Completion::AsyncCancel(_))
+                    && matching(rec)
+                {
+                    self.release_on_final.insert(key);
+                }
+            }
+        }
+    }
+}
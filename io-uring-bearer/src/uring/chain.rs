@@ -0,0 +1,410 @@
+//! Linked chains of submissions reported as a whole
+
+use super::UringBearer;
+use crate::completion::{ChainResult, LinkOutcome};
+use crate::error::UringBearerError;
+use crate::fixed::InstallTracking;
+use crate::Completion;
+use crate::SubmissionFlags;
+#[cfg(feature = "socket")]
+use io_uring_fd::FdKind;
+use io_uring_opcode::{OpCode, OpCompletion};
+
+use hashbrown::HashMap as SelectedHashMap;
+use nohash_hasher::BuildNoHashHasher as SelectedHasher;
+use std::collections::VecDeque;
+
+/// A link to push within a chain via [`UringBearer::push_chain`]
+pub struct ChainLink<C> {
+    rec: Completion<C>,
+    flags: Option<SubmissionFlags>,
+    install: Option<InstallTracking>,
+    fixed_fd: Option<u32>,
+    opcode: u8,
+    is_link_timeout: bool,
+}
+
+impl<C: core::fmt::Debug + Clone + OpCompletion> ChainLink<C> {
+    /// General Op implementing OpCode trait (see io-uring-opcode)
+    pub fn op<Op: OpCode<C>>(op: Op) -> Result<Self, UringBearerError> {
+        let submission = op.submission()?;
+        Ok(Self::submitted(
+            submission.opcode(),
+            Completion::Op(submission),
+        ))
+    }
+    /// Pending typed Completion directly, refused for the records pushed only through
+    /// their dedicated push methods e.g. Accept, ProvideBuffers or FutexWait.
+    pub fn typed(rec: Completion<C>) -> Result<Self, UringBearerError> {
+        match rec.opcode() {
+            Some(opcode) => Ok(Self::submitted(opcode, rec)),
+            None => Err(UringBearerError::TypedNotPushable),
+        }
+    }
+    fn submitted(opcode: u8, rec: Completion<C>) -> Self {
+        ChainLink {
+            rec,
+            flags: None,
+            install: None,
+            fixed_fd: None,
+            opcode,
+            is_link_timeout: false,
+        }
+    }
+    /// Socket implementing OpExtSocket, the fixed slot installed is recorded as per
+    /// [`UringBearer::push_socket`].
+    #[cfg(feature = "socket")]
+    pub fn socket<Op>(op: Op) -> Result<Self, UringBearerError>
+    where
+        Op: OpCode<C> + io_uring_opcode::OpExtSocket,
+    {
+        use io_uring_opcode::types::TargetFdType;
+        let install = match op.target_fd() {
+            TargetFdType::Regular => None,
+            TargetFdType::FixedAuto => Some((None, FdKind::RecvSend)),
+            TargetFdType::FixedManual(slot) => Some((Some(slot), FdKind::RecvSend)),
+        };
+        let submission = op.submission()?;
+        let mut link = Self::submitted(submission.opcode(), Completion::Socket(submission));
+        link.install = install;
+        Ok(link)
+    }
+    /// Connect implementing OpExtConnect
+    #[cfg(feature = "connect")]
+    pub fn connect<Op>(op: Op) -> Result<Self, UringBearerError>
+    where
+        Op: OpCode<C> + io_uring_opcode::OpExtConnect,
+    {
+        let submission = op.submission()?;
+        Ok(Self::submitted(
+            submission.opcode(),
+            Completion::Connect(submission),
+        ))
+    }
+    /// Send or SendMsg implementing OpExtSend, the fixed filehandle must be registered or
+    /// installed by an earlier Socket in the chain into the manual slot.
+    #[cfg(feature = "send")]
+    pub fn send<Op>(op: Op) -> Result<Self, UringBearerError>
+    where
+        Op: OpCode<C> + io_uring_opcode::OpExtSend,
+    {
+        let fixed_fd = op.fixed_fd();
+        let submission = op.submission()?;
+        let mut link = Self::submitted(submission.opcode(), Completion::Send(submission));
+        link.fixed_fd = Some(fixed_fd);
+        Ok(link)
+    }
+    /// LinkTimeout implementing OpExtTimeout guarding the link before it
+    #[cfg(feature = "timeout")]
+    pub fn link_timeout<Op>(op: Op) -> Result<Self, UringBearerError>
+    where
+        Op: OpCode<C> + io_uring_opcode::OpExtTimeout,
+    {
+        if !op.is_linked() {
+            return Err(UringBearerError::TimeoutLinkMismatch);
+        }
+        let submission = op.submission()?;
+        let mut link = Self::submitted(submission.opcode(), Completion::Timeout(submission));
+        link.is_link_timeout = true;
+        Ok(link)
+    }
+    /// Submission flags of the link, the link flags are managed by the chain except that
+    /// on_io_hard_link is honoured for the links but the last.
+    pub fn with_flags(mut self, flags: SubmissionFlags) -> Self {
+        self.flags = Some(flags);
+        self
+    }
+}
+
+struct ChainRec {
+    keys: Vec<usize>,
+    link_timeouts: Vec<bool>,
+    results: Vec<Option<i32>>,
+    pending: usize,
+}
+
+/// Pending chains by the chain id and their links by the key
+pub(crate) struct Chains {
+    next_id: usize,
+    links: SelectedHashMap<usize, (usize, usize), SelectedHasher<usize>>,
+    chains: SelectedHashMap<usize, ChainRec, SelectedHasher<usize>>,
+    /// Completed chains not yet collected, oldest first
+    uncollected: VecDeque<usize>,
+    max_uncollected: usize,
+}
+
+impl Chains {
+    /// Keep up to the given count of the completed chains not yet collected
+    pub(crate) fn with_max_uncollected(max_uncollected: usize) -> Self {
+        Self {
+            next_id: 0,
+            links: SelectedHashMap::with_hasher(SelectedHasher::default()),
+            chains: SelectedHashMap::with_hasher(SelectedHasher::default()),
+            uncollected: VecDeque::new(),
+            max_uncollected,
+        }
+    }
+    fn track(&mut self, keys: Vec<usize>, link_timeouts: Vec<bool>) -> usize {
+        let id = self.next_id;
+        self.next_id = self.next_id.wrapping_add(1);
+        for (idx, key) in keys.iter().enumerate() {
+            self.links.insert(*key, (id, idx));
+        }
+        let results = vec![None; keys.len()];
+        let pending = keys.len();
+        self.chains.insert(
+            id,
+            ChainRec {
+                keys,
+                link_timeouts,
+                results,
+                pending,
+            },
+        );
+        id
+    }
+    /// Record the result of the link at the key if it is within a pending chain. The
+    /// result is taken from the completions carrying one, not from the notifications,
+    /// and the link is complete upon its final completion after which the key is no
+    /// longer tracked as the record may be released and reused.
+    #[inline]
+    pub(crate) fn completed(&mut self, key: usize, res: i32, more: bool, notif: bool) {
+        let Some((id, idx)) = self.links.get(&key).copied() else {
+            return;
+        };
+        let Some(chain) = self.chains.get_mut(&id) else {
+            return;
+        };
+        if !notif {
+            chain.results[idx] = Some(res);
+        }
+        if more {
+            return;
+        }
+        self.links.remove(&key);
+        chain.pending -= 1;
+        if chain.pending == 0 {
+            self.uncollected.push_back(id);
+            // Oldest completed chains nobody collected are dropped
+            while self.uncollected.len() > self.max_uncollected {
+                if let Some(oldest) = self.uncollected.pop_front() {
+                    self.chains.remove(&oldest);
+                }
+            }
+        }
+    }
+    #[inline]
+    pub(crate) fn clear(&mut self) {
+        self.links.clear();
+        self.chains.clear();
+        self.uncollected.clear();
+    }
+    fn take_completed(&mut self, id: usize) -> Result<Option<ChainResult>, UringBearerError> {
+        match self.chains.get(&id) {
+            None => return Err(UringBearerError::ChainNotExist(id)),
+            Some(chain) if chain.pending > 0 => return Ok(None),
+            Some(_) => {}
+        }
+        let Some(chain) = self.chains.remove(&id) else {
+            return Err(UringBearerError::ChainNotExist(id));
+        };
+        self.uncollected.retain(|uncollected| *uncollected != id);
+        let mut severed = false;
+        let mut outcomes = Vec::with_capacity(chain.keys.len());
+        for (res, is_link_timeout) in chain.results.iter().zip(chain.link_timeouts) {
+            let res = res.unwrap_or_default();
+            let outcome = match (is_link_timeout, res) {
+                (true, r) if r == -libc::ETIME => LinkOutcome::TimedOut,
+                (_, r) if r == -libc::ECANCELED && (severed || !is_link_timeout) => {
+                    LinkOutcome::Canceled
+                }
+                // LinkTimeout is canceled when the link it guards completes in time
+                (true, r) if r == -libc::ECANCELED => LinkOutcome::Succeeded(0),
+                (_, r) if r < 0 => LinkOutcome::Failed(r),
+                (_, r) => LinkOutcome::Succeeded(r),
+            };
+            if matches!(outcome, LinkOutcome::Failed(_) | LinkOutcome::TimedOut) {
+                severed = true;
+            }
+            outcomes.push(outcome);
+        }
+        Ok(Some(ChainResult {
+            keys: chain.keys,
+            outcomes,
+        }))
+    }
+}
+
+impl<C: core::fmt::Debug + Clone + OpCompletion> UringBearer<C> {
+    /// Push the links as a chain contiguously, each link starting only once the one before
+    /// it completed, e.g. Socket -> Connect -> Send -> LinkTimeout. The link flag is set
+    /// on all but the last link. None of the links are pushed if any can not be.
+    ///
+    /// The completions are handed out per link as usual whilst the chain as a whole is
+    /// reported via [`UringBearer::chain_result`] once all its links completed.
+    /// Returns the chain id and the keys of the links in the chain order.
+    pub fn push_chain<I>(&mut self, links: I) -> Result<(usize, Vec<usize>), UringBearerError>
+    where
+        I: IntoIterator<Item = ChainLink<C>>,
+    {
+        let links: Vec<ChainLink<C>> = links.into_iter().collect();
+        let last = match links.len() {
+            0 => return Err(UringBearerError::ChainEmpty),
+            len => len - 1,
+        };
+        let mut installing: Vec<u32> = Vec::new();
+        for (idx, link) in links.iter().enumerate() {
+            let flags = link.flags.unwrap_or_default();
+            if flags.is_skip_success() {
+                return Err(UringBearerError::ChainSkipSuccess);
+            }
+            if link.is_link_timeout && idx == 0 {
+                return Err(UringBearerError::TimeoutLinkMismatch);
+            }
+            if let Some(fixed_fd) = link.fixed_fd {
+                if !installing.contains(&fixed_fd) && !self._fixed_fd_validate(fixed_fd) {
+                    return Err(UringBearerError::FdNotRegistered(fixed_fd));
+                }
+            }
+            if let Some((Some(manual_slot), _)) = link.install {
+                self._fixed_target_validate(Some(manual_slot))?;
+                installing.push(manual_slot);
+            }
+            self._op_supported(link.opcode)?;
+        }
+
+        let mut recs = Vec::with_capacity(links.len());
+        let mut tracking = Vec::with_capacity(links.len());
+        for (idx, link) in links.into_iter().enumerate() {
+            let flags = link.flags.unwrap_or_default();
+            let flags = match idx == last {
+                true => flags.off_links(),
+                false if flags.is_linked() => flags,
+                false => flags.on_io_link(),
+            };
+            match self._take_record(link.rec) {
+                Ok(key) => {
+                    recs.push((key, Some(flags)));
+                    tracking.push((link.install, link.is_link_timeout));
+                }
+                Err(e) => {
+                    let keys: Vec<usize> = recs.iter().map(|(key, _)| *key).collect();
+                    self._release_unpushed(&keys)?;
+                    return Err(e);
+                }
+            }
+        }
+        let keys: Vec<usize> = recs.iter().map(|(key, _)| *key).collect();
+        if let Err(e) = self._push_records(&recs, false) {
+            self._release_unpushed(&keys)?;
+            return Err(e);
+        }
+
+        let mut link_timeouts = Vec::with_capacity(keys.len());
+        for (key, (install, is_link_timeout)) in keys.iter().zip(tracking) {
+            if let Some((manual_slot, kind)) = install {
+                self.fd_register.expect_install(*key, manual_slot, kind);
+            }
+            link_timeouts.push(is_link_timeout);
+        }
+        let id = self.chains.track(keys.clone(), link_timeouts);
+        Ok((id, keys))
+    }
+    /// Result of the chain pushed via [`UringBearer::push_chain`] once all its links have
+    /// completed, after which the chain is no longer tracked. None while any is pending.
+    /// Up to the PendingCompletions capacity of the completed chains are kept until
+    /// collected, the oldest of these being dropped beyond it.
+    pub fn chain_result(&mut self, chain: usize) -> Result<Option<ChainResult>, UringBearerError> {
+        self.chains.take_completed(chain)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn completed_upon_final() {
+        let mut chains = Chains::with_max_uncollected(4);
+        let id = chains.track(vec![1, 2], vec![false, false]);
+        chains.completed(1, 5, false, false);
+        assert!(matches!(chains.take_completed(id), Ok(None)));
+        chains.completed(2, 7, true, false);
+        chains.completed(2, 0, false, true);
+        let result = chains
+            .take_completed(id)
+            .expect("chain")
+            .expect("completed");
+        assert_eq!(result.keys(), &[1, 2]);
+        assert_eq!(
+            result.outcomes(),
+            &[LinkOutcome::Succeeded(5), LinkOutcome::Succeeded(7)]
+        );
+        assert!(matches!(
+            chains.take_completed(id),
+            Err(UringBearerError::ChainNotExist(_))
+        ));
+    }
+
+    #[test]
+    fn completed_failed_severs() {
+        let mut chains = Chains::with_max_uncollected(4);
+        let id = chains.track(vec![1, 2, 3], vec![false, true, false]);
+        chains.completed(1, -libc::ETIME, false, false);
+        chains.completed(2, -libc::ECANCELED, false, false);
+        chains.completed(3, -libc::ECANCELED, false, false);
+        let result = chains
+            .take_completed(id)
+            .expect("chain")
+            .expect("completed");
+        assert_eq!(
+            result.outcomes(),
+            &[
+                LinkOutcome::Failed(-libc::ETIME),
+                LinkOutcome::Canceled,
+                LinkOutcome::Canceled
+            ]
+        );
+        assert!(!result.is_success());
+    }
+
+    #[test]
+    fn completed_link_timeout_in_time() {
+        let mut chains = Chains::with_max_uncollected(4);
+        let id = chains.track(vec![1, 2], vec![false, true]);
+        chains.completed(1, 3, false, false);
+        chains.completed(2, -libc::ECANCELED, false, false);
+        let result = chains
+            .take_completed(id)
+            .expect("chain")
+            .expect("completed");
+        assert!(result.is_success());
+    }
+
+    #[test]
+    fn uncollected_bounded() {
+        let mut chains = Chains::with_max_uncollected(2);
+        let ids: Vec<usize> = (0..3)
+            .map(|key| chains.track(vec![key], vec![false]))
+            .collect();
+        for key in 0..3 {
+            chains.completed(key, 0, false, false);
+        }
+        assert!(matches!(
+            chains.take_completed(ids[0]),
+            Err(UringBearerError::ChainNotExist(_))
+        ));
+        assert!(matches!(chains.take_completed(ids[1]), Ok(Some(_))));
+        assert!(matches!(chains.take_completed(ids[2]), Ok(Some(_))));
+    }
+
+    #[test]
+    fn completed_untracked_ignored() {
+        let mut chains = Chains::with_max_uncollected(1);
+        chains.completed(9, 0, false, false);
+        assert!(matches!(
+            chains.take_completed(0),
+            Err(UringBearerError::ChainNotExist(_))
+        ));
+    }
+}
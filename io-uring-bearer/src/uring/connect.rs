@@ -18,14 +18,34 @@ impl<C: core::fmt::Debug + Clone + OpCompletion> UringBearer<C> {
     where
         Op: OpCode<C> + OpExtConnect,
     {
-        let key = self
-            .fd_slab
-            .take_next_with(Completion::Connect(op.submission()?))
-            .map_err(UringBearerError::Slabbable)?;
+        let key = self._take_connect(op)?;
+        self._push_new(key, flags)
+    }
+    /// Push a Connect followed by the linked LinkTimeout cancelling it upon expiry.
+    /// Returns the keys of the Connect and the LinkTimeout respectively.
+    #[cfg(feature = "timeout")]
+    pub fn push_connect_with_timeout<Op, OpT>(
+        &mut self,
+        op: Op,
+        link_timeout: OpT,
+        flags: Option<SubmissionFlags>,
+    ) -> Result<(usize, usize), UringBearerError>
+    where
+        Op: OpCode<C> + OpExtConnect,
+        OpT: OpCode<C> + io_uring_opcode::OpExtTimeout,
+    {
+        let key = self._take_connect(op)?;
+        self._push_with_link_timeout(key, flags, link_timeout)
+    }
+    fn _take_connect<Op>(&mut self, op: Op) -> Result<usize, UringBearerError>
+    where
+        Op: OpCode<C> + OpExtConnect,
+    {
+        let submission = op.submission()?;
+        self._op_supported(submission.opcode())?;
 
-        match self._push_to_completion(key, flags) {
-            Err(e) => Err(e),
-            Ok(()) => Ok(key),
-        }
+        self.fd_slab
+            .take_next_with(Completion::Connect(submission))
+            .map_err(UringBearerError::Slabbable)
     }
 }
@@ -0,0 +1,377 @@
+//! Draining the completions ring into the completion handlers
+
+use super::{fixed_buffers, multishot, UringBearer, COMPLETION_BATCH};
+use crate::completion::{
+    CompletionFlow, MultishotStatus, OpCompletionResult, SubmissionRecordStatus,
+};
+use crate::error::UringBearerError;
+use crate::fixed::InstallTracking;
+use crate::slab::CancelTarget;
+use crate::Completion;
+use crate::SubmissionFlags;
+
+use core::mem::MaybeUninit;
+use io_uring_opcode::{OpCode, OpCompletion};
+use slabbable::Slabbable;
+
+type Deferred<C> = Box<dyn FnOnce(&mut UringBearer<C>) -> Result<usize, UringBearerError>>;
+
+/// Submissions enqueued from within the completion handler, e.g. re-arming Accept or Recv.
+/// These are pushed in order once the handler returns and before the next completion is
+/// handled, staged for the next submit. All are pushed also when the handler returns an
+/// error or any of the pushes fails, the first error being returned.
+pub struct Enqueue<C: core::fmt::Debug + Clone + OpCompletion> {
+    deferred: Vec<Deferred<C>>,
+}
+
+impl<C: core::fmt::Debug + Clone + OpCompletion> Enqueue<C> {
+    fn new() -> Self {
+        Enqueue {
+            deferred: Vec::new(),
+        }
+    }
+    /// Enqueue the push through any of the bearer push methods, e.g.
+    /// `enqueue.push_with(move |bearer| bearer.add_recv(fixed_fd, buf_idx, None))`
+    pub fn push_with<P>(&mut self, push: P)
+    where
+        P: FnOnce(&mut UringBearer<C>) -> Result<usize, UringBearerError> + 'static,
+    {
+        self.deferred.push(Box::new(push));
+    }
+    /// Enqueue a general Op implementing OpCode trait, see [`UringBearer::push_op`]
+    pub fn push_op<Op: OpCode<C> + 'static>(&mut self, op: Op, flags: Option<SubmissionFlags>) {
+        self.push_with(move |bearer| bearer.push_op(op, flags));
+    }
+    /// Count of the enqueued submissions pending the push
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.deferred.len()
+    }
+    /// Whether nothing was enqueued
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.deferred.is_empty()
+    }
+}
+
+impl<C: core::fmt::Debug + Clone + OpCompletion> UringBearer<C> {
+    /// Spin the completions ring with the fallible handler that may mutate its captured
+    /// state, stop the spin and enqueue new submissions. Single-shot records are retained
+    /// and multishot as per [`UringBearer::lifecycle_completions`]. The completions left
+    /// over upon Stop or error are handed out first upon the next spin. The submissions
+    /// enqueued by the handler are pushed also when it returns an error, see [`Enqueue`].
+    /// At most limit completions are handled if given. Returns the count of the
+    /// completions handled.
+    pub fn try_completions<F, U, E>(
+        &mut self,
+        user: &mut U,
+        limit: Option<u32>,
+        mut func: F,
+    ) -> Result<usize, E>
+    where
+        F: FnMut(
+            &mut U,
+            &mut Enqueue<C>,
+            &io_uring::cqueue::Entry,
+            &Completion<C>,
+            OpCompletionResult<C>,
+            MultishotStatus,
+        ) -> Result<CompletionFlow, E>,
+        E: From<UringBearerError>,
+    {
+        // SAFETY: We Retain the original single-shot records and multishot are retained
+        // by the bearer for as long as the kernel refers to them.
+        unsafe {
+            self.handle_try_completions(user, limit, |u, q, e, rec, res, status| {
+                func(u, q, e, rec, res, status).map(|flow| (SubmissionRecordStatus::Retain, flow))
+            })
+        }
+    }
+    /// Spin the completions ring with the fallible handler deciding also what to do with
+    /// the original submission record as per [`UringBearer::handle_lifecycle_completions`].
+    /// The record is retained upon the handler returning an error whilst the submissions
+    /// it enqueued are pushed.
+    ///
+    /// # Safety
+    ///
+    /// Same as [`UringBearer::handle_completions`], the record must be retained if kernel
+    /// still refers to it.
+    pub unsafe fn handle_try_completions<F, U, E>(
+        &mut self,
+        user: &mut U,
+        limit: Option<u32>,
+        mut func: F,
+    ) -> Result<usize, E>
+    where
+        F: FnMut(
+            &mut U,
+            &mut Enqueue<C>,
+            &io_uring::cqueue::Entry,
+            &Completion<C>,
+            OpCompletionResult<C>,
+            MultishotStatus,
+        ) -> Result<(SubmissionRecordStatus, CompletionFlow), E>,
+        E: From<UringBearerError>,
+    {
+        // SAFETY: Upheld by the caller as per above.
+        unsafe { self._spin(user, limit, &mut func) }
+    }
+    /// Drain up to limit completions copying these out of the ring in internal batches
+    pub(crate) unsafe fn _spin<F, U, E>(
+        &mut self,
+        user: &mut U,
+        limit: Option<u32>,
+        func: &mut F,
+    ) -> Result<usize, E>
+    where
+        F: FnMut(
+            &mut U,
+            &mut Enqueue<C>,
+            &io_uring::cqueue::Entry,
+            &Completion<C>,
+            OpCompletionResult<C>,
+            MultishotStatus,
+        ) -> Result<(SubmissionRecordStatus, CompletionFlow), E>,
+        E: From<UringBearerError>,
+    {
+        // SAFETY: Array of MaybeUninit does not require initialization
+        let mut batch: [MaybeUninit<io_uring::cqueue::Entry>; COMPLETION_BATCH] =
+            unsafe { MaybeUninit::uninit().assume_init() };
+        let want = limit.map_or(usize::MAX, |limit| limit as usize);
+        // SAFETY: Upheld by the caller.
+        unsafe { self._drain(user, &mut batch, want, func) }
+    }
+    /// Drain up to want completions, the leftovers of a previous spin first and then copied
+    /// out of the ring a batch at a time syncing the ring once per batch. The re-armed
+    /// multishot records are pushed again regardless of the outcome.
+    pub(crate) unsafe fn _drain<F, U, E>(
+        &mut self,
+        user: &mut U,
+        batch: &mut [MaybeUninit<io_uring::cqueue::Entry>],
+        want: usize,
+        func: &mut F,
+    ) -> Result<usize, E>
+    where
+        F: FnMut(
+            &mut U,
+            &mut Enqueue<C>,
+            &io_uring::cqueue::Entry,
+            &Completion<C>,
+            OpCompletionResult<C>,
+            MultishotStatus,
+        ) -> Result<(SubmissionRecordStatus, CompletionFlow), E>,
+        E: From<UringBearerError>,
+    {
+        let mut rearm = Vec::new();
+        let drained = self._drain_into(user, batch, want, func, &mut rearm);
+        let rearmed = self._rearm_pending(rearm);
+        let handled = drained?;
+        rearmed?;
+        Ok(handled)
+    }
+    fn _drain_into<F, U, E>(
+        &mut self,
+        user: &mut U,
+        batch: &mut [MaybeUninit<io_uring::cqueue::Entry>],
+        want: usize,
+        func: &mut F,
+        rearm: &mut Vec<(usize, Option<InstallTracking>)>,
+    ) -> Result<usize, E>
+    where
+        F: FnMut(
+            &mut U,
+            &mut Enqueue<C>,
+            &io_uring::cqueue::Entry,
+            &Completion<C>,
+            OpCompletionResult<C>,
+            MultishotStatus,
+        ) -> Result<(SubmissionRecordStatus, CompletionFlow), E>,
+        E: From<UringBearerError>,
+    {
+        let mut enqueue = Enqueue::new();
+        let mut handled = 0;
+        while handled < want {
+            if let Some(item) = self.cq_backlog.pop_front() {
+                handled += 1;
+                match self._handle_one(user, &mut enqueue, &item, func, rearm)? {
+                    CompletionFlow::Continue => continue,
+                    CompletionFlow::Stop => return Ok(handled),
+                }
+            }
+            let take = (want - handled).min(batch.len());
+            if take == 0 {
+                break;
+            }
+            // The completion queue syncs the head upon dropped at the end of the statement
+            let items = self.io_uring.completion().fill(&mut batch[..take]);
+            let copied = items.len();
+            for (i, item) in items.iter().enumerate() {
+                handled += 1;
+                let flow = self._handle_one(user, &mut enqueue, item, func, rearm);
+                if !matches!(flow, Ok(CompletionFlow::Continue)) {
+                    self.cq_backlog.extend(items[i + 1..].iter().cloned());
+                    return flow.map(|_| handled);
+                }
+            }
+            if copied < take {
+                break;
+            }
+        }
+        Ok(handled)
+    }
+    /// Handle the single completion copied out of the ring
+    fn _handle_one<F, U, E>(
+        &mut self,
+        user: &mut U,
+        enqueue: &mut Enqueue<C>,
+        item: &io_uring::cqueue::Entry,
+        func: &mut F,
+        rearm: &mut Vec<(usize, Option<InstallTracking>)>,
+    ) -> Result<CompletionFlow, E>
+    where
+        F: FnMut(
+            &mut U,
+            &mut Enqueue<C>,
+            &io_uring::cqueue::Entry,
+            &Completion<C>,
+            OpCompletionResult<C>,
+            MultishotStatus,
+        ) -> Result<(SubmissionRecordStatus, CompletionFlow), E>,
+        E: From<UringBearerError>,
+    {
+        let key = item.user_data();
+        if let Some(buf_idx) = fixed_buffers::released_tag(key) {
+            if let Ok(Some(bufs_rec)) = self.bufs.slot_get_mut(buf_idx) {
+                bufs_rec.fixed_released();
+            }
+            return Ok(CompletionFlow::Continue);
+        }
+        if !io_uring::cqueue::more(item.flags()) {
+            self.in_flight.remove(&(key as usize));
+        }
+        self.chains.completed(
+            key as usize,
+            item.result(),
+            io_uring::cqueue::more(item.flags()),
+            io_uring::cqueue::notif(item.flags()),
+        );
+        let a_rec_t = self
+            .fd_slab
+            .slot_get_mut(key as usize)
+            .map_err(UringBearerError::Slabbable)?;
+
+        let Some(completed_rec) = a_rec_t else {
+            return Ok(CompletionFlow::Continue);
+        };
+        let more = io_uring::cqueue::more(item.flags());
+        let tracked = self
+            .fd_register
+            .installed(key as usize, item.result(), more);
+        let typed_res = completed_rec.completion(item);
+        if !more {
+            for buf_idx in completed_rec.buf_idxs() {
+                if let Ok(Some(bufs)) = self.bufs.slot_get_mut(*buf_idx) {
+                    bufs.force_owner_reusable();
+                }
+            }
+        }
+        let status = match (completed_rec.is_multishot(), more) {
+            (false, _) => MultishotStatus::Single,
+            (true, true) => MultishotStatus::More,
+            (true, false)
+                if !self.release_on_final.contains(&(key as usize))
+                    && multishot::rearm_due(
+                        &mut self.rearm_policies,
+                        key as usize,
+                        item.result(),
+                    ) =>
+            {
+                MultishotStatus::Rearmed
+            }
+            (true, false) => MultishotStatus::Terminated,
+        };
+        // SendZc notification only tells the kernel no longer refers to the buffer.
+        let verdict = match io_uring::cqueue::notif(item.flags()) {
+            true => Ok((SubmissionRecordStatus::Retain, CompletionFlow::Continue)),
+            false => func(user, enqueue, item, completed_rec, typed_res, status),
+        };
+        let rec_status = match verdict {
+            Ok((ref rec_status, _)) => rec_status.clone(),
+            Err(_) => SubmissionRecordStatus::Retain,
+        };
+        // Canceled, AsyncCancel, CloseFixed and terminated multishot records are
+        // released upon their final completion.
+        let release = match completed_rec {
+            Completion::AsyncCancel(cancel) => {
+                // Target was not found in-flight e.g. it completed and was retained already
+                if let CancelTarget::Key(target) = cancel.target() {
+                    if item.result() == -libc::ENOENT {
+                        self.release_on_final.remove(&target);
+                    }
+                }
+                true
+            }
+            Completion::CloseFixed(close) => {
+                if close.is_slot_freed() {
+                    self.fd_register.remove(close.slot());
+                }
+                true
+            }
+            _ => {
+                status == MultishotStatus::Terminated
+                    || !more && self.release_on_final.contains(&(key as usize))
+            }
+        };
+        if rec_status == SubmissionRecordStatus::Forget && more {
+            // Kernel still refers to the record until the final completion
+            self.release_on_final.insert(key as usize);
+        } else if rec_status == SubmissionRecordStatus::Forget || release {
+            // Forget takes precedence over the re-arm due
+            self.release_on_final.remove(&(key as usize));
+            self.rearm_policies.remove(&(key as usize));
+            self.fd_slab
+                .mark_for_reuse(key as usize)
+                .map_err(UringBearerError::Slabbable)?;
+        } else if status == MultishotStatus::Rearmed {
+            // Kernel no longer refers to the record until pushed again
+            rearm.push((key as usize, tracked));
+        }
+        let mut pushed = Ok(0);
+        for push in enqueue.deferred.drain(..) {
+            if let Err(e) = push(self) {
+                pushed = pushed.and(Err(e));
+            }
+        }
+        let (_, flow) = verdict?;
+        pushed?;
+        Ok(flow)
+    }
+    /// Push the re-armed multishot records again, re-tracking the fixed filehandles these
+    /// install. The record failing to push is released and the first error returned.
+    fn _rearm_pending(
+        &mut self,
+        rearm: Vec<(usize, Option<InstallTracking>)>,
+    ) -> Result<(), UringBearerError> {
+        let mut first_err = None;
+        for (key, tracked) in rearm {
+            match self._push_to_completion(key, None) {
+                Ok(()) => {
+                    if let Some((manual_slot, kind)) = tracked {
+                        self.fd_register.expect_install(key, manual_slot, kind);
+                    }
+                }
+                Err(e) => {
+                    self.rearm_policies.remove(&key);
+                    self.fd_slab
+                        .mark_for_reuse(key)
+                        .map_err(UringBearerError::Slabbable)?;
+                    first_err.get_or_insert(e);
+                }
+            }
+        }
+        match first_err {
+            Some(e) => Err(e),
+            None => Ok(()),
+        }
+    }
+}
@@ -18,14 +18,14 @@ impl<C: core::fmt::Debug + Clone + OpCompletion> UringBearer<C> {
     where
         Op: OpCode<C> + OpExtEpollCtl,
     {
+        let submission = op.submission()?;
+        self._op_supported(submission.opcode())?;
+
         let key = self
             .fd_slab
-            .take_next_with(Completion::EpollCtl(op.submission()?))
+            .take_next_with(Completion::EpollCtl(submission))
             .map_err(UringBearerError::Slabbable)?;
 
-        match self._push_to_completion(key, flags) {
-            Err(e) => Err(e),
-            Ok(()) => Ok(key),
-        }
+        self._push_new(key, flags)
     }
 }
@@ -0,0 +1,220 @@
+//! Registered (fixed) Buffers API Surface
+//!
+//! Created buffers are pinned into the kernel fixed buffers table for ReadFixed,
+//! WriteFixed and SendZc to refer them by the kernel index. Each registered
+//! buffer is tagged so the kernel posts a completion once it no longer refers
+//! to it after being replaced or unregistered, upon which the buffers may be
+//! destroyed again.
+
+use super::UringBearer;
+use crate::error::UringBearerError;
+use crate::slab::buffer::{TakenImmutableBuffer, TakenMutableBuffer};
+
+use io_uring_opcode::OpCompletion;
+use slabbable::Slabbable;
+
+/// The kernel posts the resource tag as user_data which must not collide with the slab keys
+const FIXED_BUF_TAG: u64 = 1 << 63;
+
+/// The created buffers index if the completion user_data is a released fixed buffer tag
+#[inline]
+pub(crate) fn released_tag(user_data: u64) -> Option<usize> {
+    match user_data & FIXED_BUF_TAG {
+        0 => None,
+        _ => Some((user_data & !FIXED_BUF_TAG) as usize),
+    }
+}
+
+impl<C: core::fmt::Debug + Clone + OpCompletion> UringBearer<C> {
+    /// Register the fixed buffers table where each index is the kernel index and
+    /// the value the created buffers index pinned there. None leaves the index sparse
+    /// for filling it later via update_registered_buffers.
+    pub fn register_buffer_table(
+        &mut self,
+        table: &[Option<usize>],
+    ) -> Result<(), UringBearerError> {
+        if !self.fixed_bufs.is_empty() {
+            return Err(UringBearerError::RegisterBuffers(
+                "Fixed buffers table already registered".to_string(),
+            ));
+        }
+        if table.is_empty() || table.len() > u16::MAX as usize + 1 {
+            return Err(UringBearerError::InvalidParameterI32(
+                "UringBearer::register_buffer_table",
+                "table.len() == 0 || table.len() > u16::MAX + 1",
+                table.len() as i32,
+            ));
+        }
+        let (iovecs, tags) = self._fixed_buffers_iovecs(table)?;
+        // SAFETY: BuffersRec allocations are not moved and cannot be destroyed while pinned.
+        unsafe {
+            self.io_uring
+                .submitter()
+                .register_buffers2(&iovecs, &tags)
+                .map_err(|e| UringBearerError::RegisterBuffers(e.to_string()))?;
+        }
+        self.fixed_bufs = table.to_vec();
+        self._fixed_buffers_pin(0, table);
+        Ok(())
+    }
+    /// Update the registered fixed buffers table from the given kernel index onwards.
+    /// The buffers previously at the updated indices remain pinned until the kernel
+    /// has released them.
+    pub fn update_registered_buffers(
+        &mut self,
+        offset: u16,
+        bufs: &[Option<usize>],
+    ) -> Result<(), UringBearerError> {
+        if offset as usize + bufs.len() > self.fixed_bufs.len() {
+            return Err(UringBearerError::FixedBuffersRange(
+                offset as u32,
+                bufs.len(),
+            ));
+        }
+        let (iovecs, tags) = self._fixed_buffers_iovecs(bufs)?;
+        // SAFETY: BuffersRec allocations are not moved and cannot be destroyed while pinned.
+        unsafe {
+            self.io_uring
+                .submitter()
+                .register_buffers_update(offset as u32, &iovecs, Some(&tags))
+                .map_err(|e| UringBearerError::RegisterBuffers(e.to_string()))?;
+        }
+        for (i, replaced) in self.fixed_bufs[offset as usize..offset as usize + bufs.len()]
+            .iter()
+            .enumerate()
+        {
+            if let Some(buf_idx) = replaced {
+                if let Ok(Some(bufs_rec)) = self.bufs.slot_get_mut(*buf_idx) {
+                    if bufs_rec.fixed_index == Some(offset + i as u16) {
+                        bufs_rec.fixed_index = None;
+                    }
+                }
+            }
+        }
+        self.fixed_bufs[offset as usize..offset as usize + bufs.len()].copy_from_slice(bufs);
+        self._fixed_buffers_pin(offset, bufs);
+        Ok(())
+    }
+    /// Unregister the whole fixed buffers table. The buffers remain pinned until
+    /// the kernel has released them.
+    pub fn unregister_buffer_table(&mut self) -> Result<(), UringBearerError> {
+        self.io_uring
+            .submitter()
+            .unregister_buffers()
+            .map_err(|e| UringBearerError::RegisterBuffers(e.to_string()))?;
+        for buf_idx in self.fixed_bufs.drain(..).flatten() {
+            if let Ok(Some(bufs_rec)) = self.bufs.slot_get_mut(buf_idx) {
+                bufs_rec.fixed_index = None;
+            }
+        }
+        Ok(())
+    }
+    /// Created buffers index registered at the given kernel index if any
+    pub fn registered_buffer(&self, kernel_index: u16) -> Option<usize> {
+        self.fixed_bufs
+            .get(kernel_index as usize)
+            .copied()
+            .flatten()
+    }
+    /// Take a single registered buffer for Read-Only use by its created buffers index,
+    /// carrying the kernel index it is registered at e.g. for WriteFixed.
+    pub fn take_one_fixed_buffer(
+        &mut self,
+        buf_idx: usize,
+    ) -> Result<TakenImmutableBuffer, UringBearerError> {
+        let kernel_index = self._fixed_index(buf_idx)?;
+        self.take_one_immutable_buffer(buf_idx, kernel_index)
+    }
+    /// Take a single registered buffer for the kernel to fill by its created buffers index,
+    /// carrying the kernel index it is registered at e.g. for ReadFixed.
+    pub fn take_one_fixed_mutable_buffer(
+        &mut self,
+        buf_idx: usize,
+    ) -> Result<TakenMutableBuffer, UringBearerError> {
+        let kernel_index = self._fixed_index(buf_idx)?;
+        self._take_one_mutable_buffer(buf_idx, kernel_index)
+    }
+    fn _fixed_index(&self, buf_idx: usize) -> Result<u16, UringBearerError> {
+        match self.bufs.slot_get_ref(buf_idx) {
+            Ok(Some(bufs_rec)) => match bufs_rec.fixed_index {
+                Some(kernel_index) => Ok(kernel_index),
+                None => Err(UringBearerError::BufferNotFixed(buf_idx)),
+            },
+            Ok(None) => Err(UringBearerError::BufferNotExist(buf_idx)),
+            Err(e) => Err(UringBearerError::Slabbable(e)),
+        }
+    }
+    /// Error unless the created buffers are registered at the given kernel index
+    pub(crate) fn _fixed_buffer_validate(
+        &self,
+        buf_idx: usize,
+        kernel_index: u16,
+    ) -> Result<(), UringBearerError> {
+        match self.registered_buffer(kernel_index) {
+            Some(registered) if registered == buf_idx => Ok(()),
+            _ => Err(UringBearerError::BufferNotFixed(buf_idx)),
+        }
+    }
+    /// Kernel no longer refers to the buffers through one of the fixed buffers table slots
+    pub(crate) fn _fixed_buffer_released(&mut self, buf_idx: usize) {
+        if let Ok(Some(bufs_rec)) = self.bufs.slot_get_mut(buf_idx) {
+            bufs_rec.fixed_released();
+        }
+    }
+    fn _fixed_buffers_iovecs(
+        &self,
+        table: &[Option<usize>],
+    ) -> Result<(Vec<libc::iovec>, Vec<u64>), UringBearerError> {
+        let mut iovecs = Vec::with_capacity(table.len());
+        let mut tags = Vec::with_capacity(table.len());
+        for slot in table {
+            match slot {
+                Some(buf_idx) => match self.bufs.slot_get_ref(*buf_idx) {
+                    Ok(Some(bufs_rec)) => {
+                        iovecs.push(bufs_rec.as_iovec());
+                        tags.push(FIXED_BUF_TAG | *buf_idx as u64);
+                    }
+                    Ok(None) => return Err(UringBearerError::BufferNotExist(*buf_idx)),
+                    Err(e) => return Err(UringBearerError::Slabbable(e)),
+                },
+                // Sparse slot
+                None => {
+                    iovecs.push(libc::iovec {
+                        iov_base: core::ptr::null_mut(),
+                        iov_len: 0,
+                    });
+                    tags.push(0);
+                }
+            }
+        }
+        Ok((iovecs, tags))
+    }
+    fn _fixed_buffers_pin(&mut self, offset: u16, table: &[Option<usize>]) {
+        for (i, slot) in table.iter().enumerate() {
+            if let Some(buf_idx) = slot {
+                if let Ok(Some(bufs_rec)) = self.bufs.slot_get_mut(*buf_idx) {
+                    bufs_rec.fixed_index = Some(offset + i as u16);
+                    bufs_rec.fixed_pins += 1;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn released_tag_roundtrip() {
+        assert_eq!(released_tag(FIXED_BUF_TAG), Some(0));
+        assert_eq!(released_tag(FIXED_BUF_TAG | 42), Some(42));
+    }
+
+    #[test]
+    fn released_tag_not_slab_key() {
+        assert_eq!(released_tag(0), None);
+        assert_eq!(released_tag(42), None);
+        assert_eq!(released_tag(!FIXED_BUF_TAG), None);
+    }
+}
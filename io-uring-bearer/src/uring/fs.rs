@@ -0,0 +1,58 @@
+//! Interaface for pushing filesystem ops implementing OpExtFs
+
+use super::UringBearer;
+use crate::error::UringBearerError;
+use crate::Completion;
+use crate::SubmissionFlags;
+use io_uring_fd::FdKind;
+use io_uring_opcode::types::TargetFdType;
+use io_uring_opcode::OpExtFs;
+use io_uring_opcode::{OpCode, OpCompletion};
+
+impl<C: core::fmt::Debug + Clone + OpCompletion> UringBearer<C> {
+    /// Push a filesystem op e.g. OpenAt, Close or Fsync implementing OpCode + traits
+    /// (see io-uring-opcode)
+    ///
+    /// The fixed slot installed upon completion e.g. by OpenAt is recorded as a registered filehandle.
+    /// Close of a fixed slot is refused as the slot would remain recorded, see
+    /// [`UringBearer::close_fixed`] instead.
+    pub fn push_fs<Op>(
+        &mut self,
+        op: Op,
+        flags: Option<SubmissionFlags>,
+    ) -> Result<usize, UringBearerError>
+    where
+        Op: OpCode<C> + OpExtFs,
+    {
+        let fixed_fd = op.fd().and_then(|fd| fd.fixed());
+        if let Some(fixed_fd) = fixed_fd {
+            if !self._fixed_fd_validate(fixed_fd) {
+                return Err(UringBearerError::FdNotRegistered(fixed_fd));
+            }
+        }
+        let install = match op.target_fd() {
+            Some(TargetFdType::FixedAuto) => Some(None),
+            Some(TargetFdType::FixedManual(slot)) => Some(Some(slot)),
+            _ => None,
+        };
+        if let Some(manual_slot) = install {
+            self._fixed_target_validate(manual_slot)?;
+        }
+        let submission = op.submission()?;
+        if let Some(fixed_fd) = fixed_fd {
+            if submission.opcode() == io_uring::opcode::Close::CODE {
+                return Err(UringBearerError::CloseFixedViaFs(fixed_fd));
+            }
+        }
+        self._op_supported(submission.opcode())?;
+
+        let key = self._take_record(Completion::Fs(submission))?;
+
+        self._push_new(key, flags)?;
+        if let Some(manual_slot) = install {
+            self.fd_register
+                .expect_install(key, manual_slot, FdKind::File);
+        }
+        Ok(key)
+    }
+}
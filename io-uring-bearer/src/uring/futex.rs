@@ -69,7 +69,8 @@ impl<C: core::fmt::Debug + Clone + OpCompletion> UringBearer<C> {
         bitset: u64,
         val: u64,
     ) -> Result<usize, UringBearerError> {
-        let ftx_rec_ref = match self.futexes.slot_get_mut(futex_idx) {
+        self._op_supported(io_uring::opcode::FutexWait::CODE)?;
+        let ftx_rec_ref = match self.futexes.slot_get_ref(futex_idx) {
             Ok(Some(itm)) => match itm.owner() {
                 Owner::Kernel => return Err(UringBearerError::FutexNoOwnership(futex_idx)),
                 _ => itm,
@@ -77,39 +78,28 @@ impl<C: core::fmt::Debug + Clone + OpCompletion> UringBearer<C> {
             Ok(None) => return Err(UringBearerError::FutexNotExist(futex_idx)),
             Err(e) => return Err(UringBearerError::Slabbable(e)),
         };
-        let key = self
-            .fd_slab
-            .take_next_with(Completion::FutexWait(crate::slab::futex::wait_futex_rec(
-                bitset,
-                val,
-                ftx_rec_ref,
-            )))
-            .map_err(UringBearerError::Slabbable)?;
-        let futex_wait_rec = self
-            .fd_slab
-            .slot_get_ref(key)
-            .map_err(UringBearerError::Slabbable)?;
-
-        let iou = &mut self.io_uring;
-        let mut s_queue = iou.submission();
-
-        let submission = match futex_wait_rec {
-            Some(Completion::FutexWait(futex_wait_rec)) => {
-                crate::slab::futex::entry(futex_wait_rec).user_data(key as u64)
+        let key = self._take_record(Completion::FutexWait(crate::slab::futex::wait_futex_rec(
+            futex_idx,
+            bitset,
+            val,
+            ftx_rec_ref,
+        )))?;
+        let submission = match self.fd_slab.slot_get_ref(key) {
+            Ok(Some(Completion::FutexWait(futex_wait_rec))) => {
+                crate::slab::futex::entry(futex_wait_rec)
             }
-            _ => {
+            Ok(_) => {
                 return Err(UringBearerError::SlabBugSetGet(
                     "FutexWait not found after set?",
                 ))
             }
+            Err(e) => return Err(UringBearerError::Slabbable(e)),
         };
         // SAFETY: We don't allow move / invalidation of the safe owned atomic given other guarantees hold.
-        match unsafe { s_queue.push(&submission) } {
-            Ok(_) => {
-                ftx_rec_ref.force_owner_kernel();
-                Ok(key)
-            }
-            Err(_) => Err(UringBearerError::SubmissionPush),
+        self._push_new_entry(key, submission)?;
+        if let Ok(Some(ftx_rec)) = self.futexes.slot_get_mut(futex_idx) {
+            ftx_rec.force_owner_kernel();
         }
+        Ok(key)
     }
 }
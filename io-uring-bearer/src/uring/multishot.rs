@@ -0,0 +1,108 @@
+//! Multishot lifecycle and re-arm policy
+
+use super::UringBearer;
+use crate::completion::RearmPolicy;
+use crate::error::UringBearerError;
+use io_uring_opcode::OpCompletion;
+use slabbable::Slabbable;
+
+use hashbrown::HashMap as SelectedHashMap;
+use nohash_hasher::BuildNoHashHasher as SelectedHasher;
+
+pub(crate) type RearmPolicies = SelectedHashMap<usize, RearmPolicy, SelectedHasher<usize>>;
+
+impl<C: core::fmt::Debug + Clone + OpCompletion> UringBearer<C> {
+    /// Set the re-arm policy of the pending multishot submission identified by the key
+    /// returned upon pushing it, e.g. AcceptMulti or RecvMulti. The re-armed record is
+    /// pushed again on the same key without the original flags for the next submit.
+    pub fn set_rearm_policy(
+        &mut self,
+        key: usize,
+        policy: RearmPolicy,
+    ) -> Result<(), UringBearerError> {
+        match self.fd_slab.slot_get_ref(key) {
+            Ok(Some(rec)) if rec.is_multishot() => {}
+            Ok(Some(_)) => return Err(UringBearerError::NotMultishot(key)),
+            Ok(None) => return Err(UringBearerError::KeyNotExist(key)),
+            Err(e) => return Err(UringBearerError::Slabbable(e)),
+        }
+        match policy {
+            RearmPolicy::Never => self.rearm_policies.remove(&key),
+            _ => self.rearm_policies.insert(key, policy),
+        };
+        Ok(())
+    }
+    /// Re-arm policy of the pending multishot submission if any was set
+    #[inline]
+    pub fn rearm_policy(&self, key: usize) -> RearmPolicy {
+        self.rearm_policies.get(&key).copied().unwrap_or_default()
+    }
+}
+
+/// Whether the terminated multishot submission is due re-arming as per its policy.
+/// Only running out of provided buffers, the count exhaustion reported by Timeout as
+/// ETIME and the normal final completion are transient, any other error ends the
+/// submission for good.
+#[inline]
+pub(crate) fn rearm_due(policies: &mut RearmPolicies, key: usize, res: i32) -> bool {
+    if res < 0 && res != -libc::ENOBUFS && res != -libc::ETIME {
+        return false;
+    }
+    match policies.get_mut(&key) {
+        Some(RearmPolicy::Always) => true,
+        Some(RearmPolicy::UpTo(remaining)) if *remaining > 0 => {
+            *remaining -= 1;
+            true
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn policies_with(key: usize, policy: RearmPolicy) -> RearmPolicies {
+        let mut policies = RearmPolicies::with_hasher(SelectedHasher::default());
+        policies.insert(key, policy);
+        policies
+    }
+
+    #[test]
+    fn rearm_never() {
+        let mut policies = policies_with(1, RearmPolicy::Never);
+        for res in [0, 1, -libc::ENOBUFS, -libc::ETIME] {
+            assert!(!rearm_due(&mut policies, 1, res));
+        }
+        let mut unset = RearmPolicies::with_hasher(SelectedHasher::default());
+        assert!(!rearm_due(&mut unset, 1, 0));
+    }
+
+    #[test]
+    fn rearm_always_transient() {
+        let mut policies = policies_with(1, RearmPolicy::Always);
+        for res in [0, 1, -libc::ENOBUFS, -libc::ETIME] {
+            assert!(rearm_due(&mut policies, 1, res));
+        }
+        assert!(!rearm_due(&mut policies, 2, 0));
+    }
+
+    #[test]
+    fn rearm_always_fatal() {
+        let mut policies = policies_with(1, RearmPolicy::Always);
+        for res in [-libc::ECANCELED, -libc::EBADF, -libc::EINVAL] {
+            assert!(!rearm_due(&mut policies, 1, res));
+        }
+    }
+
+    #[test]
+    fn rearm_up_to_counts_down() {
+        let mut policies = policies_with(1, RearmPolicy::UpTo(2));
+        assert!(!rearm_due(&mut policies, 1, -libc::ECANCELED));
+        assert_eq!(policies.get(&1), Some(&RearmPolicy::UpTo(2)));
+        assert!(rearm_due(&mut policies, 1, -libc::ENOBUFS));
+        assert!(rearm_due(&mut policies, 1, 0));
+        assert_eq!(policies.get(&1), Some(&RearmPolicy::UpTo(0)));
+        assert!(!rearm_due(&mut policies, 1, -libc::ETIME));
+    }
+}
@@ -0,0 +1,32 @@
+//! Interaface for pushing path ops implementing OpExtPath
+
+use super::UringBearer;
+use crate::error::UringBearerError;
+use crate::Completion;
+use crate::SubmissionFlags;
+use io_uring_opcode::OpExtPath;
+use io_uring_opcode::{OpCode, OpCompletion};
+use slabbable::Slabbable;
+
+impl<C: core::fmt::Debug + Clone + OpCompletion> UringBearer<C> {
+    /// Push a path op e.g. RenameAt, UnlinkAt or MkdirAt implementing OpCode + traits
+    /// (see io-uring-opcode)
+    pub fn push_path<Op>(
+        &mut self,
+        op: Op,
+        flags: Option<SubmissionFlags>,
+    ) -> Result<usize, UringBearerError>
+    where
+        Op: OpCode<C> + OpExtPath,
+    {
+        let submission = op.submission()?;
+        self._op_supported(submission.opcode())?;
+
+        let key = self
+            .fd_slab
+            .take_next_with(Completion::Path(submission))
+            .map_err(UringBearerError::Slabbable)?;
+
+        self._push_new(key, flags)
+    }
+}
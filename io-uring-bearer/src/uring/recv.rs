@@ -18,17 +18,31 @@ impl<C: core::fmt::Debug + Clone + OpCompletion> UringBearer<C> {
         buf_idx: usize,
         flags: Option<SubmissionFlags>,
     ) -> Result<usize, UringBearerError> {
-        let taken_buf = self.take_one_mutable_buffer(buf_idx)?;
+        let key = self._take_recv(fixed_fd, buf_idx)?;
+        self._push_new(key, flags)
+    }
+    /// Add Recv pending Completion followed by the linked LinkTimeout cancelling it upon expiry.
+    /// Returns the keys of the Recv and the LinkTimeout respectively.
+    #[cfg(feature = "timeout")]
+    pub fn add_recv_with_timeout<OpT>(
+        &mut self,
+        fixed_fd: u32,
+        buf_idx: usize,
+        link_timeout: OpT,
+        flags: Option<SubmissionFlags>,
+    ) -> Result<(usize, usize), UringBearerError>
+    where
+        OpT: io_uring_opcode::OpCode<C> + io_uring_opcode::OpExtTimeout,
+    {
+        let key = self._take_recv(fixed_fd, buf_idx)?;
+        self._push_with_link_timeout(key, flags, link_timeout)
+    }
+    fn _take_recv(&mut self, fixed_fd: u32, buf_idx: usize) -> Result<usize, UringBearerError> {
         if !self._fixed_fd_validate(fixed_fd) {
             return Err(UringBearerError::FdNotRegistered(fixed_fd));
         }
-        let key = self
-            .fd_slab
-            .take_next_with(Completion::Recv(RecvRec::new(fixed_fd as u32, taken_buf)))
-            .map_err(UringBearerError::Slabbable)?;
-
-        self._push_to_completion(key, flags)?;
-        Ok(key)
+        let taken_buf = self.take_one_mutable_buffer(buf_idx)?;
+        self._take_record(Completion::Recv(RecvRec::new(fixed_fd as u32, taken_buf)))
     }
     /// Add RecvMulti pending Completion
     pub fn add_recv_multi(
@@ -48,7 +62,7 @@ impl<C: core::fmt::Debug + Clone + OpCompletion> UringBearer<C> {
             )))
             .map_err(UringBearerError::Slabbable)?;
 
-        self._push_to_completion(key, flags)?;
+        self._push_new(key, flags)?;
         Ok(key)
     }
 }
@@ -3,23 +3,111 @@
 use super::UringBearer;
 use io_uring_fd::{FdKind, RegisteredFd};
 //use crate::fd::{FdKind, RegisteredFd};
+use crate::slab::{CloseFixedRec, FixedFdInstallRec};
 use crate::uring::UringBearerError;
+use crate::Completion;
 use crate::RawFd;
+use crate::SubmissionFlags;
 use io_uring_opcode::OpCompletion;
+use slabbable::Slabbable;
+use std::os::fd::{AsFd, AsRawFd, FromRawFd, OwnedFd};
+
+/// IORING_REGISTER_FILE_ALLOC_RANGE not exposed by io-uring
+const IORING_REGISTER_FILE_ALLOC_RANGE: libc::c_uint = 25;
+
+/// struct io_uring_file_index_range
+#[repr(C)]
+struct FileIndexRange {
+    off: u32,
+    len: u32,
+    resv: u64,
+}
 
 impl<C: core::fmt::Debug + Clone + OpCompletion> UringBearer<C> {
     // TODO: keep track of the FdKind and morph it between different types maybe
     //       Acceptor should not become Send/Recv and RecvSend shold become Recv once all Sent .. maybe?
     /// Add registered filehandle
     pub fn add_registered_fd(&mut self, reg_fd: RegisteredFd) -> Result<u32, UringBearerError> {
-        if self.fd_register.len() == self.fd_register.capacity() {
-            return Err(UringBearerError::FdRegisterFull);
-        }
-        let entry = self.fd_register.vacant_entry();
-        let key = entry.key() as u32;
-        self.fd_register.insert((key, reg_fd));
+        let key = match self.fd_register.vacant() {
+            Some(key) => key,
+            None => return Err(UringBearerError::FdRegisterFull),
+        };
+        self.fd_register.insert(key, reg_fd);
         Ok(key)
     }
+    /// Register the filehandle straight into a free fixed slot of the committed table
+    /// e.g. after commit_registered_init. The kernel holds its own reference so the slot
+    /// remains usable until close_fixed even if the given filehandle is closed.
+    pub fn register_fd<F: AsFd>(&mut self, fd: F, kind: FdKind) -> Result<u32, UringBearerError> {
+        let raw_fd = fd.as_fd().as_raw_fd();
+        let slot = match self.fd_register.vacant() {
+            Some(slot) => slot,
+            None => return Err(UringBearerError::FdRegisterFull),
+        };
+        self.io_uring
+            .submitter()
+            .register_files_update(slot, &[raw_fd])
+            .map_err(|e| UringBearerError::RegisterHandles(e.to_string()))?;
+        self.fd_register
+            .insert(slot, RegisteredFd::from_raw(raw_fd, kind));
+        Ok(slot)
+    }
+    /// Install the registered filehandle at the fixed slot as a regular filehandle e.g. for
+    /// handing it over to a non-io_uring library while the fixed slot remains registered.
+    /// Once completed take the regular filehandle via take_installed_fd with the returned key,
+    /// the record must be retained until then. Requires Linux 6.8.
+    pub fn install_fixed_as_regular(
+        &mut self,
+        slot: u32,
+        cloexec: bool,
+        flags: Option<SubmissionFlags>,
+    ) -> Result<usize, UringBearerError> {
+        if self.fd_register.get(slot).is_none() {
+            return Err(UringBearerError::FdNotRegistered(slot));
+        }
+        self._op_supported(io_uring::opcode::FixedFdInstall::CODE)?;
+
+        let key = self
+            .fd_slab
+            .take_next_with(Completion::FixedFdInstall(FixedFdInstallRec::new(
+                slot, cloexec,
+            )))
+            .map_err(UringBearerError::Slabbable)?;
+
+        self._push_new(key, flags)
+    }
+    /// Take the regular filehandle installed by the completed install_fixed_as_regular
+    /// releasing its record.
+    pub fn take_installed_fd(&mut self, key: usize) -> Result<OwnedFd, UringBearerError> {
+        let (slot, res) = match self.fd_slab.slot_get_ref(key) {
+            Ok(Some(Completion::FixedFdInstall(install))) => match install.result() {
+                Some(res) => (install.slot(), res),
+                None => return Err(UringBearerError::NotFdInstalled(key)),
+            },
+            Ok(Some(_)) => return Err(UringBearerError::NotFdInstalled(key)),
+            Ok(None) => return Err(UringBearerError::KeyNotExist(key)),
+            Err(e) => return Err(UringBearerError::Slabbable(e)),
+        };
+        self.fd_slab
+            .mark_for_reuse(key)
+            .map_err(UringBearerError::Slabbable)?;
+        if res < 0 {
+            return Err(UringBearerError::FdInstallFailed(key, res));
+        }
+        self.fd_register.installed_as_regular(slot, res);
+        // SAFETY: Kernel installed the filehandle for us and the record is released so it
+        // cannot be taken twice.
+        Ok(unsafe { OwnedFd::from_raw_fd(res) })
+    }
+    /// Registered filehandle at the fixed slot if any, including the ones installed
+    /// by the kernel e.g. through a fixed Socket, Accept or OpenAt.
+    pub fn registered_fd(&self, slot: u32) -> Option<&RegisteredFd> {
+        self.fd_register.get(slot).map(|(_, reg_fd)| reg_fd)
+    }
+    /// Count of the currently registered filehandles
+    pub fn registered_fd_count(&self) -> u32 {
+        self.fd_register.len()
+    }
     /// Register Recv handle
     pub fn register_recv(&mut self, fd: RawFd) -> Result<u32, UringBearerError> {
         self.add_registered_fd(RegisteredFd::from_raw(fd, FdKind::Recv))
@@ -28,6 +116,43 @@ impl<C: core::fmt::Debug + Clone + OpCompletion> UringBearer<C> {
     pub fn register_acceptor(&mut self, fd: RawFd) -> Result<u32, UringBearerError> {
         self.add_registered_fd(RegisteredFd::from_raw(fd, FdKind::Acceptor))
     }
+    /// Fixed filehandle slot the raw filehandle was registered at if any
+    pub fn fixed_fd_of(&self, fd: RawFd) -> Option<u32> {
+        self.fd_register.fixed_of(fd as u32)
+    }
+    /// Close the registered filehandle at the fixed slot, clearing the kernel table
+    /// entry. The slot is freed for add_registered_fd upon the completion. The raw
+    /// filehandle originally registered is not closed by this and remains owned by the caller.
+    /// Returns the key of the Close submission which is released upon its completion.
+    pub fn close_fixed(
+        &mut self,
+        slot: u32,
+        flags: Option<SubmissionFlags>,
+    ) -> Result<usize, UringBearerError> {
+        if self.fd_register.get(slot).is_none() {
+            return Err(UringBearerError::FdNotRegistered(slot));
+        }
+        self._op_supported(io_uring::opcode::Close::CODE)?;
+
+        let key = self
+            .fd_slab
+            .take_next_with(Completion::CloseFixed(CloseFixedRec::new(slot)))
+            .map_err(UringBearerError::Slabbable)?;
+
+        self._push_new(key, flags)
+    }
+    /// Error unless the manually targeted fixed slot to install into fits the register
+    pub(crate) fn _fixed_target_validate(
+        &self,
+        manual_slot: Option<u32>,
+    ) -> Result<(), UringBearerError> {
+        match manual_slot {
+            Some(slot) if slot >= self.fd_register.capacity() => {
+                Err(UringBearerError::InvalidTargetFd(slot))
+            }
+            _ => Ok(()),
+        }
+    }
     /// Sparse Commit of one rgistered handle
     pub fn commit_registered_sparse(&mut self, key: u32) -> Result<(), UringBearerError> {
         let raw_fd = match self.fd_register.get(key) {
@@ -44,6 +169,48 @@ impl<C: core::fmt::Debug + Clone + OpCompletion> UringBearer<C> {
             Err(_) => Err(UringBearerError::FdRegisterFail),
         }
     }
+    /// Restrict the fixed slots the kernel auto-assigns from into the given range so
+    /// that these never collide with the slots handed out by add_registered_fd or
+    /// targeted manually. Requires the table committed first e.g. via
+    /// commit_registered_init and Linux 6.0.
+    pub fn register_file_alloc_range(
+        &mut self,
+        offset: u32,
+        len: u32,
+    ) -> Result<(), UringBearerError> {
+        if offset
+            .checked_add(len)
+            .map_or(true, |end| end > self.fd_register.capacity())
+        {
+            return Err(UringBearerError::InvalidParameterI32(
+                "UringBearer::register_file_alloc_range",
+                "offset + len > RegisteredFd capacity",
+                len as i32,
+            ));
+        }
+        let range = FileIndexRange {
+            off: offset,
+            len,
+            resv: 0,
+        };
+        // SAFETY: The range is valid for the duration of the call and the kernel only reads it.
+        let ret = unsafe {
+            libc::syscall(
+                libc::SYS_io_uring_register,
+                self.io_uring.as_raw_fd(),
+                IORING_REGISTER_FILE_ALLOC_RANGE,
+                &range as *const FileIndexRange,
+                0,
+            )
+        };
+        if ret < 0 {
+            return Err(UringBearerError::RegisterHandles(
+                std::io::Error::last_os_error().to_string(),
+            ));
+        }
+        self.fd_register.set_alloc_range(offset, len);
+        Ok(())
+    }
     /// Commit the full table of currently registered and free capacity into the kernel.
     ///
     /// # Warning
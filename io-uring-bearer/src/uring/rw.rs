@@ -0,0 +1,41 @@
+//! Interaface for pushing Read and Write implementing OpExtRw
+
+use super::UringBearer;
+use crate::error::UringBearerError;
+use crate::Completion;
+use crate::SubmissionFlags;
+use io_uring_opcode::OpExtRw;
+use io_uring_opcode::{OpCode, OpCompletion};
+
+impl<C: core::fmt::Debug + Clone + OpCompletion> UringBearer<C> {
+    /// Push a Read, Write or their fixed and vectored variants implementing OpCode + traits
+    /// (see io-uring-opcode). Any bearer managed buffers taken by it are returned upon the
+    /// final completion. The buffer of ReadFixed / WriteFixed must be registered at its
+    /// kernel index.
+    pub fn push_rw<Op>(
+        &mut self,
+        op: Op,
+        flags: Option<SubmissionFlags>,
+    ) -> Result<usize, UringBearerError>
+    where
+        Op: OpCode<C> + OpExtRw,
+    {
+        if let Some(fixed_fd) = op.fd().fixed() {
+            if !self._fixed_fd_validate(fixed_fd) {
+                return Err(UringBearerError::FdNotRegistered(fixed_fd));
+            }
+        }
+        if let Some((buf_idx, kernel_index)) = op.fixed_buf() {
+            if let Err(e) = self._fixed_buffer_validate(buf_idx, kernel_index) {
+                self._return_taken(&[buf_idx]);
+                return Err(e);
+            }
+        }
+        let submission = op.submission()?;
+        self._submission_supported(&submission)?;
+
+        let key = self._take_record(Completion::Rw(submission))?;
+
+        self._push_new(key, flags)
+    }
+}
@@ -0,0 +1,31 @@
+//! Interaface for pushing Send and SendMsg implementing OpExtSend
+
+use super::UringBearer;
+use crate::error::UringBearerError;
+use crate::Completion;
+use crate::SubmissionFlags;
+use io_uring_opcode::OpExtSend;
+use io_uring_opcode::{OpCode, OpCompletion};
+
+impl<C: core::fmt::Debug + Clone + OpCompletion> UringBearer<C> {
+    /// Push a Send or SendMsg implementing OpCode + traits (see io-uring-opcode)
+    /// Any bearer managed buffers taken by it are returned upon the final completion.
+    pub fn push_send<Op>(
+        &mut self,
+        op: Op,
+        flags: Option<SubmissionFlags>,
+    ) -> Result<usize, UringBearerError>
+    where
+        Op: OpCode<C> + OpExtSend,
+    {
+        if !self._fixed_fd_validate(op.fixed_fd()) {
+            return Err(UringBearerError::FdNotRegistered(op.fixed_fd()));
+        }
+        let submission = op.submission()?;
+        self._submission_supported(&submission)?;
+
+        let key = self._take_record(Completion::Send(submission))?;
+
+        self._push_new(key, flags)
+    }
+}
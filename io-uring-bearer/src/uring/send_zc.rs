@@ -7,35 +7,34 @@ use crate::UringBearer;
 
 use crate::slab::SendZcRec;
 
-use crate::slab::send_zc::DestTo;
+use crate::slab::DestTo;
 use io_uring_opcode::OpCompletion;
 use slabbable::Slabbable;
 
 impl<C: core::fmt::Debug + Clone + OpCompletion> UringBearer<C> {
     /// Add SendZc pending Completion. The referenced buffers index must hold
-    /// only one buffer and must be registered into kernel with a valid indexed
-    /// buffer id.
+    /// only one buffer and must be registered at the given kernel index via
+    /// register_buffer_table or update_registered_buffers. The buffer is returned for re-use upon the notification completion
+    /// which the bearer handles without handing it out.
     #[inline]
     pub fn add_send_zc_singlebuf(
         &mut self,
         fixed_fd: u32,
         buf_idx: usize,
         kernel_index: u16,
+        to_addr: Option<DestTo>,
         flags: Option<SubmissionFlags>,
     ) -> Result<usize, UringBearerError> {
-        let taken_buf = self.take_one_immutable_buffer(buf_idx, kernel_index)?;
+        self._fixed_buffer_validate(buf_idx, kernel_index)?;
         if !self._fixed_fd_validate(fixed_fd) {
             return Err(UringBearerError::FdNotRegistered(fixed_fd));
         }
-        let key = self
-            .fd_slab
-            .take_next_with(Completion::SendZc(SendZcRec::with_fixed_buf(
-                fixed_fd as u32,
-                taken_buf,
-            )))
-            .map_err(UringBearerError::Slabbable)?;
+        let taken_buf = self.take_one_immutable_buffer(buf_idx, kernel_index)?;
+        let key = self._take_record(Completion::SendZc(SendZcRec::with_fixed_buf(
+            fixed_fd, taken_buf, to_addr,
+        )))?;
 
-        self._push_to_completion(key, flags)?;
+        self._push_new(key, flags)?;
         Ok(key)
     }
     /// Zero-Copy Send with the supplied raw buffer which not managed by the bearer.
@@ -62,7 +61,7 @@ impl<C: core::fmt::Debug + Clone + OpCompletion> UringBearer<C> {
                 to_addr,
             )))
             .map_err(UringBearerError::Slabbable)?;
-        self._push_to_completion(key, flags)?;
+        self._push_new(key, flags)?;
 
         Ok(key)
     }
@@ -0,0 +1,158 @@
+//! Graceful shutdown API Surface
+
+use super::UringBearer;
+use crate::error::UringBearerError;
+use crate::slab::CancelTarget;
+use crate::Completion;
+use core::mem::ManuallyDrop;
+use core::time::Duration;
+use io_uring::types::{SubmitArgs, Timespec};
+use io_uring_opcode::OpCompletion;
+use slabbable::Slabbable;
+use std::time::Instant;
+
+/// Deadline for the in-flight submissions to return upon Drop
+const DROP_SHUTDOWN_DEADLINE: Duration = Duration::from_secs(5);
+
+impl<C: core::fmt::Debug + Clone + OpCompletion> UringBearer<C> {
+    /// Count of the pushed submissions pending their final completion
+    #[inline]
+    pub fn in_flight(&self) -> usize {
+        self.in_flight.len()
+    }
+    /// Cancel all the in-flight submissions and drain the completions, discarding them,
+    /// until every record has returned from the kernel or the deadline passes. Once
+    /// nothing is in-flight the registered filehandles, buffers and buffer rings are
+    /// unregistered and all the kernel owned buffers and futexes are returned. Buffers
+    /// provided into a buffer group remain kernel owned as the next buffer select
+    /// submission may still pick them, these are freed only upon Drop. Regular filehandles
+    /// installed by the discarded completions are closed.
+    ///
+    /// Errors with ShutdownTimeout carrying the count still in-flight if the deadline passed.
+    pub fn shutdown(&mut self, deadline: Duration) -> Result<(), UringBearerError> {
+        let started = Instant::now();
+
+        if !self.in_flight.is_empty() {
+            // Synchronous cancel requires 6.0, otherwise fall back to the async cancel.
+            match self.cancel_sync(CancelTarget::All, Some(deadline)) {
+                Ok(_) => {}
+                // Timed out ones may still be canceled asynchronously within the deadline
+                Err(UringBearerError::CancelSync(_)) | Err(UringBearerError::CancelTimeout) => {
+                    self.cancel_all(None)?;
+                }
+                Err(e) => return Err(e),
+            }
+        }
+
+        loop {
+            self._drain_discard()?;
+            if self.in_flight.is_empty() {
+                break;
+            }
+            let remaining = match deadline.checked_sub(started.elapsed()) {
+                Some(remaining) if !remaining.is_zero() => remaining,
+                _ => return Err(UringBearerError::ShutdownTimeout(self.in_flight.len())),
+            };
+            self._wait_one(remaining)?;
+        }
+
+        self._reconcile_all_returned();
+
+        let submitter = self.io_uring.submitter();
+        for bgid in self.buf_rings.drain(..) {
+            let _ = submitter.unregister_buf_ring(bgid);
+        }
+        // Neither may have been registered
+        let _ = submitter.unregister_files();
+        let _ = submitter.unregister_buffers();
+        self.fd_register.clear();
+        self.fixed_bufs.clear();
+        self.chains.clear();
+        Ok(())
+    }
+    /// Drain the completions without handing them out, releasing the records upon their
+    /// final completion.
+    fn _drain_discard(&mut self) -> Result<(), UringBearerError> {
+        let mut finished: Vec<io_uring::cqueue::Entry> = Vec::new();
+        let mut released: Vec<usize> = Vec::new();
+        let backlog: Vec<io_uring::cqueue::Entry> = self.cq_backlog.drain(..).collect();
+        for item in backlog.into_iter().chain(self.io_uring.completion()) {
+            if let Some(buf_idx) = super::fixed_buffers::released_tag(item.user_data()) {
+                released.push(buf_idx);
+                continue;
+            }
+            let key = item.user_data() as usize;
+            let more = io_uring::cqueue::more(item.flags());
+            let regular = !self.fd_register.is_install_tracked(key);
+            self.fd_register.installed(key, item.result(), more);
+            // Nobody is left to take the installed regular filehandle
+            if regular && item.result() >= 0 {
+                if let Ok(Some(rec)) = self.fd_slab.slot_get_ref(key) {
+                    if rec.installs_fd() {
+                        // SAFETY: Kernel installed the filehandle for us and it was never taken.
+                        unsafe { libc::close(item.result()) };
+                    }
+                }
+            }
+            if !more {
+                self.in_flight.remove(&key);
+                finished.push(item);
+            }
+        }
+        for buf_idx in released {
+            self._fixed_buffer_released(buf_idx);
+        }
+        for item in finished {
+            let key = item.user_data() as usize;
+            self.release_on_final.remove(&key);
+            self.rearm_policies.remove(&key);
+            if let Ok(Some(rec)) = self.fd_slab.slot_get_mut(key) {
+                if let Completion::CloseFixed(close) = rec {
+                    close.completion(&item);
+                    if close.is_slot_freed() {
+                        self.fd_register.remove(close.slot());
+                    }
+                }
+                self._reconcile_returned(key);
+                self.fd_slab
+                    .mark_for_reuse(key)
+                    .map_err(UringBearerError::Slabbable)?;
+            }
+        }
+        Ok(())
+    }
+    /// Submit and wait for a completion up to the given time
+    fn _wait_one(&mut self, remaining: Duration) -> Result<(), UringBearerError> {
+        if self.io_uring.params().is_feature_ext_arg() {
+            let ts = Timespec::from(remaining);
+            let args = SubmitArgs::new().timespec(&ts);
+            match self.io_uring.submitter().submit_with_args(1, &args) {
+                Ok(_) => Ok(()),
+                Err(e) if matches!(e.raw_os_error(), Some(libc::ETIME) | Some(libc::EINTR)) => {
+                    Ok(())
+                }
+                Err(e) => Err(UringBearerError::Submission(e.to_string())),
+            }
+        } else {
+            self.submit()?;
+            std::thread::sleep(remaining.min(Duration::from_millis(1)));
+            Ok(())
+        }
+    }
+}
+
+impl<C: core::fmt::Debug + Clone + OpCompletion> Drop for UringBearer<C> {
+    fn drop(&mut self) {
+        if self.shutdown(DROP_SHUTDOWN_DEADLINE).is_ok() {
+            // SAFETY: Nothing is in-flight so the kernel no longer refers to the records
+            // and these are not used after. Nothing can be submitted anymore either to
+            // select the buffers still provided into a buffer group.
+            unsafe {
+                ManuallyDrop::drop(&mut self.fd_slab);
+                ManuallyDrop::drop(&mut self.bufs);
+                ManuallyDrop::drop(&mut self.futexes);
+            }
+        }
+        // Otherwise the records are leaked as the kernel may still write into them.
+    }
+}
@@ -4,12 +4,16 @@ use super::UringBearer;
 use crate::error::UringBearerError;
 use crate::Completion;
 use crate::SubmissionFlags;
+use io_uring_fd::FdKind;
+use io_uring_opcode::types::TargetFdType;
 use io_uring_opcode::OpExtSocket;
 use io_uring_opcode::{OpCode, OpCompletion};
 use slabbable::Slabbable;
 
 impl<C: core::fmt::Debug + Clone + OpCompletion> UringBearer<C> {
     /// Push a Socket implementing OpCode +  traits (see io-uring-opcode)
+    ///
+    /// The fixed slot installed upon completion is recorded as a registered filehandle.
     pub fn push_socket<Op>(
         &mut self,
         op: Op,
@@ -18,14 +22,27 @@ impl<C: core::fmt::Debug + Clone + OpCompletion> UringBearer<C> {
     where
         Op: OpCode<C> + OpExtSocket,
     {
+        let install = match op.target_fd() {
+            TargetFdType::Regular => None,
+            TargetFdType::FixedAuto => Some(None),
+            TargetFdType::FixedManual(slot) => Some(Some(slot)),
+        };
+        if let Some(manual_slot) = install {
+            self._fixed_target_validate(manual_slot)?;
+        }
+        let submission = op.submission()?;
+        self._op_supported(submission.opcode())?;
+
         let key = self
             .fd_slab
-            .take_next_with(Completion::Socket(op.submission()?))
+            .take_next_with(Completion::Socket(submission))
             .map_err(UringBearerError::Slabbable)?;
 
-        match self._push_to_completion(key, flags) {
-            Err(e) => Err(e),
-            Ok(()) => Ok(key),
+        self._push_new(key, flags)?;
+        if let Some(manual_slot) = install {
+            self.fd_register
+                .expect_install(key, manual_slot, FdKind::RecvSend);
         }
+        Ok(key)
     }
 }
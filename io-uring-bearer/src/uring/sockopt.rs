@@ -0,0 +1,35 @@
+//! Interaface for pushing socket option ops implementing OpExtSockOpt
+
+use super::UringBearer;
+use crate::error::UringBearerError;
+use crate::Completion;
+use crate::SubmissionFlags;
+use io_uring_opcode::OpExtSockOpt;
+use io_uring_opcode::{OpCode, OpCompletion};
+use slabbable::Slabbable;
+
+impl<C: core::fmt::Debug + Clone + OpCompletion> UringBearer<C> {
+    /// Push a socket option op implementing OpCode + traits (see io-uring-opcode)
+    /// on the registered fixed socket filehandle.
+    pub fn push_sockopt<Op>(
+        &mut self,
+        op: Op,
+        flags: Option<SubmissionFlags>,
+    ) -> Result<usize, UringBearerError>
+    where
+        Op: OpCode<C> + OpExtSockOpt,
+    {
+        if !self._fixed_fd_validate(op.fixed_fd()) {
+            return Err(UringBearerError::FdNotRegistered(op.fixed_fd()));
+        }
+        let submission = op.submission()?;
+        self._op_supported(submission.opcode())?;
+
+        let key = self
+            .fd_slab
+            .take_next_with(Completion::SockOpt(submission))
+            .map_err(UringBearerError::Slabbable)?;
+
+        self._push_new(key, flags)
+    }
+}
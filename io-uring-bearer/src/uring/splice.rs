@@ -0,0 +1,133 @@
+//! Interaface for pushing Splice and Tee implementing OpExtSplice
+
+use super::UringBearer;
+use crate::error::UringBearerError;
+use crate::Completion;
+use crate::RawFd;
+use crate::SubmissionFlags;
+use io_uring_opcode::types::FdRef;
+use io_uring_opcode::OpExtSplice;
+use io_uring_opcode::{OpCode, OpCompletion};
+
+use std::os::fd::{AsRawFd, FromRawFd, OwnedFd};
+
+/// Intermediate pipe pair between two chained splices. It must be kept open until
+/// both of the splices have completed.
+#[derive(Debug)]
+pub struct SplicePipe {
+    read: OwnedFd,
+    write: OwnedFd,
+}
+
+impl SplicePipe {
+    /// Create a new close-on-exec pipe pair
+    pub fn new() -> Result<Self, UringBearerError> {
+        let mut fds: [RawFd; 2] = [-1; 2];
+        // SAFETY: pipe2 only writes the two filehandles into the array
+        if unsafe { libc::pipe2(fds.as_mut_ptr(), libc::O_CLOEXEC) } != 0 {
+            return Err(UringBearerError::Pipe(
+                std::io::Error::last_os_error().to_string(),
+            ));
+        }
+        // SAFETY: Both filehandles were just created and are owned by nothing else
+        unsafe {
+            Ok(Self {
+                read: OwnedFd::from_raw_fd(fds[0]),
+                write: OwnedFd::from_raw_fd(fds[1]),
+            })
+        }
+    }
+    /// Read end of the pipe, spliced out of by the second splice
+    #[inline]
+    pub fn read_fd(&self) -> RawFd {
+        self.read.as_raw_fd()
+    }
+    /// Write end of the pipe, spliced into by the first splice
+    #[inline]
+    pub fn write_fd(&self) -> RawFd {
+        self.write.as_raw_fd()
+    }
+}
+
+impl<C: core::fmt::Debug + Clone + OpCompletion> UringBearer<C> {
+    /// Push a Splice or Tee implementing OpCode + traits (see io-uring-opcode)
+    pub fn push_splice<Op>(
+        &mut self,
+        op: Op,
+        flags: Option<SubmissionFlags>,
+    ) -> Result<usize, UringBearerError>
+    where
+        Op: OpCode<C> + OpExtSplice,
+    {
+        self._splice_fds_validate(&op)?;
+        let key = self._take_splice(op)?;
+
+        self._push_new(key, flags)
+    }
+    /// Create the intermediate pipe pair and push the two splices built from it, the
+    /// first moving into the pipe and linked to the second moving out of it, e.g.
+    /// socket -> pipe -> socket without copying through the userspace.
+    /// The link is hard as the kernel otherwise severs it when the first moves less
+    /// than its len. The second must set SPLICE_F_NONBLOCK so that it completes with
+    /// -EAGAIN instead of blocking when the first moved nothing into the pipe.
+    /// Both are pushed or neither of these.
+    /// Returns the keys of the two splices respectively and the pipe pair which must be
+    /// kept open until both have completed.
+    pub fn push_splice_through_pipe<Op, F>(
+        &mut self,
+        build: F,
+        flags: Option<SubmissionFlags>,
+    ) -> Result<(usize, usize, SplicePipe), UringBearerError>
+    where
+        Op: OpCode<C> + OpExtSplice,
+        F: FnOnce(&SplicePipe) -> (Op, Op),
+    {
+        let pipe = SplicePipe::new()?;
+        let (to_pipe, from_pipe) = build(&pipe);
+        if to_pipe.fd_out() != FdRef::Regular(pipe.write_fd())
+            || from_pipe.fd_in() != FdRef::Regular(pipe.read_fd())
+        {
+            return Err(UringBearerError::SplicePipeMismatch);
+        }
+        if from_pipe.splice_flags() & libc::SPLICE_F_NONBLOCK == 0 {
+            return Err(UringBearerError::SplicePipeBlocking);
+        }
+        self._splice_fds_validate(&to_pipe)?;
+        self._splice_fds_validate(&from_pipe)?;
+        let to_pipe_key = self._take_splice(to_pipe)?;
+        let from_pipe_key = match self._take_splice(from_pipe) {
+            Ok(key) => key,
+            Err(e) => {
+                self._release_unpushed(&[to_pipe_key])?;
+                return Err(e);
+            }
+        };
+        let first_flags = flags.unwrap_or_default().on_io_hard_link();
+        if let Err(e) = self._push_records(
+            &[(to_pipe_key, Some(first_flags)), (from_pipe_key, flags)],
+            false,
+        ) {
+            self._release_unpushed(&[to_pipe_key, from_pipe_key])?;
+            return Err(e);
+        }
+        Ok((to_pipe_key, from_pipe_key, pipe))
+    }
+    fn _take_splice<Op>(&mut self, op: Op) -> Result<usize, UringBearerError>
+    where
+        Op: OpCode<C> + OpExtSplice,
+    {
+        let submission = op.submission()?;
+        self._op_supported(submission.opcode())?;
+        self._take_record(Completion::Splice(submission))
+    }
+    fn _splice_fds_validate<Op: OpExtSplice>(&self, op: &Op) -> Result<(), UringBearerError> {
+        for fd in [op.fd_in(), op.fd_out()] {
+            if let Some(fixed_fd) = fd.fixed() {
+                if !self._fixed_fd_validate(fixed_fd) {
+                    return Err(UringBearerError::FdNotRegistered(fixed_fd));
+                }
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,84 @@
+//! Interaface for pushing Timeout and LinkTimeout implementing OpExtTimeout
+
+use super::UringBearer;
+use crate::error::UringBearerError;
+use crate::Completion;
+use crate::SubmissionFlags;
+use io_uring_opcode::OpExtTimeout;
+use io_uring_opcode::{OpCode, OpCompletion};
+use slabbable::Slabbable;
+
+impl<C: core::fmt::Debug + Clone + OpCompletion> UringBearer<C> {
+    /// Push a standalone Timeout implementing OpCode +  traits (see io-uring-opcode)
+    pub fn push_timeout<Op>(
+        &mut self,
+        op: Op,
+        flags: Option<SubmissionFlags>,
+    ) -> Result<usize, UringBearerError>
+    where
+        Op: OpCode<C> + OpExtTimeout,
+    {
+        if op.is_linked() {
+            return Err(UringBearerError::TimeoutLinkMismatch);
+        }
+        self._push_timeout_rec(op, flags)
+    }
+    /// Push the record just taken linked to the LinkTimeout cancelling it upon expiry, both
+    /// contiguously or neither in which case the record is released.
+    /// Returns the keys of the record and the LinkTimeout respectively.
+    pub(crate) fn _push_with_link_timeout<Op>(
+        &mut self,
+        key: usize,
+        flags: Option<SubmissionFlags>,
+        link_timeout: Op,
+    ) -> Result<(usize, usize), UringBearerError>
+    where
+        Op: OpCode<C> + OpExtTimeout,
+    {
+        let timeout_key = match self._take_link_timeout(link_timeout) {
+            Ok(timeout_key) => timeout_key,
+            Err(e) => {
+                self._release_unpushed(&[key])?;
+                return Err(e);
+            }
+        };
+        let flags = flags.unwrap_or_default().on_io_link();
+        if let Err(e) = self._push_records(&[(key, Some(flags)), (timeout_key, None)], false) {
+            self._release_unpushed(&[key, timeout_key])?;
+            return Err(e);
+        }
+        Ok((key, timeout_key))
+    }
+    fn _take_link_timeout<Op>(&mut self, op: Op) -> Result<usize, UringBearerError>
+    where
+        Op: OpCode<C> + OpExtTimeout,
+    {
+        if !op.is_linked() {
+            return Err(UringBearerError::TimeoutLinkMismatch);
+        }
+        let submission = op.submission()?;
+        self._op_supported(submission.opcode())?;
+
+        self.fd_slab
+            .take_next_with(Completion::Timeout(submission))
+            .map_err(UringBearerError::Slabbable)
+    }
+    fn _push_timeout_rec<Op>(
+        &mut self,
+        op: Op,
+        flags: Option<SubmissionFlags>,
+    ) -> Result<usize, UringBearerError>
+    where
+        Op: OpCode<C> + OpExtTimeout,
+    {
+        let submission = op.submission()?;
+        self._op_supported(submission.opcode())?;
+
+        let key = self
+            .fd_slab
+            .take_next_with(Completion::Timeout(submission))
+            .map_err(UringBearerError::Slabbable)?;
+
+        self._push_new(key, flags)
+    }
+}
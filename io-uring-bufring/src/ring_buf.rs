@@ -177,7 +177,7 @@ impl RingBufUnregistered {
     {
         // SAFETY: We hold the underlying ringbuf and keep it valid
         let r = unsafe {
-            bearer.io_uring().submitter().register_buf_ring_with_flags(
+            bearer.register_buf_ring(
                 self.ring_start.as_ptr_mut() as _,
                 self.total_bufs_count(), // TODO: hardcoded atm. abstract this in hugetbl
                 bgid,
@@ -216,7 +216,7 @@ impl RingBufRegistered {
     where
         W: core::fmt::Debug + Clone + io_uring_opcode::OpCompletion,
     {
-        let r = bearer.io_uring().submitter().unregister_buf_ring(self.bgid);
+        let r = bearer.unregister_buf_ring(self.bgid);
 
         match r {
             Err(e) => Err(RingBufError::Unregister(self, e)),
@@ -46,7 +46,7 @@ fn main() {
     let epoll_ctl = EpollCtl::with_epfd_handled(epfd, handle_fd, 666).unwrap();
 
     // Push the EpollCtl into the UringBearer
-    let ctl_idx = bearer.push_op(epoll_ctl).unwrap();
+    let ctl_idx = bearer.push_op(epoll_ctl, None).unwrap();
 
     // This is the indexed EpollCtl index for later modifications.
     println!("EpollCtl Index is = {}", ctl_idx);
@@ -1,7 +1,5 @@
 //! EpollCtl Record
 
-use core::pin::Pin;
-
 use crate::error::EpollCtlError;
 
 use crate::HandledFd;
@@ -70,6 +68,7 @@ impl EpollOpKind {
 
 impl OpCompletion for EpollCtl {
     type Error = OpError;
+    type Output = ();
     fn entry(&self) -> io_uring::squeue::Entry {
         let ez_ptr = std::ptr::addr_of!(self.ev);
         io_uring::opcode::EpollCtl::new(
@@ -80,6 +79,9 @@ impl OpCompletion for EpollCtl {
         )
         .build()
     }
+    fn opcode(&self) -> u8 {
+        io_uring::opcode::EpollCtl::CODE
+    }
     fn owner(&self) -> Owner {
         self.owner.clone()
     }
@@ -87,15 +89,23 @@ impl OpCompletion for EpollCtl {
         self.owner = Owner::Kernel;
         true
     }
+    fn fixed_fd(&self) -> Option<u32> {
+        Some(self.epfd as u32)
+    }
+    /// The epoll_ctl(2) is applied when the result is zero, otherwise the negative errno.
+    fn completion(&mut self, cqe: &io_uring::cqueue::Entry) -> Result<(), OpError> {
+        self.owner = Owner::Returned;
+        match cqe.result() {
+            res if res < 0 => Err(OpError::from_cqe_result("EpollCtl", res)),
+            _ => Ok(()),
+        }
+    }
 }
 
 impl OpCode<EpollCtl> for EpollCtl {
     fn submission(self) -> Result<EpollCtl, OpError> {
         Ok(self)
     }
-    fn completion(&mut self, _: Pin<&mut EpollCtl>) -> Result<(), OpError> {
-        todo!()
-    }
 }
 
 impl OpExtEpollCtl for EpollCtl {
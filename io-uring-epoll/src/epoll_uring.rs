@@ -20,6 +20,13 @@ impl EpollUringHandler {
     pub fn with_bearer<C: core::fmt::Debug + Clone + OpCompletion>(
         bearer: &mut UringBearer<C>,
     ) -> Result<Self, EpollUringHandlerError> {
+        if !bearer
+            .capabilities()
+            .is_supported(io_uring::opcode::EpollCtl::CODE)
+        {
+            return Err(EpollUringHandlerError::NotSupported);
+        }
+
         // SAFETY: FFI no-data in
         let epfd = unsafe { libc::epoll_create1(0) };
         if epfd == -1 {
@@ -4,6 +4,7 @@ use core::fmt;
 use core::fmt::Display;
 
 use io_uring_bearer::error::UringBearerError;
+use io_uring_opcode::OpError;
 
 /// EpollCtl Errors
 #[derive(Debug)]
@@ -11,7 +12,13 @@ pub enum EpollCtlError {}
 
 impl Display for EpollCtlError {
     fn fmt(&self, _: &mut fmt::Formatter<'_>) -> fmt::Result {
-        todo!()
+        match *self {}
+    }
+}
+
+impl From<EpollCtlError> for OpError {
+    fn from(e: EpollCtlError) -> OpError {
+        match e {}
     }
 }
 
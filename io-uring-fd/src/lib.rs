@@ -24,6 +24,17 @@ impl RegisteredFd {
     pub fn from_raw(raw_fd: RawFd, kind: FdKind) -> Self {
         Self { kind, raw_fd }
     }
+    /// Create Registered filehandle installed by the kernel straight into the fixed slot
+    /// e.g. through a fixed Socket or Accept, which has no regular RawFd (-1).
+    #[inline]
+    pub fn kernel_installed(kind: FdKind) -> Self {
+        Self { kind, raw_fd: -1 }
+    }
+    /// Whether the filehandle was installed by the kernel without a regular RawFd
+    #[inline]
+    pub fn is_kernel_installed(&self) -> bool {
+        self.raw_fd < 0
+    }
 }
 
 /// Type of Fd mainly used by the safe API
@@ -39,4 +50,6 @@ pub enum FdKind {
     Send,
     /// Recv-Send Handle
     RecvSend,
+    /// File handle e.g. opened via OpenAt
+    File,
 }
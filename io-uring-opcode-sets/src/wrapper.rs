@@ -1,50 +1,691 @@
 //! magic
 
-use core::pin::Pin;
 use io_uring_opcode::{OpCode, OpCompletion, OpError};
 
+#[cfg(feature = "bind_listen")]
+pub use io_uring_op_bind_listen::{Bind, Listen};
+
+#[cfg(feature = "sockopt")]
+pub use io_uring_op_sockopt::{GetSockOpt, QueuedBytes, SetSockOpt, SockOpt};
+
 #[cfg(feature = "connect")]
 pub use io_uring_op_connect::Connect;
 
+#[cfg(feature = "fs")]
+pub use io_uring_op_fs::{
+    Close, Fallocate, Fsync, Ftruncate, OpenAt, OpenAt2, Statx, SyncFileRange,
+};
+
+#[cfg(feature = "path")]
+pub use io_uring_op_path::{LinkAt, MkdirAt, RenameAt, SymlinkAt, UnlinkAt};
+
+#[cfg(feature = "rw")]
+pub use io_uring_op_rw::{Read, ReadFixed, Readv, Write, WriteFixed, Writev};
+
+#[cfg(feature = "send")]
+pub use io_uring_op_send::{Send, SendMsg};
+
 #[cfg(feature = "socket")]
 pub use io_uring_op_socket::Socket;
 
-/// unreachable
-#[derive(Clone, Debug)]
-pub enum WrapperError {}
+#[cfg(any(feature = "fs", feature = "socket"))]
+use io_uring_opcode::types::CompletedFd;
+
+#[cfg(feature = "splice")]
+pub use io_uring_op_splice::{Splice, Tee};
+
+#[cfg(feature = "timeout")]
+pub use io_uring_op_timeout::{LinkTimeout, LinkTimeoutFired, Timeout, TimeoutFired};
 
 /// Wrapper for all the possible OpCodes
 #[derive(Clone, Debug)]
 pub enum Wrapper {
+    /// Bind OpCode
+    #[cfg(feature = "bind_listen")]
+    Bind(Bind),
+    /// Listen OpCode
+    #[cfg(feature = "bind_listen")]
+    Listen(Listen),
+    /// SetSockOpt OpCode
+    #[cfg(feature = "sockopt")]
+    SetSockOpt(SetSockOpt),
+    /// GetSockOpt OpCode
+    #[cfg(feature = "sockopt")]
+    GetSockOpt(GetSockOpt),
+    /// QueuedBytes OpCode
+    #[cfg(feature = "sockopt")]
+    QueuedBytes(QueuedBytes),
     /// Connect OpCode
     #[cfg(feature = "connect")]
     Connect(Connect),
+    /// OpenAt OpCode
+    #[cfg(feature = "fs")]
+    OpenAt(OpenAt),
+    /// OpenAt2 OpCode
+    #[cfg(feature = "fs")]
+    OpenAt2(OpenAt2),
+    /// Close OpCode
+    #[cfg(feature = "fs")]
+    Close(Close),
+    /// Statx OpCode
+    #[cfg(feature = "fs")]
+    Statx(Statx),
+    /// Fsync OpCode
+    #[cfg(feature = "fs")]
+    Fsync(Fsync),
+    /// SyncFileRange OpCode
+    #[cfg(feature = "fs")]
+    SyncFileRange(SyncFileRange),
+    /// Fallocate OpCode
+    #[cfg(feature = "fs")]
+    Fallocate(Fallocate),
+    /// Ftruncate OpCode
+    #[cfg(feature = "fs")]
+    Ftruncate(Ftruncate),
+    /// LinkAt OpCode
+    #[cfg(feature = "path")]
+    LinkAt(LinkAt),
+    /// MkdirAt OpCode
+    #[cfg(feature = "path")]
+    MkdirAt(MkdirAt),
+    /// RenameAt OpCode
+    #[cfg(feature = "path")]
+    RenameAt(RenameAt),
+    /// SymlinkAt OpCode
+    #[cfg(feature = "path")]
+    SymlinkAt(SymlinkAt),
+    /// UnlinkAt OpCode
+    #[cfg(feature = "path")]
+    UnlinkAt(UnlinkAt),
+    /// Read OpCode
+    #[cfg(feature = "rw")]
+    Read(Read),
+    /// ReadFixed OpCode
+    #[cfg(feature = "rw")]
+    ReadFixed(ReadFixed),
+    /// Readv OpCode
+    #[cfg(feature = "rw")]
+    Readv(Readv),
+    /// Write OpCode
+    #[cfg(feature = "rw")]
+    Write(Write),
+    /// WriteFixed OpCode
+    #[cfg(feature = "rw")]
+    WriteFixed(WriteFixed),
+    /// Writev OpCode
+    #[cfg(feature = "rw")]
+    Writev(Writev),
+    /// Send OpCode
+    #[cfg(feature = "send")]
+    Send(Send),
+    /// SendMsg OpCode
+    #[cfg(feature = "send")]
+    SendMsg(SendMsg),
     /// Socket OpCode
     #[cfg(feature = "socket")]
     Socket(Socket),
+    /// Splice OpCode
+    #[cfg(feature = "splice")]
+    Splice(Splice),
+    /// Tee OpCode
+    #[cfg(feature = "splice")]
+    Tee(Tee),
+    /// Timeout OpCode
+    #[cfg(feature = "timeout")]
+    Timeout(Timeout),
+    /// LinkTimeout OpCode
+    #[cfg(feature = "timeout")]
+    LinkTimeout(LinkTimeout),
+}
+
+/// Typed completion results for all the possible OpCodes
+#[derive(Clone, Debug, PartialEq)]
+pub enum WrapperOutput {
+    /// Bind OpCode completed
+    #[cfg(feature = "bind_listen")]
+    Bind(()),
+    /// Listen OpCode completed
+    #[cfg(feature = "bind_listen")]
+    Listen(()),
+    /// SetSockOpt OpCode completed
+    #[cfg(feature = "sockopt")]
+    SetSockOpt(()),
+    /// GetSockOpt OpCode completed with the typed option
+    #[cfg(feature = "sockopt")]
+    GetSockOpt(SockOpt),
+    /// QueuedBytes OpCode completed with the queued bytes
+    #[cfg(feature = "sockopt")]
+    QueuedBytes(usize),
+    /// Connect OpCode completed
+    #[cfg(feature = "connect")]
+    Connect(()),
+    /// OpenAt OpCode completed with the resulting filehandle
+    #[cfg(feature = "fs")]
+    OpenAt(CompletedFd),
+    /// OpenAt2 OpCode completed with the resulting filehandle
+    #[cfg(feature = "fs")]
+    OpenAt2(CompletedFd),
+    /// Close OpCode completed
+    #[cfg(feature = "fs")]
+    Close(()),
+    /// Statx OpCode completed with the resulting statx
+    #[cfg(feature = "fs")]
+    Statx(libc::statx),
+    /// Fsync OpCode completed
+    #[cfg(feature = "fs")]
+    Fsync(()),
+    /// SyncFileRange OpCode completed
+    #[cfg(feature = "fs")]
+    SyncFileRange(()),
+    /// Fallocate OpCode completed
+    #[cfg(feature = "fs")]
+    Fallocate(()),
+    /// Ftruncate OpCode completed
+    #[cfg(feature = "fs")]
+    Ftruncate(()),
+    /// LinkAt OpCode completed
+    #[cfg(feature = "path")]
+    LinkAt(()),
+    /// MkdirAt OpCode completed
+    #[cfg(feature = "path")]
+    MkdirAt(()),
+    /// RenameAt OpCode completed
+    #[cfg(feature = "path")]
+    RenameAt(()),
+    /// SymlinkAt OpCode completed
+    #[cfg(feature = "path")]
+    SymlinkAt(()),
+    /// UnlinkAt OpCode completed
+    #[cfg(feature = "path")]
+    UnlinkAt(()),
+    /// Read OpCode completed with the count of bytes read
+    #[cfg(feature = "rw")]
+    Read(usize),
+    /// ReadFixed OpCode completed with the count of bytes read
+    #[cfg(feature = "rw")]
+    ReadFixed(usize),
+    /// Readv OpCode completed with the count of bytes read
+    #[cfg(feature = "rw")]
+    Readv(usize),
+    /// Write OpCode completed with the count of bytes written
+    #[cfg(feature = "rw")]
+    Write(usize),
+    /// WriteFixed OpCode completed with the count of bytes written
+    #[cfg(feature = "rw")]
+    WriteFixed(usize),
+    /// Writev OpCode completed with the count of bytes written
+    #[cfg(feature = "rw")]
+    Writev(usize),
+    /// Send OpCode completed with the count of bytes sent
+    #[cfg(feature = "send")]
+    Send(usize),
+    /// SendMsg OpCode completed with the count of bytes sent
+    #[cfg(feature = "send")]
+    SendMsg(usize),
+    /// Socket OpCode completed with the resulting filehandle
+    #[cfg(feature = "socket")]
+    Socket(CompletedFd),
+    /// Splice OpCode completed with the count of bytes moved
+    #[cfg(feature = "splice")]
+    Splice(usize),
+    /// Tee OpCode completed with the count of bytes duplicated
+    #[cfg(feature = "splice")]
+    Tee(usize),
+    /// Timeout OpCode completed
+    #[cfg(feature = "timeout")]
+    Timeout(TimeoutFired),
+    /// LinkTimeout OpCode completed
+    #[cfg(feature = "timeout")]
+    LinkTimeout(LinkTimeoutFired),
 }
 
 impl OpCompletion for Wrapper {
-    type Error = WrapperError;
+    type Error = OpError;
+    type Output = WrapperOutput;
     #[inline]
     fn entry(&self) -> io_uring::squeue::Entry {
         match self {
+            #[cfg(feature = "bind_listen")]
+            Self::Bind(i) => i.entry(),
+            #[cfg(feature = "bind_listen")]
+            Self::Listen(i) => i.entry(),
+            #[cfg(feature = "sockopt")]
+            Self::SetSockOpt(i) => i.entry(),
+            #[cfg(feature = "sockopt")]
+            Self::GetSockOpt(i) => i.entry(),
+            #[cfg(feature = "sockopt")]
+            Self::QueuedBytes(i) => i.entry(),
+            #[cfg(feature = "connect")]
             Self::Connect(i) => i.entry(),
+            #[cfg(feature = "fs")]
+            Self::OpenAt(i) => i.entry(),
+            #[cfg(feature = "fs")]
+            Self::OpenAt2(i) => i.entry(),
+            #[cfg(feature = "fs")]
+            Self::Close(i) => i.entry(),
+            #[cfg(feature = "fs")]
+            Self::Statx(i) => i.entry(),
+            #[cfg(feature = "fs")]
+            Self::Fsync(i) => i.entry(),
+            #[cfg(feature = "fs")]
+            Self::SyncFileRange(i) => i.entry(),
+            #[cfg(feature = "fs")]
+            Self::Fallocate(i) => i.entry(),
+            #[cfg(feature = "fs")]
+            Self::Ftruncate(i) => i.entry(),
+            #[cfg(feature = "path")]
+            Self::LinkAt(i) => i.entry(),
+            #[cfg(feature = "path")]
+            Self::MkdirAt(i) => i.entry(),
+            #[cfg(feature = "path")]
+            Self::RenameAt(i) => i.entry(),
+            #[cfg(feature = "path")]
+            Self::SymlinkAt(i) => i.entry(),
+            #[cfg(feature = "path")]
+            Self::UnlinkAt(i) => i.entry(),
+            #[cfg(feature = "rw")]
+            Self::Read(i) => i.entry(),
+            #[cfg(feature = "rw")]
+            Self::ReadFixed(i) => i.entry(),
+            #[cfg(feature = "rw")]
+            Self::Readv(i) => i.entry(),
+            #[cfg(feature = "rw")]
+            Self::Write(i) => i.entry(),
+            #[cfg(feature = "rw")]
+            Self::WriteFixed(i) => i.entry(),
+            #[cfg(feature = "rw")]
+            Self::Writev(i) => i.entry(),
+            #[cfg(feature = "send")]
+            Self::Send(i) => i.entry(),
+            #[cfg(feature = "send")]
+            Self::SendMsg(i) => i.entry(),
+            #[cfg(feature = "socket")]
             Self::Socket(i) => i.entry(),
+            #[cfg(feature = "splice")]
+            Self::Splice(i) => i.entry(),
+            #[cfg(feature = "splice")]
+            Self::Tee(i) => i.entry(),
+            #[cfg(feature = "timeout")]
+            Self::Timeout(i) => i.entry(),
+            #[cfg(feature = "timeout")]
+            Self::LinkTimeout(i) => i.entry(),
+        }
+    }
+    #[inline]
+    fn opcode(&self) -> u8 {
+        match self {
+            #[cfg(feature = "bind_listen")]
+            Self::Bind(i) => i.opcode(),
+            #[cfg(feature = "bind_listen")]
+            Self::Listen(i) => i.opcode(),
+            #[cfg(feature = "sockopt")]
+            Self::SetSockOpt(i) => i.opcode(),
+            #[cfg(feature = "sockopt")]
+            Self::GetSockOpt(i) => i.opcode(),
+            #[cfg(feature = "sockopt")]
+            Self::QueuedBytes(i) => i.opcode(),
+            #[cfg(feature = "connect")]
+            Self::Connect(i) => i.opcode(),
+            #[cfg(feature = "fs")]
+            Self::OpenAt(i) => i.opcode(),
+            #[cfg(feature = "fs")]
+            Self::OpenAt2(i) => i.opcode(),
+            #[cfg(feature = "fs")]
+            Self::Close(i) => i.opcode(),
+            #[cfg(feature = "fs")]
+            Self::Statx(i) => i.opcode(),
+            #[cfg(feature = "fs")]
+            Self::Fsync(i) => i.opcode(),
+            #[cfg(feature = "fs")]
+            Self::SyncFileRange(i) => i.opcode(),
+            #[cfg(feature = "fs")]
+            Self::Fallocate(i) => i.opcode(),
+            #[cfg(feature = "fs")]
+            Self::Ftruncate(i) => i.opcode(),
+            #[cfg(feature = "path")]
+            Self::LinkAt(i) => i.opcode(),
+            #[cfg(feature = "path")]
+            Self::MkdirAt(i) => i.opcode(),
+            #[cfg(feature = "path")]
+            Self::RenameAt(i) => i.opcode(),
+            #[cfg(feature = "path")]
+            Self::SymlinkAt(i) => i.opcode(),
+            #[cfg(feature = "path")]
+            Self::UnlinkAt(i) => i.opcode(),
+            #[cfg(feature = "rw")]
+            Self::Read(i) => i.opcode(),
+            #[cfg(feature = "rw")]
+            Self::ReadFixed(i) => i.opcode(),
+            #[cfg(feature = "rw")]
+            Self::Readv(i) => i.opcode(),
+            #[cfg(feature = "rw")]
+            Self::Write(i) => i.opcode(),
+            #[cfg(feature = "rw")]
+            Self::WriteFixed(i) => i.opcode(),
+            #[cfg(feature = "rw")]
+            Self::Writev(i) => i.opcode(),
+            #[cfg(feature = "send")]
+            Self::Send(i) => i.opcode(),
+            #[cfg(feature = "send")]
+            Self::SendMsg(i) => i.opcode(),
+            #[cfg(feature = "socket")]
+            Self::Socket(i) => i.opcode(),
+            #[cfg(feature = "splice")]
+            Self::Splice(i) => i.opcode(),
+            #[cfg(feature = "splice")]
+            Self::Tee(i) => i.opcode(),
+            #[cfg(feature = "timeout")]
+            Self::Timeout(i) => i.opcode(),
+            #[cfg(feature = "timeout")]
+            Self::LinkTimeout(i) => i.opcode(),
         }
     }
     #[inline]
     fn owner(&self) -> io_uring_owner::Owner {
         match self {
+            #[cfg(feature = "bind_listen")]
+            Self::Bind(i) => i.owner(),
+            #[cfg(feature = "bind_listen")]
+            Self::Listen(i) => i.owner(),
+            #[cfg(feature = "sockopt")]
+            Self::SetSockOpt(i) => i.owner(),
+            #[cfg(feature = "sockopt")]
+            Self::GetSockOpt(i) => i.owner(),
+            #[cfg(feature = "sockopt")]
+            Self::QueuedBytes(i) => i.owner(),
+            #[cfg(feature = "connect")]
             Self::Connect(i) => i.owner(),
+            #[cfg(feature = "fs")]
+            Self::OpenAt(i) => i.owner(),
+            #[cfg(feature = "fs")]
+            Self::OpenAt2(i) => i.owner(),
+            #[cfg(feature = "fs")]
+            Self::Close(i) => i.owner(),
+            #[cfg(feature = "fs")]
+            Self::Statx(i) => i.owner(),
+            #[cfg(feature = "fs")]
+            Self::Fsync(i) => i.owner(),
+            #[cfg(feature = "fs")]
+            Self::SyncFileRange(i) => i.owner(),
+            #[cfg(feature = "fs")]
+            Self::Fallocate(i) => i.owner(),
+            #[cfg(feature = "fs")]
+            Self::Ftruncate(i) => i.owner(),
+            #[cfg(feature = "path")]
+            Self::LinkAt(i) => i.owner(),
+            #[cfg(feature = "path")]
+            Self::MkdirAt(i) => i.owner(),
+            #[cfg(feature = "path")]
+            Self::RenameAt(i) => i.owner(),
+            #[cfg(feature = "path")]
+            Self::SymlinkAt(i) => i.owner(),
+            #[cfg(feature = "path")]
+            Self::UnlinkAt(i) => i.owner(),
+            #[cfg(feature = "rw")]
+            Self::Read(i) => i.owner(),
+            #[cfg(feature = "rw")]
+            Self::ReadFixed(i) => i.owner(),
+            #[cfg(feature = "rw")]
+            Self::Readv(i) => i.owner(),
+            #[cfg(feature = "rw")]
+            Self::Write(i) => i.owner(),
+            #[cfg(feature = "rw")]
+            Self::WriteFixed(i) => i.owner(),
+            #[cfg(feature = "rw")]
+            Self::Writev(i) => i.owner(),
+            #[cfg(feature = "send")]
+            Self::Send(i) => i.owner(),
+            #[cfg(feature = "send")]
+            Self::SendMsg(i) => i.owner(),
+            #[cfg(feature = "socket")]
             Self::Socket(i) => i.owner(),
+            #[cfg(feature = "splice")]
+            Self::Splice(i) => i.owner(),
+            #[cfg(feature = "splice")]
+            Self::Tee(i) => i.owner(),
+            #[cfg(feature = "timeout")]
+            Self::Timeout(i) => i.owner(),
+            #[cfg(feature = "timeout")]
+            Self::LinkTimeout(i) => i.owner(),
         }
     }
     #[inline]
     fn force_owner_kernel(&mut self) -> bool {
         match self {
+            #[cfg(feature = "bind_listen")]
+            Self::Bind(i) => i.force_owner_kernel(),
+            #[cfg(feature = "bind_listen")]
+            Self::Listen(i) => i.force_owner_kernel(),
+            #[cfg(feature = "sockopt")]
+            Self::SetSockOpt(i) => i.force_owner_kernel(),
+            #[cfg(feature = "sockopt")]
+            Self::GetSockOpt(i) => i.force_owner_kernel(),
+            #[cfg(feature = "sockopt")]
+            Self::QueuedBytes(i) => i.force_owner_kernel(),
+            #[cfg(feature = "connect")]
             Self::Connect(i) => i.force_owner_kernel(),
+            #[cfg(feature = "fs")]
+            Self::OpenAt(i) => i.force_owner_kernel(),
+            #[cfg(feature = "fs")]
+            Self::OpenAt2(i) => i.force_owner_kernel(),
+            #[cfg(feature = "fs")]
+            Self::Close(i) => i.force_owner_kernel(),
+            #[cfg(feature = "fs")]
+            Self::Statx(i) => i.force_owner_kernel(),
+            #[cfg(feature = "fs")]
+            Self::Fsync(i) => i.force_owner_kernel(),
+            #[cfg(feature = "fs")]
+            Self::SyncFileRange(i) => i.force_owner_kernel(),
+            #[cfg(feature = "fs")]
+            Self::Fallocate(i) => i.force_owner_kernel(),
+            #[cfg(feature = "fs")]
+            Self::Ftruncate(i) => i.force_owner_kernel(),
+            #[cfg(feature = "path")]
+            Self::LinkAt(i) => i.force_owner_kernel(),
+            #[cfg(feature = "path")]
+            Self::MkdirAt(i) => i.force_owner_kernel(),
+            #[cfg(feature = "path")]
+            Self::RenameAt(i) => i.force_owner_kernel(),
+            #[cfg(feature = "path")]
+            Self::SymlinkAt(i) => i.force_owner_kernel(),
+            #[cfg(feature = "path")]
+            Self::UnlinkAt(i) => i.force_owner_kernel(),
+            #[cfg(feature = "rw")]
+            Self::Read(i) => i.force_owner_kernel(),
+            #[cfg(feature = "rw")]
+            Self::ReadFixed(i) => i.force_owner_kernel(),
+            #[cfg(feature = "rw")]
+            Self::Readv(i) => i.force_owner_kernel(),
+            #[cfg(feature = "rw")]
+            Self::Write(i) => i.force_owner_kernel(),
+            #[cfg(feature = "rw")]
+            Self::WriteFixed(i) => i.force_owner_kernel(),
+            #[cfg(feature = "rw")]
+            Self::Writev(i) => i.force_owner_kernel(),
+            #[cfg(feature = "send")]
+            Self::Send(i) => i.force_owner_kernel(),
+            #[cfg(feature = "send")]
+            Self::SendMsg(i) => i.force_owner_kernel(),
+            #[cfg(feature = "socket")]
             Self::Socket(i) => i.force_owner_kernel(),
+            #[cfg(feature = "splice")]
+            Self::Splice(i) => i.force_owner_kernel(),
+            #[cfg(feature = "splice")]
+            Self::Tee(i) => i.force_owner_kernel(),
+            #[cfg(feature = "timeout")]
+            Self::Timeout(i) => i.force_owner_kernel(),
+            #[cfg(feature = "timeout")]
+            Self::LinkTimeout(i) => i.force_owner_kernel(),
+        }
+    }
+    #[inline]
+    fn fixed_fd(&self) -> Option<u32> {
+        match self {
+            #[cfg(feature = "bind_listen")]
+            Self::Bind(i) => i.fixed_fd(),
+            #[cfg(feature = "bind_listen")]
+            Self::Listen(i) => i.fixed_fd(),
+            #[cfg(feature = "sockopt")]
+            Self::SetSockOpt(i) => i.fixed_fd(),
+            #[cfg(feature = "sockopt")]
+            Self::GetSockOpt(i) => i.fixed_fd(),
+            #[cfg(feature = "sockopt")]
+            Self::QueuedBytes(i) => i.fixed_fd(),
+            #[cfg(feature = "connect")]
+            Self::Connect(i) => i.fixed_fd(),
+            #[cfg(feature = "fs")]
+            Self::OpenAt(i) => i.fixed_fd(),
+            #[cfg(feature = "fs")]
+            Self::OpenAt2(i) => i.fixed_fd(),
+            #[cfg(feature = "fs")]
+            Self::Close(i) => i.fixed_fd(),
+            #[cfg(feature = "fs")]
+            Self::Statx(i) => i.fixed_fd(),
+            #[cfg(feature = "fs")]
+            Self::Fsync(i) => i.fixed_fd(),
+            #[cfg(feature = "fs")]
+            Self::SyncFileRange(i) => i.fixed_fd(),
+            #[cfg(feature = "fs")]
+            Self::Fallocate(i) => i.fixed_fd(),
+            #[cfg(feature = "fs")]
+            Self::Ftruncate(i) => i.fixed_fd(),
+            #[cfg(feature = "path")]
+            Self::LinkAt(i) => i.fixed_fd(),
+            #[cfg(feature = "path")]
+            Self::MkdirAt(i) => i.fixed_fd(),
+            #[cfg(feature = "path")]
+            Self::RenameAt(i) => i.fixed_fd(),
+            #[cfg(feature = "path")]
+            Self::SymlinkAt(i) => i.fixed_fd(),
+            #[cfg(feature = "path")]
+            Self::UnlinkAt(i) => i.fixed_fd(),
+            #[cfg(feature = "rw")]
+            Self::Read(i) => i.fixed_fd(),
+            #[cfg(feature = "rw")]
+            Self::ReadFixed(i) => i.fixed_fd(),
+            #[cfg(feature = "rw")]
+            Self::Readv(i) => i.fixed_fd(),
+            #[cfg(feature = "rw")]
+            Self::Write(i) => i.fixed_fd(),
+            #[cfg(feature = "rw")]
+            Self::WriteFixed(i) => i.fixed_fd(),
+            #[cfg(feature = "rw")]
+            Self::Writev(i) => i.fixed_fd(),
+            #[cfg(feature = "send")]
+            Self::Send(i) => i.fixed_fd(),
+            #[cfg(feature = "send")]
+            Self::SendMsg(i) => i.fixed_fd(),
+            #[cfg(feature = "socket")]
+            Self::Socket(i) => i.fixed_fd(),
+            #[cfg(feature = "splice")]
+            Self::Splice(i) => i.fixed_fd(),
+            #[cfg(feature = "splice")]
+            Self::Tee(i) => i.fixed_fd(),
+            #[cfg(feature = "timeout")]
+            Self::Timeout(i) => i.fixed_fd(),
+            #[cfg(feature = "timeout")]
+            Self::LinkTimeout(i) => i.fixed_fd(),
+        }
+    }
+    #[inline]
+    fn buf_idxs(&self) -> &[usize] {
+        match self {
+            #[cfg(feature = "rw")]
+            Self::Read(i) => i.buf_idxs(),
+            #[cfg(feature = "rw")]
+            Self::ReadFixed(i) => i.buf_idxs(),
+            #[cfg(feature = "rw")]
+            Self::Readv(i) => i.buf_idxs(),
+            #[cfg(feature = "rw")]
+            Self::Write(i) => i.buf_idxs(),
+            #[cfg(feature = "rw")]
+            Self::WriteFixed(i) => i.buf_idxs(),
+            #[cfg(feature = "rw")]
+            Self::Writev(i) => i.buf_idxs(),
+            #[cfg(feature = "send")]
+            Self::Send(i) => i.buf_idxs(),
+            #[cfg(feature = "send")]
+            Self::SendMsg(i) => i.buf_idxs(),
+            #[allow(unreachable_patterns)]
+            _ => &[],
+        }
+    }
+    #[inline]
+    fn is_multishot(&self) -> bool {
+        match self {
+            #[cfg(feature = "timeout")]
+            Self::Timeout(i) => i.is_multishot(),
+            #[allow(unreachable_patterns)]
+            _ => false,
+        }
+    }
+    #[inline]
+    fn completion(&mut self, cqe: &io_uring::cqueue::Entry) -> Result<WrapperOutput, OpError> {
+        match self {
+            #[cfg(feature = "bind_listen")]
+            Self::Bind(i) => i.completion(cqe).map(WrapperOutput::Bind),
+            #[cfg(feature = "bind_listen")]
+            Self::Listen(i) => i.completion(cqe).map(WrapperOutput::Listen),
+            #[cfg(feature = "sockopt")]
+            Self::SetSockOpt(i) => i.completion(cqe).map(WrapperOutput::SetSockOpt),
+            #[cfg(feature = "sockopt")]
+            Self::GetSockOpt(i) => i.completion(cqe).map(WrapperOutput::GetSockOpt),
+            #[cfg(feature = "sockopt")]
+            Self::QueuedBytes(i) => i.completion(cqe).map(WrapperOutput::QueuedBytes),
+            #[cfg(feature = "connect")]
+            Self::Connect(i) => i.completion(cqe).map(WrapperOutput::Connect),
+            #[cfg(feature = "fs")]
+            Self::OpenAt(i) => i.completion(cqe).map(WrapperOutput::OpenAt),
+            #[cfg(feature = "fs")]
+            Self::OpenAt2(i) => i.completion(cqe).map(WrapperOutput::OpenAt2),
+            #[cfg(feature = "fs")]
+            Self::Close(i) => i.completion(cqe).map(WrapperOutput::Close),
+            #[cfg(feature = "fs")]
+            Self::Statx(i) => i.completion(cqe).map(WrapperOutput::Statx),
+            #[cfg(feature = "fs")]
+            Self::Fsync(i) => i.completion(cqe).map(WrapperOutput::Fsync),
+            #[cfg(feature = "fs")]
+            Self::SyncFileRange(i) => i.completion(cqe).map(WrapperOutput::SyncFileRange),
+            #[cfg(feature = "fs")]
+            Self::Fallocate(i) => i.completion(cqe).map(WrapperOutput::Fallocate),
+            #[cfg(feature = "fs")]
+            Self::Ftruncate(i) => i.completion(cqe).map(WrapperOutput::Ftruncate),
+            #[cfg(feature = "path")]
+            Self::LinkAt(i) => i.completion(cqe).map(WrapperOutput::LinkAt),
+            #[cfg(feature = "path")]
+            Self::MkdirAt(i) => i.completion(cqe).map(WrapperOutput::MkdirAt),
+            #[cfg(feature = "path")]
+            Self::RenameAt(i) => i.completion(cqe).map(WrapperOutput::RenameAt),
+            #[cfg(feature = "path")]
+            Self::SymlinkAt(i) => i.completion(cqe).map(WrapperOutput::SymlinkAt),
+            #[cfg(feature = "path")]
+            Self::UnlinkAt(i) => i.completion(cqe).map(WrapperOutput::UnlinkAt),
+            #[cfg(feature = "rw")]
+            Self::Read(i) => i.completion(cqe).map(WrapperOutput::Read),
+            #[cfg(feature = "rw")]
+            Self::ReadFixed(i) => i.completion(cqe).map(WrapperOutput::ReadFixed),
+            #[cfg(feature = "rw")]
+            Self::Readv(i) => i.completion(cqe).map(WrapperOutput::Readv),
+            #[cfg(feature = "rw")]
+            Self::Write(i) => i.completion(cqe).map(WrapperOutput::Write),
+            #[cfg(feature = "rw")]
+            Self::WriteFixed(i) => i.completion(cqe).map(WrapperOutput::WriteFixed),
+            #[cfg(feature = "rw")]
+            Self::Writev(i) => i.completion(cqe).map(WrapperOutput::Writev),
+            #[cfg(feature = "send")]
+            Self::Send(i) => i.completion(cqe).map(WrapperOutput::Send),
+            #[cfg(feature = "send")]
+            Self::SendMsg(i) => i.completion(cqe).map(WrapperOutput::SendMsg),
+            #[cfg(feature = "socket")]
+            Self::Socket(i) => i.completion(cqe).map(WrapperOutput::Socket),
+            #[cfg(feature = "splice")]
+            Self::Splice(i) => i.completion(cqe).map(WrapperOutput::Splice),
+            #[cfg(feature = "splice")]
+            Self::Tee(i) => i.completion(cqe).map(WrapperOutput::Tee),
+            #[cfg(feature = "timeout")]
+            Self::Timeout(i) => i.completion(cqe).map(WrapperOutput::Timeout),
+            #[cfg(feature = "timeout")]
+            Self::LinkTimeout(i) => i.completion(cqe).map(WrapperOutput::LinkTimeout),
         }
     }
 }
@@ -61,9 +702,6 @@ impl OpCode<Connect> for Wrapper {
     fn submission(self) -> Result<Connect, OpError> {
         todo!()
     }
-    fn completion(&mut self, _: Pin<&mut Connect>) -> Result<(), OpError> {
-        todo!()
-    }
 }
 
 #[cfg(feature = "socket")]
@@ -71,54 +709,289 @@ impl OpCode<Socket> for Wrapper {
     fn submission(self) -> Result<Socket, OpError> {
         todo!()
     }
-    fn completion(&mut self, _: Pin<&mut Socket>) -> Result<(), OpError> {
-        todo!()
-    }
 }
 
 impl OpCode<Wrapper> for Wrapper {
+    #[inline]
     fn submission(self) -> Result<Wrapper, OpError> {
-        todo!()
-    }
-    fn completion(&mut self, _: Pin<&mut Wrapper>) -> Result<(), OpError> {
-        todo!()
+        Ok(self)
     }
 }
 
+#[cfg(feature = "socket")]
 impl OpCode<Wrapper> for Socket {
     #[inline]
     fn submission(self) -> Result<Wrapper, OpError> {
         Ok(Wrapper::Socket(self))
     }
-    fn completion(&mut self, _: Pin<&mut Wrapper>) -> Result<(), OpError> {
-        todo!()
+}
+
+#[cfg(feature = "fs")]
+impl OpCode<Wrapper> for OpenAt {
+    #[inline]
+    fn submission(self) -> Result<Wrapper, OpError> {
+        Ok(Wrapper::OpenAt(self))
     }
 }
 
+#[cfg(feature = "fs")]
+impl OpCode<Wrapper> for OpenAt2 {
+    #[inline]
+    fn submission(self) -> Result<Wrapper, OpError> {
+        Ok(Wrapper::OpenAt2(self))
+    }
+}
+
+#[cfg(feature = "fs")]
+impl OpCode<Wrapper> for Close {
+    #[inline]
+    fn submission(self) -> Result<Wrapper, OpError> {
+        Ok(Wrapper::Close(self))
+    }
+}
+
+#[cfg(feature = "fs")]
+impl OpCode<Wrapper> for Statx {
+    #[inline]
+    fn submission(self) -> Result<Wrapper, OpError> {
+        Ok(Wrapper::Statx(self))
+    }
+}
+
+#[cfg(feature = "fs")]
+impl OpCode<Wrapper> for Fsync {
+    #[inline]
+    fn submission(self) -> Result<Wrapper, OpError> {
+        Ok(Wrapper::Fsync(self))
+    }
+}
+
+#[cfg(feature = "fs")]
+impl OpCode<Wrapper> for SyncFileRange {
+    #[inline]
+    fn submission(self) -> Result<Wrapper, OpError> {
+        Ok(Wrapper::SyncFileRange(self))
+    }
+}
+
+#[cfg(feature = "fs")]
+impl OpCode<Wrapper> for Fallocate {
+    #[inline]
+    fn submission(self) -> Result<Wrapper, OpError> {
+        Ok(Wrapper::Fallocate(self))
+    }
+}
+
+#[cfg(feature = "fs")]
+impl OpCode<Wrapper> for Ftruncate {
+    #[inline]
+    fn submission(self) -> Result<Wrapper, OpError> {
+        Ok(Wrapper::Ftruncate(self))
+    }
+}
+
+#[cfg(feature = "path")]
+impl OpCode<Wrapper> for LinkAt {
+    #[inline]
+    fn submission(self) -> Result<Wrapper, OpError> {
+        Ok(Wrapper::LinkAt(self))
+    }
+}
+
+#[cfg(feature = "path")]
+impl OpCode<Wrapper> for MkdirAt {
+    #[inline]
+    fn submission(self) -> Result<Wrapper, OpError> {
+        Ok(Wrapper::MkdirAt(self))
+    }
+}
+
+#[cfg(feature = "path")]
+impl OpCode<Wrapper> for RenameAt {
+    #[inline]
+    fn submission(self) -> Result<Wrapper, OpError> {
+        Ok(Wrapper::RenameAt(self))
+    }
+}
+
+#[cfg(feature = "path")]
+impl OpCode<Wrapper> for SymlinkAt {
+    #[inline]
+    fn submission(self) -> Result<Wrapper, OpError> {
+        Ok(Wrapper::SymlinkAt(self))
+    }
+}
+
+#[cfg(feature = "path")]
+impl OpCode<Wrapper> for UnlinkAt {
+    #[inline]
+    fn submission(self) -> Result<Wrapper, OpError> {
+        Ok(Wrapper::UnlinkAt(self))
+    }
+}
+
+#[cfg(feature = "rw")]
+impl OpCode<Wrapper> for Read {
+    #[inline]
+    fn submission(self) -> Result<Wrapper, OpError> {
+        Ok(Wrapper::Read(self))
+    }
+}
+
+#[cfg(feature = "rw")]
+impl OpCode<Wrapper> for ReadFixed {
+    #[inline]
+    fn submission(self) -> Result<Wrapper, OpError> {
+        Ok(Wrapper::ReadFixed(self))
+    }
+}
+
+#[cfg(feature = "rw")]
+impl OpCode<Wrapper> for Readv {
+    #[inline]
+    fn submission(self) -> Result<Wrapper, OpError> {
+        Ok(Wrapper::Readv(self))
+    }
+}
+
+#[cfg(feature = "rw")]
+impl OpCode<Wrapper> for Write {
+    #[inline]
+    fn submission(self) -> Result<Wrapper, OpError> {
+        Ok(Wrapper::Write(self))
+    }
+}
+
+#[cfg(feature = "rw")]
+impl OpCode<Wrapper> for WriteFixed {
+    #[inline]
+    fn submission(self) -> Result<Wrapper, OpError> {
+        Ok(Wrapper::WriteFixed(self))
+    }
+}
+
+#[cfg(feature = "rw")]
+impl OpCode<Wrapper> for Writev {
+    #[inline]
+    fn submission(self) -> Result<Wrapper, OpError> {
+        Ok(Wrapper::Writev(self))
+    }
+}
+
+#[cfg(feature = "send")]
+impl OpCode<Wrapper> for Send {
+    #[inline]
+    fn submission(self) -> Result<Wrapper, OpError> {
+        Ok(Wrapper::Send(self))
+    }
+}
+
+#[cfg(feature = "send")]
+impl OpCode<Wrapper> for SendMsg {
+    #[inline]
+    fn submission(self) -> Result<Wrapper, OpError> {
+        Ok(Wrapper::SendMsg(self))
+    }
+}
+
+#[cfg(feature = "splice")]
+impl OpCode<Wrapper> for Splice {
+    #[inline]
+    fn submission(self) -> Result<Wrapper, OpError> {
+        Ok(Wrapper::Splice(self))
+    }
+}
+
+#[cfg(feature = "splice")]
+impl OpCode<Wrapper> for Tee {
+    #[inline]
+    fn submission(self) -> Result<Wrapper, OpError> {
+        Ok(Wrapper::Tee(self))
+    }
+}
+
+#[cfg(feature = "timeout")]
+impl OpCode<Wrapper> for Timeout {
+    #[inline]
+    fn submission(self) -> Result<Wrapper, OpError> {
+        Ok(Wrapper::Timeout(self))
+    }
+}
+
+#[cfg(feature = "timeout")]
+impl OpCode<Wrapper> for LinkTimeout {
+    #[inline]
+    fn submission(self) -> Result<Wrapper, OpError> {
+        Ok(Wrapper::LinkTimeout(self))
+    }
+}
+
+#[cfg(feature = "bind_listen")]
+impl OpCode<Wrapper> for Bind {
+    #[inline]
+    fn submission(self) -> Result<Wrapper, OpError> {
+        Ok(Wrapper::Bind(self))
+    }
+}
+
+#[cfg(feature = "bind_listen")]
+impl OpCode<Wrapper> for Listen {
+    #[inline]
+    fn submission(self) -> Result<Wrapper, OpError> {
+        Ok(Wrapper::Listen(self))
+    }
+}
+
+#[cfg(feature = "sockopt")]
+impl OpCode<Wrapper> for SetSockOpt {
+    #[inline]
+    fn submission(self) -> Result<Wrapper, OpError> {
+        Ok(Wrapper::SetSockOpt(self))
+    }
+}
+
+#[cfg(feature = "sockopt")]
+impl OpCode<Wrapper> for GetSockOpt {
+    #[inline]
+    fn submission(self) -> Result<Wrapper, OpError> {
+        Ok(Wrapper::GetSockOpt(self))
+    }
+}
+
+#[cfg(feature = "sockopt")]
+impl OpCode<Wrapper> for QueuedBytes {
+    #[inline]
+    fn submission(self) -> Result<Wrapper, OpError> {
+        Ok(Wrapper::QueuedBytes(self))
+    }
+}
+
+#[cfg(feature = "connect")]
 impl OpCode<Wrapper> for Connect {
     #[inline]
     fn submission(self) -> Result<Wrapper, OpError> {
         Ok(Wrapper::Connect(self))
     }
-    fn completion(&mut self, _: Pin<&mut Wrapper>) -> Result<(), OpError> {
-        todo!()
-    }
 }
 
 impl Wrapper {
     /// Unwrap Socket type
+    #[cfg(feature = "socket")]
     #[inline]
     pub fn unwrap_socket(&self) -> &Socket {
         match self {
             Self::Socket(ref s) => s,
+            #[allow(unreachable_patterns)]
             _ => panic!("Invalid Unwrap - Not a Socket"),
         }
     }
     /// Unwrap Connect type
+    #[cfg(feature = "connect")]
     #[inline]
     pub fn unwrap_connect(&self) -> &Connect {
         match self {
             Self::Connect(ref c) => c,
+            #[allow(unreachable_patterns)]
             _ => panic!("Invalid Unwrap - Not a Connect"),
         }
     }
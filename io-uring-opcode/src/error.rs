@@ -4,13 +4,184 @@ use core::fmt;
 use core::fmt::Display;
 
 /// Harmonized error for OpCode impls creating submission() and completion()
-#[derive(Clone, Debug)]
-pub enum OpError {}
+#[derive(Clone, Debug, PartialEq)]
+pub enum OpError {
+    /// Completion of the named OpCode resulted in the errno returned by the kernel.
+    Completion(&'static str, Errno),
+    /// Must target valid fixed fd, Usually between 0 .. u32_MAX-2
+    InvalidTarget(u32),
+    /// Invalid combination of parameters rejected before submission
+    InvalidParameter(&'static str),
+}
+
+impl OpError {
+    /// Map the negative completion result of the named OpCode into the errno
+    #[inline]
+    pub fn from_cqe_result(op: &'static str, res: i32) -> Self {
+        Self::Completion(op, Errno::from_raw(-res))
+    }
+    /// The errno if the error was returned by the kernel upon completion
+    #[inline]
+    pub fn errno(&self) -> Option<Errno> {
+        match self {
+            Self::Completion(_, errno) => Some(*errno),
+            _ => None,
+        }
+    }
+}
 
 impl Display for OpError {
-    fn fmt(&self, _: &mut fmt::Formatter<'_>) -> fmt::Result {
-        todo!()
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Completion(op, errno) => write!(f, "{} completion: {}", op, errno),
+            Self::InvalidTarget(slot) => write!(f, "Invalid fixed fd target slot {}", slot),
+            Self::InvalidParameter(s) => write!(f, "Invalid parameter: {}", s),
+        }
     }
 }
 
 impl std::error::Error for OpError {}
+
+macro_rules! errno_map {
+    ($($(#[$doc:meta])* $variant:ident => $libc:ident,)*) => {
+        /// Named errno returned by the kernel in the completion result.
+        #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+        pub enum Errno {
+            $($(#[$doc])* $variant,)*
+            /// Any other errno not mapped above
+            Other(i32),
+        }
+
+        impl Errno {
+            /// Map the positive raw errno, e.g. libc::ECONNREFUSED
+            #[inline]
+            pub fn from_raw(errno: i32) -> Self {
+                match errno {
+                    $(libc::$libc => Self::$variant,)*
+                    other => Self::Other(other),
+                }
+            }
+            /// The positive raw errno
+            #[inline]
+            pub fn raw(&self) -> i32 {
+                match self {
+                    $(Self::$variant => libc::$libc,)*
+                    Self::Other(other) => *other,
+                }
+            }
+        }
+
+        impl Display for Errno {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                match self {
+                    $(Self::$variant => write!(f, stringify!($libc)),)*
+                    Self::Other(other) => write!(f, "errno {}", other),
+                }
+            }
+        }
+    };
+}
+
+errno_map! {
+    /// Operation not permitted
+    NotPermitted => EPERM,
+    /// No such file or directory
+    NoEntry => ENOENT,
+    /// Interrupted
+    Interrupted => EINTR,
+    /// I/O error
+    Io => EIO,
+    /// Bad filehandle, e.g. invalid fixed fd slot
+    BadFd => EBADF,
+    /// Resource temporarily unavailable, try again
+    Again => EAGAIN,
+    /// Out of memory
+    NoMemory => ENOMEM,
+    /// Permission denied
+    AccessDenied => EACCES,
+    /// Bad address
+    Fault => EFAULT,
+    /// Already exists
+    Exists => EEXIST,
+    /// Not a directory
+    NotDirectory => ENOTDIR,
+    /// Is a directory
+    IsDirectory => EISDIR,
+    /// Invalid argument, typically also returned for unsupported OpCodes
+    Invalid => EINVAL,
+    /// Too many open files in the system
+    SystemFileLimit => ENFILE,
+    /// Too many open files in the process
+    ProcessFileLimit => EMFILE,
+    /// No space left on device
+    NoSpace => ENOSPC,
+    /// Broken pipe
+    BrokenPipe => EPIPE,
+    /// Function not implemented
+    NotImplemented => ENOSYS,
+    /// Timer expired, e.g. Timeout completion
+    Time => ETIME,
+    /// Operation not supported
+    NotSupported => EOPNOTSUPP,
+    /// Address already in use
+    AddrInUse => EADDRINUSE,
+    /// Cannot assign requested address
+    AddrNotAvailable => EADDRNOTAVAIL,
+    /// Network is unreachable
+    NetUnreachable => ENETUNREACH,
+    /// Connection aborted
+    ConnAborted => ECONNABORTED,
+    /// Connection reset by peer
+    ConnReset => ECONNRESET,
+    /// No buffer space available, e.g. provided buffers ran out
+    NoBufs => ENOBUFS,
+    /// Socket is already connected
+    IsConnected => EISCONN,
+    /// Socket is not connected
+    NotConnected => ENOTCONN,
+    /// Connection timed out
+    TimedOut => ETIMEDOUT,
+    /// Connection refused
+    ConnRefused => ECONNREFUSED,
+    /// Host is unreachable
+    HostUnreachable => EHOSTUNREACH,
+    /// Operation already in progress
+    Already => EALREADY,
+    /// Operation now in progress
+    InProgress => EINPROGRESS,
+    /// Operation canceled, e.g. AsyncCancel or severed link
+    Canceled => ECANCELED,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn errno_from_cqe_result() {
+        let e = OpError::from_cqe_result("Connect", -libc::ECONNREFUSED);
+        assert_eq!(e, OpError::Completion("Connect", Errno::ConnRefused));
+        assert_eq!(e.errno(), Some(Errno::ConnRefused));
+    }
+    #[test]
+    fn errno_raw_roundtrip() {
+        for raw in [
+            libc::ECANCELED,
+            libc::ETIME,
+            libc::ENOBUFS,
+            libc::EAGAIN,
+            4095,
+        ] {
+            assert_eq!(Errno::from_raw(raw).raw(), raw);
+        }
+        assert_eq!(Errno::from_raw(4095), Errno::Other(4095));
+    }
+    #[test]
+    fn errno_display() {
+        assert_eq!(Errno::Canceled.to_string(), "ECANCELED");
+        assert_eq!(
+            OpError::from_cqe_result("Socket", -libc::EINVAL).to_string(),
+            "Socket completion: EINVAL"
+        );
+    }
+}
@@ -7,6 +7,11 @@ mod accept_multi;
 #[cfg(feature = "accept_multi")]
 pub use accept_multi::OpExtAcceptMulti;
 
+#[cfg(feature = "bind_listen")]
+mod bind_listen;
+#[cfg(feature = "bind_listen")]
+pub use bind_listen::OpExtBindListen;
+
 #[cfg(feature = "epoll")]
 mod epoll_ctl;
 #[cfg(feature = "epoll")]
@@ -17,12 +22,46 @@ mod connect;
 #[cfg(feature = "connect")]
 pub use connect::OpExtConnect;
 
+#[cfg(feature = "fs")]
+mod fs;
+#[cfg(feature = "fs")]
+pub use fs::OpExtFs;
+
+#[cfg(feature = "path")]
+mod path;
+#[cfg(feature = "path")]
+pub use path::OpExtPath;
+
+#[cfg(feature = "rw")]
+mod rw;
+#[cfg(feature = "rw")]
+pub use rw::OpExtRw;
+
+#[cfg(feature = "send")]
+mod send;
+#[cfg(feature = "send")]
+pub use send::OpExtSend;
+
 #[cfg(feature = "socket")]
 mod socket;
 #[cfg(feature = "socket")]
 pub use socket::OpExtSocket;
 
-use core::pin::Pin;
+#[cfg(feature = "sockopt")]
+mod sockopt;
+#[cfg(feature = "sockopt")]
+pub use sockopt::OpExtSockOpt;
+
+#[cfg(feature = "splice")]
+mod splice;
+#[cfg(feature = "splice")]
+pub use splice::OpExtSplice;
+
+#[cfg(feature = "timeout")]
+mod timeout;
+#[cfg(feature = "timeout")]
+pub use timeout::OpExtTimeout;
+
 use io_uring_owner::Owner;
 
 use crate::OpError;
@@ -31,12 +70,33 @@ use crate::OpError;
 pub trait OpCompletion {
     /// It is recommended that you use a harmonized error type but is not mandatory.
     type Error;
+    /// Typed result decoded from the completion queue entry, e.g. the resulting filehandle.
+    type Output;
     /// Provide the squeue entry
     fn entry(&self) -> io_uring::squeue::Entry;
+    /// The io_uring OpCode of the entry, e.g. io_uring::opcode::Connect::CODE
+    fn opcode(&self) -> u8;
     /// Get the current Owner
     fn owner(&self) -> Owner;
     /// Force set the owner to Kernel
     fn force_owner_kernel(&mut self) -> bool;
+    /// The fixed filehandle targeted if any, used to match e.g. cancellations
+    fn fixed_fd(&self) -> Option<u32> {
+        None
+    }
+    /// Indices of the bearer managed buffers the record refers to if any. The bearer
+    /// returns these for re-use upon the final completion.
+    fn buf_idxs(&self) -> &[usize] {
+        &[]
+    }
+    /// Whether the submission may post more than one completion (IORING_CQE_F_MORE)
+    /// e.g. AcceptMulti, the bearer retains the record until the final completion.
+    fn is_multishot(&self) -> bool {
+        false
+    }
+    /// io-uring-bearer will call this upon completion with the completion queue entry
+    /// referring to this pending record in order to decode it into the typed result.
+    fn completion(&mut self, cqe: &io_uring::cqueue::Entry) -> Result<Self::Output, Self::Error>;
 }
 
 /// The contracting type between io-uring-bearer and all the opcodes it can carry.
@@ -46,6 +106,4 @@ pub trait OpCode<C: OpCompletion> {
     // Turn the abstract OpCoe into Submission that will be pending completion.
     // io-uring-bearer will call this in order to convert the higher level type into actual submission.
     fn submission(self) -> Result<C, OpError>;
-    /// io-uring-bearer will call this upno completion
-    fn completion(&mut self, _: Pin<&mut C>) -> Result<(), OpError>;
 }
@@ -4,4 +4,6 @@
 pub trait OpExtAcceptMulti {
     /// Underlying Fixed Fd ref
     fn fixed_fd(&self) -> u32;
+    /// Whether the accepted filehandles are installed into kernel auto-assigned fixed slots
+    fn is_accept_fixed(&self) -> bool;
 }
@@ -0,0 +1,17 @@
+//! Bind / Listen extension trait
+
+use ysockaddr::YSockAddrC;
+
+/// Bind / Listen Expansion trait
+pub trait OpExtBindListen {
+    /// Underlying Fixed Fd ref
+    fn fixed_fd(&self) -> u32;
+    /// Address bound into by Bind
+    fn ysaddr(&self) -> Option<&YSockAddrC> {
+        None
+    }
+    /// Backlog of Listen
+    fn backlog(&self) -> Option<i32> {
+        None
+    }
+}
@@ -0,0 +1,13 @@
+//! Filesystem extension trait
+
+use crate::types::{FdRef, TargetFdType};
+
+/// Filesystem Expansion trait
+pub trait OpExtFs {
+    /// Underlying filehandle operated on, None for the path based e.g. OpenAt
+    fn fd(&self) -> Option<FdRef>;
+    /// Targeted Fd Type and slot if the op results in a new filehandle
+    fn target_fd(&self) -> Option<TargetFdType> {
+        None
+    }
+}
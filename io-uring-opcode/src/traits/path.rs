@@ -0,0 +1,16 @@
+//! Path extension trait
+
+use std::ffi::CStr;
+use std::os::fd::RawFd;
+
+/// Path Expansion trait
+pub trait OpExtPath {
+    /// Directory the path is resolved against e.g. libc::AT_FDCWD
+    fn dirfd(&self) -> RawFd;
+    /// Path operated on, e.g. the old path of RenameAt
+    fn path(&self) -> &CStr;
+    /// Second path resolved against its own directory if any, e.g. the new path of RenameAt
+    fn new_path(&self) -> Option<(RawFd, &CStr)> {
+        None
+    }
+}
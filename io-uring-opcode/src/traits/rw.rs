@@ -0,0 +1,26 @@
+//! Read / Write extension trait
+
+use crate::types::FdRef;
+
+/// Read / Write Expansion trait
+pub trait OpExtRw {
+    /// Underlying filehandle read from or written to
+    fn fd(&self) -> FdRef;
+    /// File offset, u64::MAX (-1) for the current file position
+    fn offset(&self) -> u64;
+    /// Total length of the buffers
+    fn len(&self) -> usize;
+    /// Whether the buffers are empty
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    /// RWF_* flags, e.g. libc::RWF_NOWAIT
+    fn rw_flags(&self) -> i32;
+    /// Whether the kernel writes into the file from the buffers
+    fn is_write(&self) -> bool;
+    /// Bearer managed buffer and its index in the kernel fixed buffers table the
+    /// ReadFixed / WriteFixed refers to, None for the others
+    fn fixed_buf(&self) -> Option<(usize, u16)> {
+        None
+    }
+}
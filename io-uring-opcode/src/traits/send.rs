@@ -0,0 +1,19 @@
+//! Send extension trait
+
+use ysockaddr::YSockAddrC;
+
+/// Send Expansion trait
+pub trait OpExtSend {
+    /// Underlying Fixed Fd ref
+    fn fixed_fd(&self) -> u32;
+    /// Total length of the data to be sent
+    fn len(&self) -> usize;
+    /// Whether there is no data to be sent
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    /// Destination when sending from an unconnected socket
+    fn dest(&self) -> Option<&YSockAddrC>;
+    /// MSG_* flags given to send(2) / sendmsg(2)
+    fn msg_flags(&self) -> i32;
+}
@@ -0,0 +1,11 @@
+//! Socket option extension trait
+
+/// Socket option Expansion trait
+pub trait OpExtSockOpt {
+    /// Underlying Fixed Fd ref
+    fn fixed_fd(&self) -> u32;
+    /// The (level, optname) operated on, None for the queue size queries
+    fn level_optname(&self) -> Option<(i32, i32)> {
+        None
+    }
+}
@@ -0,0 +1,23 @@
+//! Splice / Tee extension trait
+
+use crate::types::FdRef;
+
+/// Splice / Tee Expansion trait
+pub trait OpExtSplice {
+    /// Underlying filehandle moved from
+    fn fd_in(&self) -> FdRef;
+    /// Offset moved from, -1 for pipes and the current file position
+    fn off_in(&self) -> i64;
+    /// Underlying filehandle moved into
+    fn fd_out(&self) -> FdRef;
+    /// Offset moved into, -1 for pipes and the current file position
+    fn off_out(&self) -> i64;
+    /// Maximum count of bytes moved
+    fn len(&self) -> u32;
+    /// Whether no bytes are to be moved
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    /// SPLICE_F_* flags e.g. libc::SPLICE_F_MOVE
+    fn splice_flags(&self) -> u32;
+}
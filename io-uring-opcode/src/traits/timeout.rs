@@ -0,0 +1,11 @@
+//! Timeout extension trait
+
+use core::time::Duration;
+
+/// Timeout Expansion trait
+pub trait OpExtTimeout {
+    /// Underlying timeout duration, relative or since the clock epoch when absolute
+    fn duration(&self) -> Duration;
+    /// Whether this is a LinkTimeout which must follow a linked submission
+    fn is_linked(&self) -> bool;
+}
@@ -1,6 +1,9 @@
 //! Associated related types
 
-#[cfg(feature = "socket")]
+pub(crate) mod fd;
+pub use fd::{CompletedFd, FdRef};
+
+#[cfg(any(feature = "fs", feature = "socket"))]
 pub(crate) mod socket;
-#[cfg(feature = "socket")]
+#[cfg(any(feature = "fs", feature = "socket"))]
 pub use socket::TargetFdType;
@@ -0,0 +1,54 @@
+//! Filehandle related types
+
+use std::os::fd::RawFd;
+
+/// Filehandle resulting from a completed submission, e.g. Socket or Accept.
+#[derive(Clone, Debug, PartialEq)]
+pub enum CompletedFd {
+    /// Regular filehandle which is not considered "fixed" in io_uring
+    Regular(RawFd),
+    /// io_uring associated Fixed Fd slot either automatically assigned or manually pinned
+    Fixed(u32),
+}
+
+/// Filehandle referred by a submission, e.g. the file Read from.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum FdRef {
+    /// Regular filehandle which is not considered "fixed" in io_uring
+    Regular(RawFd),
+    /// io_uring associated Fixed Fd slot
+    Fixed(u32),
+}
+
+impl FdRef {
+    /// The fixed filehandle slot if any
+    #[inline]
+    pub fn fixed(&self) -> Option<u32> {
+        match self {
+            Self::Regular(_) => None,
+            Self::Fixed(fixed) => Some(*fixed),
+        }
+    }
+}
+
+/// Build the io_uring OpCode with the FdRef turned into either io_uring::types::Fd
+/// or io_uring::types::Fixed given the OpCode builders take either.
+///
+/// ```ignore
+/// with_fd_ref!(self.fd, |fd| io_uring::opcode::Fsync::new(fd).build())
+/// ```
+#[macro_export]
+macro_rules! with_fd_ref {
+    ($fd_ref:expr, |$fd:ident| $build:expr) => {
+        match $fd_ref {
+            $crate::types::FdRef::Regular(raw_fd) => {
+                let $fd = io_uring::types::Fd(raw_fd);
+                $build
+            }
+            $crate::types::FdRef::Fixed(fixed) => {
+                let $fd = io_uring::types::Fixed(fixed);
+                $build
+            }
+        }
+    };
+}
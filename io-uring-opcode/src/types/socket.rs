@@ -1,4 +1,4 @@
-//! Socket required types
+//! Socket and other filehandle creating ops required types
 
 /// What kind of filehandle we expect to be returned from kernel as result                                   
 #[derive(Clone, Debug, PartialEq)]
@@ -14,3 +14,17 @@ pub enum TargetFdType {
     /// Manually pinned io_uring associated Fixed Fd                                                         
     FixedManual(u32),
 }
+
+impl TargetFdType {
+    /// The io_uring destination slot, None for the regular filehandle.
+    /// Errors with the slot if it cannot be targeted manually.
+    pub fn destination_slot(&self) -> Result<Option<io_uring::types::DestinationSlot>, u32> {
+        match self {
+            Self::Regular => Ok(None),
+            Self::FixedAuto => Ok(Some(io_uring::types::DestinationSlot::auto_target())),
+            Self::FixedManual(s) => io_uring::types::DestinationSlot::try_from_slot_target(*s)
+                .map(Some)
+                .map_err(|_| *s),
+        }
+    }
+}
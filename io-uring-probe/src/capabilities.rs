@@ -0,0 +1,241 @@
+//! Kernel io_uring capabilities report
+
+use core::fmt;
+use core::fmt::Display;
+
+use io_uring::{opcode, IoUring, Probe};
+
+use crate::ProbeError;
+
+/// Buffer group id used for the trial buffer ring registration
+const PROBE_BGID: u16 = u16::MAX;
+/// Entries in the trial buffer ring
+const PROBE_RING_ENTRIES: u16 = 1;
+
+/// Single capability which may or may not be supported by the running kernel
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Capability {
+    /// Given OpCode e.g. io_uring::opcode::Socket::CODE
+    OpCode(u8),
+    /// IORING_SETUP_SQPOLL
+    SqPoll,
+    /// IORING_SETUP_SINGLE_ISSUER
+    SingleIssuer,
+    /// IORING_SETUP_DEFER_TASKRUN
+    DeferTaskrun,
+    /// IORING_REGISTER_PBUF_RING
+    BufRing,
+    /// IOU_PBUF_RING_INC incrementally consumed buffer rings
+    BufRingInc,
+    /// Futex wait / wake OpCodes
+    Futex,
+    /// WaitId OpCode
+    WaitId,
+}
+
+impl Display for Capability {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::OpCode(code) => write!(f, "OpCode {}", code),
+            Self::SqPoll => write!(f, "SQPOLL"),
+            Self::SingleIssuer => write!(f, "SINGLE_ISSUER"),
+            Self::DeferTaskrun => write!(f, "DEFER_TASKRUN"),
+            Self::BufRing => write!(f, "Buffer Ring"),
+            Self::BufRingInc => write!(f, "Incremental Buffer Ring"),
+            Self::Futex => write!(f, "Futex"),
+            Self::WaitId => write!(f, "WaitId"),
+        }
+    }
+}
+
+/// Report of the io_uring capabilities supported by the running kernel
+#[derive(Clone, Debug, PartialEq)]
+pub struct UringCapabilities {
+    opcodes: [u64; 4],
+    sqpoll: bool,
+    single_issuer: bool,
+    defer_taskrun: bool,
+    buf_ring: bool,
+    buf_ring_inc: bool,
+}
+
+impl UringCapabilities {
+    /// Probe the running kernel through a temporary IoUring instance, trialling also the
+    /// setup flags and the buffer rings through further temporary instances.
+    pub fn probe() -> Result<Self, ProbeError> {
+        let iou: IoUring = IoUring::new(2).map_err(|e| ProbeError::IoUringCreate(e.to_string()))?;
+        Self::probe_with_io_uring(&iou)
+    }
+    /// Probe the running kernel through an existing IoUring instance as per [`Self::probe`].
+    /// The buffer rings are trialled by registering and unregistering one on the given
+    /// instance, see [`Self::probe_opcodes`] for probing without side effects.
+    pub fn probe_with_io_uring(iou: &IoUring) -> Result<Self, ProbeError> {
+        let mut caps = Self::probe_opcodes(iou)?;
+
+        caps.buf_ring = probe_buf_ring(iou, 0);
+        caps.buf_ring_inc =
+            caps.buf_ring && probe_buf_ring(iou, io_uring::types::IOU_PBUF_RING_INC as u16);
+        caps.sqpoll = probe_setup(|b| b.setup_sqpoll(1000))
+            .map(|iou| iou.params().is_setup_sqpoll())
+            .unwrap_or(false);
+        caps.single_issuer = probe_setup(|b| b.setup_single_issuer())
+            .map(|iou| iou.params().is_setup_single_issuer())
+            .unwrap_or(false);
+        caps.defer_taskrun =
+            probe_setup(|b| b.setup_single_issuer().setup_defer_taskrun()).is_some();
+        Ok(caps)
+    }
+    /// Probe only the supported OpCodes through an existing IoUring instance, the setup
+    /// flags and the buffer rings are not trialled and reported as not supported.
+    pub fn probe_opcodes(iou: &IoUring) -> Result<Self, ProbeError> {
+        let mut probe = Probe::new();
+        iou.submitter()
+            .register_probe(&mut probe)
+            .map_err(|e| ProbeError::RegisterProbe(e.to_string()))?;
+
+        let mut opcodes = [0u64; 4];
+        for code in 0..=u8::MAX {
+            if probe.is_supported(code) {
+                opcodes[(code >> 6) as usize] |= 1 << (code & 63);
+            }
+        }
+        Ok(Self {
+            opcodes,
+            sqpoll: false,
+            single_issuer: false,
+            defer_taskrun: false,
+            buf_ring: false,
+            buf_ring_inc: false,
+        })
+    }
+    /// Is the given OpCode supported e.g. io_uring::opcode::Socket::CODE
+    #[inline]
+    pub fn is_supported(&self, opcode: u8) -> bool {
+        self.opcodes[(opcode >> 6) as usize] & (1 << (opcode & 63)) != 0
+    }
+    /// All the supported OpCodes
+    pub fn supported_opcodes(&self) -> impl Iterator<Item = u8> + '_ {
+        (0..=u8::MAX).filter(|code| self.is_supported(*code))
+    }
+    /// Is the given capability supported
+    pub fn supports(&self, capability: &Capability) -> bool {
+        match capability {
+            Capability::OpCode(code) => self.is_supported(*code),
+            Capability::SqPoll => self.sqpoll,
+            Capability::SingleIssuer => self.single_issuer,
+            Capability::DeferTaskrun => self.defer_taskrun,
+            Capability::BufRing => self.buf_ring,
+            Capability::BufRingInc => self.buf_ring_inc,
+            Capability::Futex => self.is_futex(),
+            Capability::WaitId => self.is_supported(opcode::WaitId::CODE),
+        }
+    }
+    /// Error with NotSupported if the given capability is not supported
+    #[inline]
+    pub fn require(&self, capability: Capability) -> Result<(), ProbeError> {
+        match self.supports(&capability) {
+            true => Ok(()),
+            false => Err(ProbeError::NotSupported(capability)),
+        }
+    }
+    /// Is IORING_SETUP_SQPOLL supported
+    #[inline]
+    pub fn is_sqpoll(&self) -> bool {
+        self.sqpoll
+    }
+    /// Is IORING_SETUP_SINGLE_ISSUER supported
+    #[inline]
+    pub fn is_single_issuer(&self) -> bool {
+        self.single_issuer
+    }
+    /// Is IORING_SETUP_DEFER_TASKRUN supported
+    #[inline]
+    pub fn is_defer_taskrun(&self) -> bool {
+        self.defer_taskrun
+    }
+    /// Are the provided buffer rings supported
+    #[inline]
+    pub fn is_buf_ring(&self) -> bool {
+        self.buf_ring
+    }
+    /// Are the incrementally consumed provided buffer rings supported
+    #[inline]
+    pub fn is_buf_ring_inc(&self) -> bool {
+        self.buf_ring_inc
+    }
+    /// Are the Futex wait / wake OpCodes supported
+    #[inline]
+    pub fn is_futex(&self) -> bool {
+        self.is_supported(opcode::FutexWait::CODE) && self.is_supported(opcode::FutexWake::CODE)
+    }
+}
+
+/// Trial the IoUring setup flags through a temporary instance
+fn probe_setup<F>(setup: F) -> Option<IoUring>
+where
+    F: FnOnce(&mut io_uring::Builder) -> &mut io_uring::Builder,
+{
+    let mut builder = IoUring::builder();
+    setup(&mut builder);
+    builder.build(2).ok()
+}
+
+/// Trial register and unregister a minimal buffer ring with the given flags
+fn probe_buf_ring(iou: &IoUring, flags: u16) -> bool {
+    let page_size = match unsafe { libc::sysconf(libc::_SC_PAGESIZE) } {
+        s if s > 0 => s as usize,
+        _ => 4096,
+    };
+    let layout = match std::alloc::Layout::from_size_align(page_size, page_size) {
+        Ok(l) => l,
+        Err(_) => return false,
+    };
+    // SAFETY: Layout is non-zero sized
+    let ring = unsafe { std::alloc::alloc_zeroed(layout) };
+    if ring.is_null() {
+        return false;
+    }
+    let submitter = iou.submitter();
+    // SAFETY: The ring memory outlives the registration as it is unregistered before dealloc
+    let registered = unsafe {
+        submitter.register_buf_ring_with_flags(ring as u64, PROBE_RING_ENTRIES, PROBE_BGID, flags)
+    };
+    let supported = match registered {
+        Ok(()) => {
+            let _ = submitter.unregister_buf_ring(PROBE_BGID);
+            true
+        }
+        Err(e) => e.raw_os_error() == Some(libc::EEXIST),
+    };
+    // SAFETY: Allocated above with the same layout
+    unsafe { std::alloc::dealloc(ring, layout) };
+    supported
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn probe_nop_supported() {
+        let caps = UringCapabilities::probe().expect("probe");
+        assert!(caps.is_supported(opcode::Nop::CODE));
+        assert!(caps.supported_opcodes().any(|c| c == opcode::Nop::CODE));
+        assert!(caps.require(Capability::OpCode(opcode::Nop::CODE)).is_ok());
+    }
+    #[test]
+    fn probe_opcodes_only() {
+        let iou: IoUring = IoUring::new(2).expect("io_uring");
+        let caps = UringCapabilities::probe_opcodes(&iou).expect("probe");
+        assert!(caps.is_supported(opcode::Nop::CODE));
+        assert!(!caps.supports(&Capability::SqPoll));
+        assert!(!caps.supports(&Capability::BufRing));
+    }
+    #[test]
+    fn inc_implies_buf_ring() {
+        let caps = UringCapabilities::probe().expect("probe");
+        if caps.is_buf_ring_inc() {
+            assert!(caps.is_buf_ring());
+        }
+    }
+}
@@ -0,0 +1,29 @@
+//! Probe Errors
+
+use core::fmt;
+use core::fmt::Display;
+
+use crate::Capability;
+
+/// Errors from probing the kernel
+#[derive(Debug)]
+pub enum ProbeError {
+    /// Error creating the IoUring instance used for probing
+    IoUringCreate(String),
+    /// Error registering the OpCode probe
+    RegisterProbe(String),
+    /// Required capability is not supported by the running kernel
+    NotSupported(Capability),
+}
+
+impl Display for ProbeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::IoUringCreate(s) => write!(f, "IoUring Create: {}", s),
+            Self::RegisterProbe(s) => write!(f, "Register Probe: {}", s),
+            Self::NotSupported(c) => write!(f, "{} is not supported in your Kernel", c),
+        }
+    }
+}
+
+impl std::error::Error for ProbeError {}
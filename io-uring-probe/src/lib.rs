@@ -1,17 +1,27 @@
-//! io-uring probe
+#![warn(
+    clippy::unwrap_used,
+    missing_docs,
+    rust_2018_idioms,
+    unused_lifetimes,
+    unused_qualifications
+)]
+#![doc = include_str!("../README.md")]
 
-	#[allow(clippy::bool_comparison)]
-	if epoll_probe.is_supported(EpollCtl::CODE) == false {
-	    return Err(EpollUringHandlerError::NotSupported);
-	}
+//***********************************************
+// Re-Exports
+//***********************************************
+pub use io_uring;
 
-	// SAFETY: FFI no-data in                                                                                                                                       
-	let epfd = unsafe { libc::epoll_create1(0) };
-        if epfd == -1 {
-            // SAFETY: ffi no-data                                                                                                                                      
-            let errno = unsafe { libc::__errno_location() };
-            return Err(EpollUringHandlerError::EpollCreate1(format!(
-                "errno: {:?}",
-                errno
-            )));
-        }
+//-----------------------------------------------
+// All Errors
+//-----------------------------------------------
+mod error;
+#[doc(inline)]
+pub use error::ProbeError;
+
+//-----------------------------------------------
+// Capabilities report
+//-----------------------------------------------
+mod capabilities;
+#[doc(inline)]
+pub use capabilities::{Capability, UringCapabilities};
@@ -9,7 +9,7 @@ use io_uring_bearer::UringBearer;
 use capacity::{Capacity, Setting};
 use io_uring_bearer::BearerCapacityKind;
 
-use io_uring_op_accept_multi::AcceptMulti;
+use io_uring_op_accept_multi::{AcceptMulti, CompletedFd};
 use io_uring_opcode::OpExtAcceptMulti;
 
 #[derive(Clone, Debug)]
@@ -52,7 +52,7 @@ fn main() {
         .unwrap();
 
     let _op_idx = bearer
-        .push_accept_multi(AcceptMulti::with_fixed_fds(0).unwrap())
+        .push_accept_multi(AcceptMulti::with_fixed_fds(0).unwrap(), None)
         .unwrap();
 
     bearer.submit().unwrap();
@@ -72,7 +72,7 @@ fn main() {
 
     loop {
         bearer
-            .completions(&mut user, |user, entry, rec| match rec {
+            .typed_completions(&mut user, |user, entry, rec, res| match rec {
                 Completion::AcceptMulti(c) => {
                     user.e += 1;
                     println!(
@@ -84,6 +84,9 @@ fn main() {
                     assert_eq!(io_uring::cqueue::more(entry.flags()), true);
                     // no error - kernel dependant allocating the fixed fileno
                     assert!(entry.result() > 0);
+                    let accepted = res.unwrap().unwrap();
+                    assert!(accepted.more);
+                    assert_eq!(accepted.fd, CompletedFd::Fixed(entry.result() as u32));
                 }
                 _ => panic!("Queue had something else than AcceptMulti?"),
             })
@@ -1,9 +1,8 @@
 //! AcceptMulti Record
 
-use core::pin::Pin;
-
 use crate::error::AcceptMultiError;
 
+use io_uring_opcode::types::CompletedFd;
 use io_uring_opcode::OpExtAcceptMulti;
 use io_uring_opcode::{OpCode, OpCompletion, OpError};
 use io_uring_owner::Owner;
@@ -38,13 +37,26 @@ impl AcceptMulti {
     }
 }
 
+/// Accepted filehandle out of one AcceptMulti completion
+#[derive(Clone, Debug, PartialEq)]
+pub struct Accepted {
+    /// The accepted filehandle, either fixed or regular as per the AcceptMulti record
+    pub fd: CompletedFd,
+    /// Whether the multishot AcceptMulti remains armed for more completions
+    pub more: bool,
+}
+
 impl OpCompletion for AcceptMulti {
     type Error = OpError;
+    type Output = Accepted;
     fn entry(&self) -> io_uring::squeue::Entry {
         io_uring::opcode::AcceptMulti::new(io_uring::types::Fixed(self.fixed_fd))
             .allocate_file_index(self.accept_fixed)
             .build()
     }
+    fn opcode(&self) -> u8 {
+        io_uring::opcode::AcceptMulti::CODE
+    }
     fn owner(&self) -> Owner {
         self.owner.clone()
     }
@@ -52,15 +64,34 @@ impl OpCompletion for AcceptMulti {
         self.owner = Owner::Kernel;
         true
     }
+    fn fixed_fd(&self) -> Option<u32> {
+        Some(self.fixed_fd)
+    }
+    fn is_multishot(&self) -> bool {
+        true
+    }
+    /// Kernel retains the record for as long as there may be more completions.
+    fn completion(&mut self, cqe: &io_uring::cqueue::Entry) -> Result<Accepted, OpError> {
+        let more = io_uring::cqueue::more(cqe.flags());
+        if !more {
+            self.owner = Owner::Returned;
+        }
+        let res = cqe.result();
+        if res < 0 {
+            return Err(OpError::from_cqe_result("AcceptMulti", res));
+        }
+        let fd = match self.accept_fixed {
+            true => CompletedFd::Fixed(res as u32),
+            false => CompletedFd::Regular(res),
+        };
+        Ok(Accepted { fd, more })
+    }
 }
 
 impl OpCode<AcceptMulti> for AcceptMulti {
     fn submission(self) -> Result<AcceptMulti, OpError> {
         Ok(self)
     }
-    fn completion(&mut self, _: Pin<&mut AcceptMulti>) -> Result<(), OpError> {
-        todo!()
-    }
 }
 
 impl OpExtAcceptMulti for AcceptMulti {
@@ -68,4 +99,8 @@ impl OpExtAcceptMulti for AcceptMulti {
     fn fixed_fd(&self) -> u32 {
         self.fixed_fd
     }
+    /// Whether accepted into fixed slots
+    fn is_accept_fixed(&self) -> bool {
+        self.accept_fixed
+    }
 }
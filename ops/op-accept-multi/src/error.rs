@@ -3,13 +3,21 @@
 use core::fmt;
 use core::fmt::Display;
 
+use io_uring_opcode::OpError;
+
 /// AcceptMulti Errors
 #[derive(Debug)]
 pub enum AcceptMultiError {}
 
 impl Display for AcceptMultiError {
     fn fmt(&self, _: &mut fmt::Formatter<'_>) -> fmt::Result {
-        todo!()
+        match *self {}
+    }
+}
+
+impl From<AcceptMultiError> for OpError {
+    fn from(e: AcceptMultiError) -> OpError {
+        match e {}
     }
 }
 
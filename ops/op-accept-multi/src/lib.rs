@@ -18,7 +18,13 @@ pub use error::*;
 // Record Types
 //-----------------------------------------------
 mod accept_multi;
-pub use accept_multi::AcceptMulti;
+pub use accept_multi::{AcceptMulti, Accepted};
+
+//-----------------------------------------------
+// Re-export associated types
+//-----------------------------------------------
+#[doc(inline)]
+pub use io_uring_opcode::types::CompletedFd;
 
 //-----------------------------------------------
 // Misc crate-wide private types
@@ -0,0 +1,146 @@
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+use std::net::{TcpListener, TcpStream};
+
+use ysockaddr::YSockAddrR;
+
+use io_uring_bearer::Completion;
+use io_uring_bearer::SubmissionFlags;
+use io_uring_bearer::UringBearer;
+
+use capacity::{Capacity, Setting};
+use io_uring_bearer::BearerCapacityKind;
+
+use io_uring_op_bind_listen::{Bind, Listen};
+use io_uring_op_socket::{CompletedFd, Socket};
+use io_uring_opcode::{OpCode, OpCompletion, OpError};
+use io_uring_owner::Owner;
+
+#[derive(Clone, Debug)]
+pub struct MyCapacity;
+
+impl Setting<BearerCapacityKind> for MyCapacity {
+    fn setting(&self, v: &BearerCapacityKind) -> usize {
+        match v {
+            BearerCapacityKind::CoreQueue => 4,
+            BearerCapacityKind::RegisteredFd => 1,
+            BearerCapacityKind::PendingCompletions => 4,
+            BearerCapacityKind::Buffers => 0,
+            BearerCapacityKind::Futexes => 0,
+        }
+    }
+}
+
+/// The OpCodes setting up the listener within the one bearer
+#[derive(Clone, Debug)]
+enum ListenerOp {
+    Socket(Socket),
+    Bind(Bind),
+    Listen(Listen),
+}
+
+impl OpCompletion for ListenerOp {
+    type Error = OpError;
+    type Output = Option<CompletedFd>;
+    fn entry(&self) -> io_uring::squeue::Entry {
+        match self {
+            Self::Socket(i) => i.entry(),
+            Self::Bind(i) => i.entry(),
+            Self::Listen(i) => i.entry(),
+        }
+    }
+    fn opcode(&self) -> u8 {
+        match self {
+            Self::Socket(i) => i.opcode(),
+            Self::Bind(i) => i.opcode(),
+            Self::Listen(i) => i.opcode(),
+        }
+    }
+    fn owner(&self) -> Owner {
+        match self {
+            Self::Socket(i) => i.owner(),
+            Self::Bind(i) => i.owner(),
+            Self::Listen(i) => i.owner(),
+        }
+    }
+    fn force_owner_kernel(&mut self) -> bool {
+        match self {
+            Self::Socket(i) => i.force_owner_kernel(),
+            Self::Bind(i) => i.force_owner_kernel(),
+            Self::Listen(i) => i.force_owner_kernel(),
+        }
+    }
+    fn completion(&mut self, cqe: &io_uring::cqueue::Entry) -> Result<Self::Output, OpError> {
+        match self {
+            Self::Socket(i) => i.completion(cqe).map(Some),
+            Self::Bind(i) => i.completion(cqe).map(|_| None),
+            Self::Listen(i) => i.completion(cqe).map(|_| None),
+        }
+    }
+}
+
+impl OpCode<ListenerOp> for Socket {
+    fn submission(self) -> Result<ListenerOp, OpError> {
+        Ok(ListenerOp::Socket(self))
+    }
+}
+
+impl OpCode<ListenerOp> for Bind {
+    fn submission(self) -> Result<ListenerOp, OpError> {
+        Ok(ListenerOp::Bind(self))
+    }
+}
+
+impl OpCode<ListenerOp> for Listen {
+    fn submission(self) -> Result<ListenerOp, OpError> {
+        Ok(ListenerOp::Listen(self))
+    }
+}
+
+fn main() {
+    // Find a free port for the listener
+    let port = TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap()
+        .port();
+    let addr = SocketAddr::new(IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1)), port);
+    let ysaddr = YSockAddrR::from_sockaddr(addr);
+
+    let my_cap = Capacity::<MyCapacity, BearerCapacityKind>::with_planned(MyCapacity {});
+    let mut bearer: UringBearer<ListenerOp> = UringBearer::with_capacity(my_cap).unwrap();
+    bearer.commit_registered_init().unwrap();
+
+    // Socket -> Bind -> Listen linked on the fixed slot 0 which never exists as a regular fd
+    let link = Some(SubmissionFlags::default().on_io_link());
+    let socket =
+        Socket::with_fixed_fd(Some(0), libc::AF_INET, libc::SOCK_STREAM, libc::IPPROTO_TCP)
+            .unwrap();
+    bearer.push_socket(socket, link).unwrap();
+    let bind = Bind::with_ysockaddr_c(0, ysaddr.as_c()).unwrap();
+    bearer.push_bind_listen(bind, link).unwrap();
+    bearer
+        .push_bind_listen(Listen::new(0, 16).unwrap(), None)
+        .unwrap();
+
+    bearer.submit_and_wait(3).unwrap();
+
+    let mut completed = 0;
+    bearer
+        .typed_completions(&mut completed, |completed, entry, rec, res| match rec {
+            Completion::Socket(_) | Completion::BindListen(_) => {
+                println!("Q<{:?}> = {:?}", entry, res);
+                res.unwrap().unwrap();
+                *completed += 1;
+            }
+            _ => panic!("Queue had something else than Socket / Bind / Listen?"),
+        })
+        .unwrap();
+    assert_eq!(completed, 3);
+
+    // Now check that something is listening
+    let stream = TcpStream::connect(addr).unwrap();
+    println!(
+        "Connected to the fixed listener on {}",
+        stream.peer_addr().unwrap()
+    );
+}
@@ -0,0 +1,78 @@
+//! Bind Record
+
+use crate::error::BindListenError;
+
+use io_uring_opcode::OpExtBindListen;
+use io_uring_opcode::{OpCode, OpCompletion, OpError};
+use io_uring_owner::Owner;
+
+use ysockaddr::YSockAddrC;
+
+/// Bind Record
+#[derive(Clone, Debug)]
+pub struct Bind {
+    /// Current owner of the record
+    owner: Owner,
+    /// Related filehandle
+    fixed_fd: u32,
+    ysaddr: YSockAddrC,
+}
+
+impl Bind {
+    /// Construct a new Bind of the fixed filehandle into the given address
+    pub fn with_ysockaddr_c(fixed_fd: u32, ysaddr: YSockAddrC) -> Result<Self, BindListenError> {
+        Ok(Bind {
+            owner: Owner::Created,
+            fixed_fd,
+            ysaddr,
+        })
+    }
+}
+
+impl OpCompletion for Bind {
+    type Error = OpError;
+    type Output = ();
+    fn entry(&self) -> io_uring::squeue::Entry {
+        let (saddr, slen) = self.ysaddr.as_c_sockaddr_len();
+
+        io_uring::opcode::Bind::new(io_uring::types::Fixed(self.fixed_fd), saddr, slen).build()
+    }
+    fn opcode(&self) -> u8 {
+        io_uring::opcode::Bind::CODE
+    }
+    fn owner(&self) -> Owner {
+        self.owner.clone()
+    }
+    fn force_owner_kernel(&mut self) -> bool {
+        self.owner = Owner::Kernel;
+        true
+    }
+    fn fixed_fd(&self) -> Option<u32> {
+        Some(self.fixed_fd)
+    }
+    /// Bound when the result is zero, otherwise the negative errno.
+    fn completion(&mut self, cqe: &io_uring::cqueue::Entry) -> Result<(), OpError> {
+        self.owner = Owner::Returned;
+        match cqe.result() {
+            res if res < 0 => Err(OpError::from_cqe_result("Bind", res)),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl OpCode<Bind> for Bind {
+    fn submission(self) -> Result<Bind, OpError> {
+        Ok(self)
+    }
+}
+
+impl OpExtBindListen for Bind {
+    /// Underlying Fixed Fd
+    fn fixed_fd(&self) -> u32 {
+        self.fixed_fd
+    }
+    /// Underlying YSockAddrC bound into
+    fn ysaddr(&self) -> Option<&YSockAddrC> {
+        Some(&self.ysaddr)
+    }
+}
@@ -0,0 +1,24 @@
+//! Bind and Listen op Errors
+
+use core::fmt;
+use core::fmt::Display;
+
+use io_uring_opcode::OpError;
+
+/// Bind and Listen Errors
+#[derive(Debug)]
+pub enum BindListenError {}
+
+impl Display for BindListenError {
+    fn fmt(&self, _: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {}
+    }
+}
+
+impl From<BindListenError> for OpError {
+    fn from(e: BindListenError) -> OpError {
+        match e {}
+    }
+}
+
+impl std::error::Error for BindListenError {}
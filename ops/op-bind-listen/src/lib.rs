@@ -0,0 +1,31 @@
+#![warn(
+    clippy::unwrap_used,
+    missing_docs,
+    rust_2018_idioms,
+    unused_lifetimes,
+    unused_qualifications
+)]
+#![doc = include_str!("../README.md")]
+
+//-----------------------------------------------
+// All Errors
+//-----------------------------------------------
+mod error;
+#[doc(inline)]
+pub use error::*;
+
+//-----------------------------------------------
+// Bind Record Types
+//-----------------------------------------------
+mod bind;
+pub use bind::Bind;
+
+//-----------------------------------------------
+// Listen Record Types
+//-----------------------------------------------
+mod listen;
+pub use listen::Listen;
+
+//-----------------------------------------------
+// Misc crate-wide private types
+//-----------------------------------------------
@@ -0,0 +1,75 @@
+//! Listen Record
+
+use crate::error::BindListenError;
+
+use io_uring_opcode::OpExtBindListen;
+use io_uring_opcode::{OpCode, OpCompletion, OpError};
+use io_uring_owner::Owner;
+
+/// Listen Record
+#[derive(Clone, Debug)]
+pub struct Listen {
+    /// Current owner of the record
+    owner: Owner,
+    /// Related filehandle
+    fixed_fd: u32,
+    /// Maximum length of the pending connections queue
+    backlog: i32,
+}
+
+impl Listen {
+    /// Construct a new Listen on the bound fixed filehandle with the given backlog
+    pub fn new(fixed_fd: u32, backlog: i32) -> Result<Self, BindListenError> {
+        Ok(Listen {
+            owner: Owner::Created,
+            fixed_fd,
+            backlog,
+        })
+    }
+}
+
+impl OpCompletion for Listen {
+    type Error = OpError;
+    type Output = ();
+    fn entry(&self) -> io_uring::squeue::Entry {
+        io_uring::opcode::Listen::new(io_uring::types::Fixed(self.fixed_fd), self.backlog).build()
+    }
+    fn opcode(&self) -> u8 {
+        io_uring::opcode::Listen::CODE
+    }
+    fn owner(&self) -> Owner {
+        self.owner.clone()
+    }
+    fn force_owner_kernel(&mut self) -> bool {
+        self.owner = Owner::Kernel;
+        true
+    }
+    fn fixed_fd(&self) -> Option<u32> {
+        Some(self.fixed_fd)
+    }
+    /// Listening when the result is zero, otherwise the negative errno.
+    fn completion(&mut self, cqe: &io_uring::cqueue::Entry) -> Result<(), OpError> {
+        self.owner = Owner::Returned;
+        match cqe.result() {
+            res if res < 0 => Err(OpError::from_cqe_result("Listen", res)),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl OpCode<Listen> for Listen {
+    fn submission(self) -> Result<Listen, OpError> {
+        Ok(self)
+    }
+}
+
+impl OpExtBindListen for Listen {
+    /// Underlying Fixed Fd
+    fn fixed_fd(&self) -> u32 {
+        self.fixed_fd
+    }
+    /// Backlog listened with
+    fn backlog(&self) -> Option<i32> {
+        Some(self.backlog)
+    }
+}
@@ -56,7 +56,7 @@ fn main() {
     ));
 
     let _op_idx = bearer
-        .push_connect(Connect::with_ysockaddr_c(0, ysaddr.as_c()).unwrap())
+        .push_connect(Connect::with_ysockaddr_c(0, ysaddr.as_c()).unwrap(), None)
         .unwrap();
 
     bearer.submit_and_wait(1).unwrap();
@@ -71,7 +71,7 @@ fn main() {
 
     loop {
         bearer
-            .completions(&mut user, |user, entry, rec| match rec {
+            .typed_completions(&mut user, |user, entry, rec, res| match rec {
                 Completion::Connect(c) => {
                     user.e += 1;
                     println!(
@@ -82,6 +82,7 @@ fn main() {
                     );
                     // no error
                     assert_eq!(entry.result(), 0);
+                    assert!(res.unwrap().is_ok());
                 }
                 _ => panic!("Queue had something else than Connect?"),
             })
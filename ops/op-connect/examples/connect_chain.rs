@@ -0,0 +1,118 @@
+use std::net::TcpListener;
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+
+use ysockaddr::YSockAddrR;
+
+use io_uring_bearer::completion::SubmissionRecordStatus;
+use io_uring_bearer::{ChainLink, LinkOutcome, UringBearer};
+
+use capacity::{Capacity, Setting};
+use io_uring_bearer::BearerCapacityKind;
+
+use io_uring_op_connect::Connect;
+
+#[derive(Clone, Debug)]
+pub struct MyCapacity;
+
+impl Setting<BearerCapacityKind> for MyCapacity {
+    fn setting(&self, v: &BearerCapacityKind) -> usize {
+        match v {
+            BearerCapacityKind::CoreQueue => 4,
+            BearerCapacityKind::RegisteredFd => 4,
+            BearerCapacityKind::PendingCompletions => 4,
+            BearerCapacityKind::Buffers => 0,
+            BearerCapacityKind::Futexes => 0,
+        }
+    }
+}
+
+fn port_of(listener: &TcpListener) -> u16 {
+    match listener.local_addr().unwrap() {
+        SocketAddr::V4(addr) => addr.port(),
+        SocketAddr::V6(_) => panic!("IPv4 Requested, IPv6 bound?"),
+    }
+}
+
+fn local(port: u16) -> YSockAddrR {
+    YSockAddrR::from_sockaddr(SocketAddr::new(
+        IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1)),
+        port,
+    ))
+}
+
+fn wait_chain(bearer: &mut UringBearer<Connect>, chain: usize) -> Vec<LinkOutcome> {
+    loop {
+        bearer.submit_and_wait(1).unwrap();
+        // SAFETY: Connect is not referred to by the kernel after its completion.
+        unsafe {
+            bearer
+                .handle_completions(&mut (), None, |_, entry, _| {
+                    println!("Link completed Q<{:?}>", entry);
+                    SubmissionRecordStatus::Forget
+                })
+                .unwrap();
+        }
+        if let Some(result) = bearer.chain_result(chain).unwrap() {
+            return result.outcomes().to_vec();
+        }
+    }
+}
+
+fn main() {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let listener_port = port_of(&listener);
+
+    // Port freed again so that nobody is listening on it
+    let refused_port = port_of(&TcpListener::bind("127.0.0.1:0").unwrap());
+
+    let my_cap = Capacity::<MyCapacity, BearerCapacityKind>::with_planned(MyCapacity {});
+    let mut bearer = UringBearer::with_capacity(my_cap).unwrap();
+
+    let socks: Vec<i32> = (0..4)
+        .map(|_| unsafe { libc::socket(libc::AF_INET, libc::SOCK_STREAM, libc::IPPROTO_TCP) })
+        .collect();
+    bearer
+        .io_uring()
+        .submitter()
+        .register_files(&socks)
+        .unwrap();
+
+    // Both links connect one after another
+    let listener_addr = local(listener_port);
+    let (chain, keys) = bearer
+        .push_chain([
+            ChainLink::connect(Connect::with_ysockaddr_c(0, listener_addr.as_c()).unwrap())
+                .unwrap(),
+            ChainLink::connect(Connect::with_ysockaddr_c(1, listener_addr.as_c()).unwrap())
+                .unwrap(),
+        ])
+        .unwrap();
+    println!("Chain {} pushed with keys {:?}", chain, keys);
+    let outcomes = wait_chain(&mut bearer, chain);
+    assert_eq!(
+        outcomes,
+        vec![LinkOutcome::Succeeded(0), LinkOutcome::Succeeded(0)]
+    );
+
+    // The refused link severs the chain canceling the link after it
+    let refused_addr = local(refused_port);
+    let (chain, _keys) = bearer
+        .push_chain([
+            ChainLink::connect(Connect::with_ysockaddr_c(2, refused_addr.as_c()).unwrap()).unwrap(),
+            ChainLink::connect(Connect::with_ysockaddr_c(3, listener_addr.as_c()).unwrap())
+                .unwrap(),
+        ])
+        .unwrap();
+    let outcomes = wait_chain(&mut bearer, chain);
+    assert_eq!(
+        outcomes,
+        vec![
+            LinkOutcome::Failed(-libc::ECONNREFUSED),
+            LinkOutcome::Canceled
+        ]
+    );
+    println!(
+        "Chain {} failed at the first link and the second was canceled",
+        chain
+    );
+}
@@ -1,7 +1,5 @@
 //! Connect Record
 
-use core::pin::Pin;
-
 use crate::error::ConnectError;
 
 use io_uring_opcode::OpExtConnect;
@@ -33,11 +31,15 @@ impl Connect {
 
 impl OpCompletion for Connect {
     type Error = OpError;
+    type Output = ();
     fn entry(&self) -> io_uring::squeue::Entry {
         let (saddr, slen) = self.ysaddr.as_c_sockaddr_len();
 
         io_uring::opcode::Connect::new(io_uring::types::Fixed(self.fixed_fd), saddr, slen).build()
     }
+    fn opcode(&self) -> u8 {
+        io_uring::opcode::Connect::CODE
+    }
     fn owner(&self) -> Owner {
         self.owner.clone()
     }
@@ -45,15 +47,23 @@ impl OpCompletion for Connect {
         self.owner = Owner::Kernel;
         true
     }
+    fn fixed_fd(&self) -> Option<u32> {
+        Some(self.fixed_fd)
+    }
+    /// Connected when the result is zero, otherwise the negative errno.
+    fn completion(&mut self, cqe: &io_uring::cqueue::Entry) -> Result<(), OpError> {
+        self.owner = Owner::Returned;
+        match cqe.result() {
+            res if res < 0 => Err(OpError::from_cqe_result("Connect", res)),
+            _ => Ok(()),
+        }
+    }
 }
 
 impl OpCode<Connect> for Connect {
     fn submission(self) -> Result<Connect, OpError> {
         Ok(self)
     }
-    fn completion(&mut self, _: Pin<&mut Connect>) -> Result<(), OpError> {
-        todo!()
-    }
 }
 
 impl OpExtConnect for Connect {
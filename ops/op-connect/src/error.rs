@@ -3,13 +3,21 @@
 use core::fmt;
 use core::fmt::Display;
 
+use io_uring_opcode::OpError;
+
 /// Connect Errors
 #[derive(Debug)]
 pub enum ConnectError {}
 
 impl Display for ConnectError {
     fn fmt(&self, _: &mut fmt::Formatter<'_>) -> fmt::Result {
-        todo!()
+        match *self {}
+    }
+}
+
+impl From<ConnectError> for OpError {
+    fn from(e: ConnectError) -> OpError {
+        match e {}
     }
 }
 
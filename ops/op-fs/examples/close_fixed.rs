@@ -0,0 +1,64 @@
+use io_uring_bearer::Completion;
+use io_uring_bearer::UringBearer;
+
+use capacity::{Capacity, Setting};
+use io_uring_bearer::BearerCapacityKind;
+
+use io_uring_op_fs::Close;
+
+use std::os::fd::AsRawFd;
+use std::os::unix::net::UnixStream;
+
+#[derive(Clone, Debug)]
+pub struct MyCapacity;
+
+impl Setting<BearerCapacityKind> for MyCapacity {
+    fn setting(&self, v: &BearerCapacityKind) -> usize {
+        match v {
+            BearerCapacityKind::CoreQueue => 2,
+            BearerCapacityKind::RegisteredFd => 1,
+            BearerCapacityKind::PendingCompletions => 2,
+            BearerCapacityKind::Buffers => 0,
+            BearerCapacityKind::Futexes => 0,
+        }
+    }
+}
+
+fn main() {
+    let my_cap = Capacity::<MyCapacity, BearerCapacityKind>::with_planned(MyCapacity {});
+    let mut bearer: UringBearer<Close> = UringBearer::with_capacity(my_cap).unwrap();
+
+    let (first, _first_peer) = UnixStream::pair().unwrap();
+    let (second, _second_peer) = UnixStream::pair().unwrap();
+
+    let slot = bearer.register_recv(first.as_raw_fd()).unwrap();
+    bearer.commit_registered_init().unwrap();
+    assert_eq!(bearer.fixed_fd_of(first.as_raw_fd()), Some(slot));
+
+    // The only slot is taken until closed.
+    assert!(bearer.register_recv(second.as_raw_fd()).is_err());
+
+    bearer.close_fixed(slot, None).unwrap();
+    bearer.submit_and_wait(1).unwrap();
+    bearer
+        .typed_completions(&mut (), |_, entry, rec, _| match rec {
+            Completion::CloseFixed(c) => {
+                println!("CloseFixed Q<{:?}> = {:?}", entry, c.result());
+                assert_eq!(c.result(), Some(0));
+            }
+            _ => panic!("Queue had something else than CloseFixed?"),
+        })
+        .unwrap();
+
+    // The slot is free again and the raw fd remains open with the caller.
+    assert_eq!(bearer.fixed_fd_of(first.as_raw_fd()), None);
+    let reused = bearer.register_recv(second.as_raw_fd()).unwrap();
+    assert_eq!(reused, slot);
+    bearer.commit_registered_sparse(reused).unwrap();
+    assert_eq!(bearer.fixed_fd_of(second.as_raw_fd()), Some(slot));
+    println!(
+        "Slot {} reused, first fd {} still open",
+        slot,
+        first.as_raw_fd()
+    );
+}
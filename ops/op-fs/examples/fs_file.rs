@@ -0,0 +1,88 @@
+use io_uring_bearer::Completion;
+use io_uring_bearer::UringBearer;
+
+use capacity::{Capacity, Setting};
+use io_uring_bearer::BearerCapacityKind;
+
+use io_uring_op_fs::{
+    Close, CompletedFd, Fallocate, FdRef, Fsync, Ftruncate, OpenAt, Statx, SyncFileRange,
+};
+use io_uring_opcode::{OpCode, OpCompletion, OpExtFs};
+
+#[derive(Clone, Debug)]
+pub struct MyCapacity;
+
+impl Setting<BearerCapacityKind> for MyCapacity {
+    fn setting(&self, v: &BearerCapacityKind) -> usize {
+        match v {
+            BearerCapacityKind::CoreQueue => 1,
+            BearerCapacityKind::RegisteredFd => 1,
+            BearerCapacityKind::PendingCompletions => 1,
+            BearerCapacityKind::Buffers => 0,
+            BearerCapacityKind::Futexes => 0,
+        }
+    }
+}
+
+/// Each bearer carries a single record type, run the given op to completion on its own.
+fn complete<C>(op: C) -> C::Output
+where
+    C: core::fmt::Debug + Clone + OpCompletion + OpCode<C> + OpExtFs,
+    C::Output: core::fmt::Debug,
+    C::Error: core::fmt::Debug,
+{
+    let my_cap = Capacity::<MyCapacity, BearerCapacityKind>::with_planned(MyCapacity {});
+    let mut bearer = UringBearer::with_capacity(my_cap).unwrap();
+    bearer.push_fs(op, None).unwrap();
+    bearer.submit_and_wait(1).unwrap();
+
+    let mut output = None;
+    bearer
+        .typed_completions(&mut output, |output, entry, rec, res| match rec {
+            Completion::Fs(_) => {
+                println!("Fs Q<{:?}> = {:?}", entry, res);
+                *output = res;
+            }
+            _ => panic!("Queue had something else than Fs?"),
+        })
+        .unwrap();
+    output.unwrap().unwrap()
+}
+
+fn main() {
+    let path = std::env::temp_dir().join(format!("io-uring-op-fs-{}", std::process::id()));
+
+    let fd = match complete(
+        OpenAt::with_regular_fd(
+            libc::AT_FDCWD,
+            &path,
+            libc::O_CREAT | libc::O_RDWR | libc::O_CLOEXEC,
+            0o600,
+        )
+        .unwrap(),
+    ) {
+        CompletedFd::Regular(fd) => fd,
+        other => panic!("Expected regular fd, got {:?}", other),
+    };
+
+    complete(Fallocate::new(FdRef::Regular(fd), 0, 8192));
+    complete(SyncFileRange::new(FdRef::Regular(fd), 0, 0));
+    let statx = complete(Statx::new(libc::AT_FDCWD, &path, libc::STATX_SIZE).unwrap());
+    assert_eq!(statx.stx_size, 8192);
+
+    complete(Ftruncate::new(FdRef::Regular(fd), 100));
+    complete(Fsync::new(FdRef::Regular(fd)).datasync());
+    let statx = complete(Statx::with_fd(fd, libc::STATX_SIZE));
+    assert_eq!(statx.stx_size, 100);
+    assert_eq!(
+        std::fs::metadata(&path).unwrap().len(),
+        100,
+        "std agrees on the size"
+    );
+
+    complete(Close::new(FdRef::Regular(fd)));
+    // Closed filehandle is no longer valid
+    assert_eq!(unsafe { libc::fcntl(fd, libc::F_GETFD) }, -1);
+
+    std::fs::remove_file(&path).unwrap();
+}
@@ -0,0 +1,89 @@
+use io_uring_bearer::Completion;
+use io_uring_bearer::UringBearer;
+
+use capacity::{Capacity, Setting};
+use io_uring_bearer::io_uring_fd::FdKind;
+use io_uring_bearer::BearerCapacityKind;
+
+use io_uring_op_fs::{CompletedFd, OpenAt};
+
+use std::io::Read;
+use std::os::fd::AsRawFd;
+use std::os::unix::net::UnixStream;
+
+#[derive(Clone, Debug)]
+pub struct MyCapacity;
+
+impl Setting<BearerCapacityKind> for MyCapacity {
+    fn setting(&self, v: &BearerCapacityKind) -> usize {
+        match v {
+            BearerCapacityKind::CoreQueue => 2,
+            BearerCapacityKind::RegisteredFd => 2,
+            BearerCapacityKind::PendingCompletions => 2,
+            BearerCapacityKind::Buffers => 0,
+            BearerCapacityKind::Futexes => 0,
+        }
+    }
+}
+
+fn main() {
+    let my_cap = Capacity::<MyCapacity, BearerCapacityKind>::with_planned(MyCapacity {});
+    let mut bearer: UringBearer<OpenAt> = UringBearer::with_capacity(my_cap).unwrap();
+    bearer.commit_registered_init().unwrap();
+
+    // Existing filehandle registered straight into the first free slot.
+    let (local, _peer) = UnixStream::pair().unwrap();
+    let local_slot = bearer.register_fd(&local, FdKind::RecvSend).unwrap();
+    assert_eq!(bearer.fixed_fd_of(local.as_raw_fd()), Some(local_slot));
+
+    let path = std::env::temp_dir().join(format!("io-uring-op-fs-install-{}", std::process::id()));
+    std::fs::write(&path, b"hello installed").unwrap();
+
+    // File opened by the kernel straight into a fixed slot..
+    let open = OpenAt::with_fixed_fd(None, libc::AT_FDCWD, &path, libc::O_RDONLY, 0).unwrap();
+    bearer.push_fs(open, None).unwrap();
+    bearer.submit_and_wait(1).unwrap();
+
+    let mut opened = None;
+    bearer
+        .typed_completions(&mut opened, |opened, entry, rec, res| match rec {
+            Completion::Fs(_) => {
+                println!("OpenAt Q<{:?}> = {:?}", entry, res);
+                *opened = Some(res.unwrap().unwrap());
+            }
+            _ => panic!("Queue had something else than Fs?"),
+        })
+        .unwrap();
+    let file_slot = match opened {
+        Some(CompletedFd::Fixed(slot)) => slot,
+        o => panic!("Unexpected {:?}", o),
+    };
+    assert!(bearer
+        .registered_fd(file_slot)
+        .unwrap()
+        .is_kernel_installed());
+
+    // .. installed as a regular filehandle for the non-io_uring use.
+    let key = bearer
+        .install_fixed_as_regular(file_slot, true, None)
+        .unwrap();
+    bearer.submit_and_wait(1).unwrap();
+    bearer
+        .typed_completions(&mut (), |_, entry, rec, _| match rec {
+            Completion::FixedFdInstall(i) => {
+                println!("FixedFdInstall Q<{:?}> = {:?}", entry, i.result());
+            }
+            _ => panic!("Queue had something else than FixedFdInstall?"),
+        })
+        .unwrap();
+    let owned = bearer.take_installed_fd(key).unwrap();
+    assert_eq!(bearer.fixed_fd_of(owned.as_raw_fd()), Some(file_slot));
+
+    let mut file = std::fs::File::from(owned);
+    let mut contents = String::new();
+    file.read_to_string(&mut contents).unwrap();
+    assert_eq!(contents, "hello installed");
+    std::fs::remove_file(&path).unwrap();
+
+    println!("Read {} bytes through the installed fd", contents.len());
+}
@@ -0,0 +1,58 @@
+use io_uring_bearer::Completion;
+use io_uring_bearer::UringBearer;
+
+use capacity::{Capacity, Setting};
+use io_uring_bearer::BearerCapacityKind;
+
+use io_uring_op_fs::{CompletedFd, OpenAt, TargetFdType};
+use io_uring_opcode::OpExtFs;
+
+#[derive(Clone, Debug)]
+pub struct MyCapacity;
+
+impl Setting<BearerCapacityKind> for MyCapacity {
+    fn setting(&self, v: &BearerCapacityKind) -> usize {
+        match v {
+            BearerCapacityKind::CoreQueue => 2,
+            BearerCapacityKind::RegisteredFd => 3,
+            BearerCapacityKind::PendingCompletions => 2,
+            BearerCapacityKind::Buffers => 0,
+            BearerCapacityKind::Futexes => 0,
+        }
+    }
+}
+
+fn main() {
+    let my_cap = Capacity::<MyCapacity, BearerCapacityKind>::with_planned(MyCapacity {});
+    let mut bearer = UringBearer::with_capacity(my_cap).unwrap();
+
+    // Make room for the fixed Fds.
+    bearer.commit_registered_init().unwrap();
+
+    // Manually pinned into slot 2 and automatically assigned into the first free slot.
+    let manual =
+        OpenAt::with_fixed_fd(Some(2), libc::AT_FDCWD, "/dev/null", libc::O_RDONLY, 0).unwrap();
+    bearer.push_fs(manual, None).unwrap();
+    let auto = OpenAt::with_fixed_fd(None, libc::AT_FDCWD, "/dev/null", libc::O_RDONLY, 0).unwrap();
+    bearer.push_fs(auto, None).unwrap();
+
+    bearer.submit_and_wait(2).unwrap();
+
+    let mut opened = Vec::new();
+    bearer
+        .typed_completions(&mut opened, |opened, entry, rec, res| match rec {
+            Completion::Fs(o) => {
+                println!("OpenAt Q<{:?}> = {:?}", entry, res);
+                let fd = res.unwrap().unwrap();
+                match o.target_fd() {
+                    Some(TargetFdType::FixedManual(2)) => assert_eq!(fd, CompletedFd::Fixed(2)),
+                    Some(TargetFdType::FixedAuto) => assert_eq!(fd, CompletedFd::Fixed(0)),
+                    t => panic!("Unexpected target {:?}", t),
+                }
+                opened.push(fd);
+            }
+            _ => panic!("Queue had something else than Fs?"),
+        })
+        .unwrap();
+    assert_eq!(opened.len(), 2);
+}
@@ -0,0 +1,67 @@
+//! Close Record
+
+use io_uring_opcode::types::FdRef;
+use io_uring_opcode::OpExtFs;
+use io_uring_opcode::{OpCode, OpCompletion, OpError};
+use io_uring_owner::Owner;
+
+/// Close Record
+#[derive(Clone, Debug)]
+pub struct Close {
+    /// Current owner of the record
+    owner: Owner,
+    /// Related filehandle
+    fd: FdRef,
+}
+
+impl Close {
+    /// Construct a new Close of either the regular filehandle or the fixed slot.
+    /// UringBearer::push_fs refuses the fixed slot which is closed via
+    /// UringBearer::close_fixed that frees the slot for re-use.
+    pub fn new(fd: FdRef) -> Self {
+        Close {
+            owner: Owner::Created,
+            fd,
+        }
+    }
+}
+
+impl OpCompletion for Close {
+    type Error = OpError;
+    type Output = ();
+    fn entry(&self) -> io_uring::squeue::Entry {
+        io_uring_opcode::with_fd_ref!(self.fd, |fd| io_uring::opcode::Close::new(fd).build())
+    }
+    fn opcode(&self) -> u8 {
+        io_uring::opcode::Close::CODE
+    }
+    fn owner(&self) -> Owner {
+        self.owner.clone()
+    }
+    fn force_owner_kernel(&mut self) -> bool {
+        self.owner = Owner::Kernel;
+        true
+    }
+    fn fixed_fd(&self) -> Option<u32> {
+        self.fd.fixed()
+    }
+    fn completion(&mut self, cqe: &io_uring::cqueue::Entry) -> Result<(), OpError> {
+        self.owner = Owner::Returned;
+        match cqe.result() {
+            res if res < 0 => Err(OpError::from_cqe_result("Close", res)),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl OpCode<Close> for Close {
+    fn submission(self) -> Result<Close, OpError> {
+        Ok(self)
+    }
+}
+
+impl OpExtFs for Close {
+    fn fd(&self) -> Option<FdRef> {
+        Some(self.fd)
+    }
+}
@@ -0,0 +1,35 @@
+//! Filesystem op Errors
+
+use core::fmt;
+use core::fmt::Display;
+
+use io_uring_opcode::OpError;
+
+/// Filesystem Errors
+#[derive(Debug)]
+pub enum FsError {
+    /// Must target valid fixed fd, Usually between 0 .. u32_MAX-2
+    InvalidTarget(u32),
+    /// Path contains an interior nul byte
+    InvalidPath(std::ffi::NulError),
+}
+
+impl Display for FsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidTarget(slot) => write!(f, "Invalid fixed fd target slot {}", slot),
+            Self::InvalidPath(e) => write!(f, "Invalid path: {}", e),
+        }
+    }
+}
+
+impl From<FsError> for OpError {
+    fn from(e: FsError) -> OpError {
+        match e {
+            FsError::InvalidTarget(slot) => OpError::InvalidTarget(slot),
+            FsError::InvalidPath(_) => OpError::InvalidParameter("Path contains a nul byte"),
+        }
+    }
+}
+
+impl std::error::Error for FsError {}
@@ -0,0 +1,60 @@
+#![warn(
+    clippy::unwrap_used,
+    missing_docs,
+    rust_2018_idioms,
+    unused_lifetimes,
+    unused_qualifications
+)]
+#![doc = include_str!("../README.md")]
+
+//-----------------------------------------------
+// All Errors
+//-----------------------------------------------
+mod error;
+#[doc(inline)]
+pub use error::*;
+
+//-----------------------------------------------
+// Re-Exports
+//-----------------------------------------------
+pub use io_uring_opcode::types::{CompletedFd, FdRef, TargetFdType};
+
+//-----------------------------------------------
+// OpenAt Record Types
+//-----------------------------------------------
+mod open;
+pub use open::{OpenAt, OpenAt2, OpenHow};
+
+//-----------------------------------------------
+// Close Record Types
+//-----------------------------------------------
+mod close;
+pub use close::Close;
+
+//-----------------------------------------------
+// Statx Record Types
+//-----------------------------------------------
+mod statx;
+pub use statx::Statx;
+
+//-----------------------------------------------
+// Sync Record Types
+//-----------------------------------------------
+mod sync;
+pub use sync::{Fsync, SyncFileRange};
+
+//-----------------------------------------------
+// File size Record Types
+//-----------------------------------------------
+mod size;
+pub use size::{Fallocate, Ftruncate};
+
+use std::ffi::CString;
+use std::os::unix::ffi::OsStrExt;
+use std::path::Path;
+
+/// Path as the nul terminated C string the kernel takes
+#[inline]
+pub(crate) fn path_cstring<P: AsRef<Path>>(path: P) -> Result<CString, FsError> {
+    CString::new(path.as_ref().as_os_str().as_bytes()).map_err(FsError::InvalidPath)
+}
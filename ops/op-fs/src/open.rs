@@ -0,0 +1,273 @@
+//! OpenAt Records
+
+use crate::error::FsError;
+use crate::path_cstring;
+
+use std::ffi::CString;
+use std::os::fd::RawFd;
+use std::path::Path;
+
+use io_uring_opcode::types::{CompletedFd, FdRef, TargetFdType};
+use io_uring_opcode::OpExtFs;
+use io_uring_opcode::{OpCode, OpCompletion, OpError};
+use io_uring_owner::Owner;
+
+/// openat2(2) open_how with the O_* flags, mode and RESOLVE_* flags
+pub use io_uring::types::OpenHow;
+
+/// Targeted Fd type into the one-way io_uring destination slot
+#[inline]
+fn destination_slot(
+    target_fd_type: &TargetFdType,
+) -> Result<Option<io_uring::types::DestinationSlot>, FsError> {
+    target_fd_type
+        .destination_slot()
+        .map_err(FsError::InvalidTarget)
+}
+
+/// Completed filehandle in the targeted Fd type
+#[inline]
+fn completed_fd(target_fd_type: &TargetFdType, res: i32) -> CompletedFd {
+    match target_fd_type {
+        TargetFdType::Regular => CompletedFd::Regular(res),
+        TargetFdType::FixedAuto => CompletedFd::Fixed(res as u32),
+        TargetFdType::FixedManual(slot) => CompletedFd::Fixed(*slot),
+    }
+}
+
+/// OpenAt Record
+#[derive(Clone, Debug)]
+pub struct OpenAt {
+    /// Current owner of the record
+    owner: Owner,
+    /// Directory the relative path is resolved against e.g. libc::AT_FDCWD
+    dirfd: RawFd,
+    path: CString,
+    flags: i32,
+    mode: libc::mode_t,
+    target_fd_type: TargetFdType,
+    target_destination_slot: Option<io_uring::types::DestinationSlot>,
+}
+
+impl OpenAt {
+    /// Construct a new OpenAt resulting in a regular filehandle, flags being the
+    /// O_* flags e.g. libc::O_RDONLY and mode the permissions if the file is created.
+    pub fn with_regular_fd<P: AsRef<Path>>(
+        dirfd: RawFd,
+        path: P,
+        flags: i32,
+        mode: libc::mode_t,
+    ) -> Result<Self, FsError> {
+        Self::with_target(TargetFdType::Regular, dirfd, path, flags, mode)
+    }
+    /// Construct a new OpenAt directly into fixed filehandle, optionally targeting specific
+    /// fixed slot or auto by default. On auto there must be free (as in set -1) registered
+    /// entries available. O_CLOEXEC is not allowed for the fixed filehandles.
+    pub fn with_fixed_fd<P: AsRef<Path>>(
+        manual_target_slot: Option<u32>,
+        dirfd: RawFd,
+        path: P,
+        flags: i32,
+        mode: libc::mode_t,
+    ) -> Result<Self, FsError> {
+        let target_fd_type = match manual_target_slot {
+            None => TargetFdType::FixedAuto,
+            Some(s) => TargetFdType::FixedManual(s),
+        };
+        Self::with_target(target_fd_type, dirfd, path, flags, mode)
+    }
+    fn with_target<P: AsRef<Path>>(
+        target_fd_type: TargetFdType,
+        dirfd: RawFd,
+        path: P,
+        flags: i32,
+        mode: libc::mode_t,
+    ) -> Result<Self, FsError> {
+        Ok(OpenAt {
+            owner: Owner::Created,
+            dirfd,
+            path: path_cstring(path)?,
+            flags,
+            mode,
+            target_destination_slot: destination_slot(&target_fd_type)?,
+            target_fd_type,
+        })
+    }
+    /// Underlying path
+    pub fn path(&self) -> &CString {
+        &self.path
+    }
+}
+
+impl OpCompletion for OpenAt {
+    type Error = OpError;
+    type Output = CompletedFd;
+    fn entry(&self) -> io_uring::squeue::Entry {
+        io_uring::opcode::OpenAt::new(io_uring::types::Fd(self.dirfd), self.path.as_ptr())
+            .flags(self.flags)
+            .mode(self.mode)
+            .file_index(self.target_destination_slot)
+            .build()
+    }
+    fn opcode(&self) -> u8 {
+        io_uring::opcode::OpenAt::CODE
+    }
+    fn owner(&self) -> Owner {
+        self.owner.clone()
+    }
+    fn force_owner_kernel(&mut self) -> bool {
+        self.owner = Owner::Kernel;
+        true
+    }
+    /// Regular and automatically assigned fixed filehandles are returned in the result
+    /// whilst the manually pinned slot is returned as zero upon success.
+    fn completion(&mut self, cqe: &io_uring::cqueue::Entry) -> Result<CompletedFd, OpError> {
+        self.owner = Owner::Returned;
+        match cqe.result() {
+            res if res < 0 => Err(OpError::from_cqe_result("OpenAt", res)),
+            res => Ok(completed_fd(&self.target_fd_type, res)),
+        }
+    }
+}
+
+impl OpCode<OpenAt> for OpenAt {
+    fn submission(self) -> Result<OpenAt, OpError> {
+        Ok(self)
+    }
+}
+
+impl OpExtFs for OpenAt {
+    fn fd(&self) -> Option<FdRef> {
+        None
+    }
+    fn target_fd(&self) -> Option<TargetFdType> {
+        Some(self.target_fd_type.clone())
+    }
+}
+
+/// OpenAt2 Record
+///
+/// The open_how is kept behind stable heap address so the record can be moved
+/// until submitted.
+#[derive(Clone, Debug)]
+pub struct OpenAt2 {
+    /// Current owner of the record
+    owner: Owner,
+    /// Directory the relative path is resolved against e.g. libc::AT_FDCWD
+    dirfd: RawFd,
+    path: CString,
+    how: Box<OpenHow>,
+    target_fd_type: TargetFdType,
+    target_destination_slot: Option<io_uring::types::DestinationSlot>,
+}
+
+impl OpenAt2 {
+    /// Construct a new OpenAt2 resulting in a regular filehandle, how carrying the
+    /// O_* flags, mode and the RESOLVE_* flags e.g. libc::RESOLVE_BENEATH.
+    pub fn with_regular_fd<P: AsRef<Path>>(
+        dirfd: RawFd,
+        path: P,
+        how: OpenHow,
+    ) -> Result<Self, FsError> {
+        Self::with_target(TargetFdType::Regular, dirfd, path, how)
+    }
+    /// Construct a new OpenAt2 directly into fixed filehandle, optionally targeting specific
+    /// fixed slot or auto by default. On auto there must be free (as in set -1) registered
+    /// entries available. O_CLOEXEC is not allowed for the fixed filehandles.
+    pub fn with_fixed_fd<P: AsRef<Path>>(
+        manual_target_slot: Option<u32>,
+        dirfd: RawFd,
+        path: P,
+        how: OpenHow,
+    ) -> Result<Self, FsError> {
+        let target_fd_type = match manual_target_slot {
+            None => TargetFdType::FixedAuto,
+            Some(s) => TargetFdType::FixedManual(s),
+        };
+        Self::with_target(target_fd_type, dirfd, path, how)
+    }
+    fn with_target<P: AsRef<Path>>(
+        target_fd_type: TargetFdType,
+        dirfd: RawFd,
+        path: P,
+        how: OpenHow,
+    ) -> Result<Self, FsError> {
+        Ok(OpenAt2 {
+            owner: Owner::Created,
+            dirfd,
+            path: path_cstring(path)?,
+            how: Box::new(how),
+            target_destination_slot: destination_slot(&target_fd_type)?,
+            target_fd_type,
+        })
+    }
+    /// Underlying path
+    pub fn path(&self) -> &CString {
+        &self.path
+    }
+}
+
+impl OpCompletion for OpenAt2 {
+    type Error = OpError;
+    type Output = CompletedFd;
+    fn entry(&self) -> io_uring::squeue::Entry {
+        io_uring::opcode::OpenAt2::new(
+            io_uring::types::Fd(self.dirfd),
+            self.path.as_ptr(),
+            &*self.how,
+        )
+        .file_index(self.target_destination_slot)
+        .build()
+    }
+    fn opcode(&self) -> u8 {
+        io_uring::opcode::OpenAt2::CODE
+    }
+    fn owner(&self) -> Owner {
+        self.owner.clone()
+    }
+    fn force_owner_kernel(&mut self) -> bool {
+        self.owner = Owner::Kernel;
+        true
+    }
+    /// Regular and automatically assigned fixed filehandles are returned in the result
+    /// whilst the manually pinned slot is returned as zero upon success.
+    fn completion(&mut self, cqe: &io_uring::cqueue::Entry) -> Result<CompletedFd, OpError> {
+        self.owner = Owner::Returned;
+        match cqe.result() {
+            res if res < 0 => Err(OpError::from_cqe_result("OpenAt2", res)),
+            res => Ok(completed_fd(&self.target_fd_type, res)),
+        }
+    }
+}
+
+impl OpCode<OpenAt2> for OpenAt2 {
+    fn submission(self) -> Result<OpenAt2, OpError> {
+        Ok(self)
+    }
+}
+
+impl OpExtFs for OpenAt2 {
+    fn fd(&self) -> Option<FdRef> {
+        None
+    }
+    fn target_fd(&self) -> Option<TargetFdType> {
+        Some(self.target_fd_type.clone())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn invalid_path_and_target() {
+        assert!(matches!(
+            OpenAt::with_regular_fd(libc::AT_FDCWD, "a\0b", libc::O_RDONLY, 0),
+            Err(FsError::InvalidPath(_))
+        ));
+        assert!(matches!(
+            OpenAt2::with_fixed_fd(Some(u32::MAX), libc::AT_FDCWD, "a", OpenHow::new()),
+            Err(FsError::InvalidTarget(u32::MAX))
+        ));
+    }
+}
@@ -0,0 +1,144 @@
+//! Fallocate / Ftruncate Records
+
+use io_uring_opcode::types::FdRef;
+use io_uring_opcode::OpExtFs;
+use io_uring_opcode::{OpCode, OpCompletion, OpError};
+use io_uring_owner::Owner;
+
+/// Fallocate Record
+#[derive(Clone, Debug)]
+pub struct Fallocate {
+    /// Current owner of the record
+    owner: Owner,
+    /// Related filehandle
+    fd: FdRef,
+    offset: u64,
+    len: u64,
+    mode: i32,
+}
+
+impl Fallocate {
+    /// Construct a new Fallocate of the given byte range
+    pub fn new(fd: FdRef, offset: u64, len: u64) -> Self {
+        Fallocate {
+            owner: Owner::Created,
+            fd,
+            offset,
+            len,
+            mode: 0,
+        }
+    }
+    /// Set the FALLOC_FL_* mode e.g. libc::FALLOC_FL_KEEP_SIZE
+    pub fn with_mode(mut self, mode: i32) -> Self {
+        self.mode = mode;
+        self
+    }
+}
+
+impl OpCompletion for Fallocate {
+    type Error = OpError;
+    type Output = ();
+    fn entry(&self) -> io_uring::squeue::Entry {
+        io_uring_opcode::with_fd_ref!(self.fd, |fd| {
+            io_uring::opcode::Fallocate::new(fd, self.len)
+                .offset(self.offset)
+                .mode(self.mode)
+                .build()
+        })
+    }
+    fn opcode(&self) -> u8 {
+        io_uring::opcode::Fallocate::CODE
+    }
+    fn owner(&self) -> Owner {
+        self.owner.clone()
+    }
+    fn force_owner_kernel(&mut self) -> bool {
+        self.owner = Owner::Kernel;
+        true
+    }
+    fn fixed_fd(&self) -> Option<u32> {
+        self.fd.fixed()
+    }
+    fn completion(&mut self, cqe: &io_uring::cqueue::Entry) -> Result<(), OpError> {
+        self.owner = Owner::Returned;
+        match cqe.result() {
+            res if res < 0 => Err(OpError::from_cqe_result("Fallocate", res)),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl OpCode<Fallocate> for Fallocate {
+    fn submission(self) -> Result<Fallocate, OpError> {
+        Ok(self)
+    }
+}
+
+impl OpExtFs for Fallocate {
+    fn fd(&self) -> Option<FdRef> {
+        Some(self.fd)
+    }
+}
+
+/// Ftruncate Record
+#[derive(Clone, Debug)]
+pub struct Ftruncate {
+    /// Current owner of the record
+    owner: Owner,
+    /// Related filehandle
+    fd: FdRef,
+    len: u64,
+}
+
+impl Ftruncate {
+    /// Construct a new Ftruncate to the given length
+    pub fn new(fd: FdRef, len: u64) -> Self {
+        Ftruncate {
+            owner: Owner::Created,
+            fd,
+            len,
+        }
+    }
+}
+
+impl OpCompletion for Ftruncate {
+    type Error = OpError;
+    type Output = ();
+    fn entry(&self) -> io_uring::squeue::Entry {
+        io_uring_opcode::with_fd_ref!(self.fd, |fd| {
+            io_uring::opcode::Ftruncate::new(fd, self.len).build()
+        })
+    }
+    fn opcode(&self) -> u8 {
+        io_uring::opcode::Ftruncate::CODE
+    }
+    fn owner(&self) -> Owner {
+        self.owner.clone()
+    }
+    fn force_owner_kernel(&mut self) -> bool {
+        self.owner = Owner::Kernel;
+        true
+    }
+    fn fixed_fd(&self) -> Option<u32> {
+        self.fd.fixed()
+    }
+    fn completion(&mut self, cqe: &io_uring::cqueue::Entry) -> Result<(), OpError> {
+        self.owner = Owner::Returned;
+        match cqe.result() {
+            res if res < 0 => Err(OpError::from_cqe_result("Ftruncate", res)),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl OpCode<Ftruncate> for Ftruncate {
+    fn submission(self) -> Result<Ftruncate, OpError> {
+        Ok(self)
+    }
+}
+
+impl OpExtFs for Ftruncate {
+    fn fd(&self) -> Option<FdRef> {
+        Some(self.fd)
+    }
+}
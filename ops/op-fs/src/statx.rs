@@ -0,0 +1,118 @@
+//! Statx Record
+
+use crate::error::FsError;
+use crate::path_cstring;
+
+use std::ffi::CString;
+use std::os::fd::RawFd;
+use std::path::Path;
+
+use io_uring_opcode::types::FdRef;
+use io_uring_opcode::OpExtFs;
+use io_uring_opcode::{OpCode, OpCompletion, OpError};
+use io_uring_owner::Owner;
+
+/// Statx Record
+///
+/// The resulting statx is owned by the record behind stable heap address so the
+/// record can be moved until submitted.
+#[derive(Clone, Debug)]
+pub struct Statx {
+    /// Current owner of the record
+    owner: Owner,
+    /// Directory the relative path is resolved against e.g. libc::AT_FDCWD
+    dirfd: RawFd,
+    path: CString,
+    flags: i32,
+    mask: u32,
+    statx: Box<libc::statx>,
+}
+
+impl Statx {
+    /// Construct a new Statx of the given path, mask being the STATX_* fields
+    /// requested e.g. libc::STATX_BASIC_STATS.
+    pub fn new<P: AsRef<Path>>(dirfd: RawFd, path: P, mask: u32) -> Result<Self, FsError> {
+        Ok(Statx {
+            owner: Owner::Created,
+            dirfd,
+            path: path_cstring(path)?,
+            flags: 0,
+            mask,
+            // SAFETY: All-zero statx is valid
+            statx: Box::new(unsafe { core::mem::zeroed() }),
+        })
+    }
+    /// Construct a new Statx of the given regular filehandle itself via AT_EMPTY_PATH
+    pub fn with_fd(fd: RawFd, mask: u32) -> Self {
+        Statx {
+            owner: Owner::Created,
+            dirfd: fd,
+            path: CString::default(),
+            flags: libc::AT_EMPTY_PATH,
+            mask,
+            // SAFETY: All-zero statx is valid
+            statx: Box::new(unsafe { core::mem::zeroed() }),
+        }
+    }
+    /// Add the AT_* flags e.g. libc::AT_SYMLINK_NOFOLLOW
+    pub fn with_flags(mut self, flags: i32) -> Self {
+        self.flags |= flags;
+        self
+    }
+    /// Underlying path
+    pub fn path(&self) -> &CString {
+        &self.path
+    }
+    /// Resulting statx filled upon completion
+    pub fn statx(&self) -> &libc::statx {
+        &self.statx
+    }
+}
+
+impl OpCompletion for Statx {
+    type Error = OpError;
+    type Output = libc::statx;
+    fn entry(&self) -> io_uring::squeue::Entry {
+        io_uring::opcode::Statx::new(
+            io_uring::types::Fd(self.dirfd),
+            self.path.as_ptr(),
+            &*self.statx as *const libc::statx as *mut io_uring::types::statx,
+        )
+        .flags(self.flags)
+        .mask(self.mask)
+        .build()
+    }
+    fn opcode(&self) -> u8 {
+        io_uring::opcode::Statx::CODE
+    }
+    fn owner(&self) -> Owner {
+        self.owner.clone()
+    }
+    fn force_owner_kernel(&mut self) -> bool {
+        self.owner = Owner::Kernel;
+        true
+    }
+    /// Copy of the resulting statx, otherwise the negative errno.
+    fn completion(&mut self, cqe: &io_uring::cqueue::Entry) -> Result<libc::statx, OpError> {
+        self.owner = Owner::Returned;
+        match cqe.result() {
+            res if res < 0 => Err(OpError::from_cqe_result("Statx", res)),
+            _ => Ok(*self.statx),
+        }
+    }
+}
+
+impl OpCode<Statx> for Statx {
+    fn submission(self) -> Result<Statx, OpError> {
+        Ok(self)
+    }
+}
+
+impl OpExtFs for Statx {
+    fn fd(&self) -> Option<FdRef> {
+        match self.flags & libc::AT_EMPTY_PATH {
+            0 => None,
+            _ => Some(FdRef::Regular(self.dirfd)),
+        }
+    }
+}
@@ -0,0 +1,154 @@
+//! Fsync / SyncFileRange Records
+
+use io_uring_opcode::types::FdRef;
+use io_uring_opcode::OpExtFs;
+use io_uring_opcode::{OpCode, OpCompletion, OpError};
+use io_uring_owner::Owner;
+
+/// Fsync Record
+#[derive(Clone, Debug)]
+pub struct Fsync {
+    /// Current owner of the record
+    owner: Owner,
+    /// Related filehandle
+    fd: FdRef,
+    datasync: bool,
+}
+
+impl Fsync {
+    /// Construct a new Fsync of the given filehandle
+    pub fn new(fd: FdRef) -> Self {
+        Fsync {
+            owner: Owner::Created,
+            fd,
+            datasync: false,
+        }
+    }
+    /// Only sync the data and the metadata required to retrieve it, fdatasync(2)
+    pub fn datasync(mut self) -> Self {
+        self.datasync = true;
+        self
+    }
+}
+
+impl OpCompletion for Fsync {
+    type Error = OpError;
+    type Output = ();
+    fn entry(&self) -> io_uring::squeue::Entry {
+        let flags = match self.datasync {
+            true => io_uring::types::FsyncFlags::DATASYNC,
+            false => io_uring::types::FsyncFlags::empty(),
+        };
+        io_uring_opcode::with_fd_ref!(self.fd, |fd| {
+            io_uring::opcode::Fsync::new(fd).flags(flags).build()
+        })
+    }
+    fn opcode(&self) -> u8 {
+        io_uring::opcode::Fsync::CODE
+    }
+    fn owner(&self) -> Owner {
+        self.owner.clone()
+    }
+    fn force_owner_kernel(&mut self) -> bool {
+        self.owner = Owner::Kernel;
+        true
+    }
+    fn fixed_fd(&self) -> Option<u32> {
+        self.fd.fixed()
+    }
+    fn completion(&mut self, cqe: &io_uring::cqueue::Entry) -> Result<(), OpError> {
+        self.owner = Owner::Returned;
+        match cqe.result() {
+            res if res < 0 => Err(OpError::from_cqe_result("Fsync", res)),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl OpCode<Fsync> for Fsync {
+    fn submission(self) -> Result<Fsync, OpError> {
+        Ok(self)
+    }
+}
+
+impl OpExtFs for Fsync {
+    fn fd(&self) -> Option<FdRef> {
+        Some(self.fd)
+    }
+}
+
+/// SyncFileRange Record
+#[derive(Clone, Debug)]
+pub struct SyncFileRange {
+    /// Current owner of the record
+    owner: Owner,
+    /// Related filehandle
+    fd: FdRef,
+    offset: u64,
+    len: u32,
+    flags: u32,
+}
+
+impl SyncFileRange {
+    /// Construct a new SyncFileRange of the given byte range, zero len syncing
+    /// until the end of the file.
+    pub fn new(fd: FdRef, offset: u64, len: u32) -> Self {
+        SyncFileRange {
+            owner: Owner::Created,
+            fd,
+            offset,
+            len,
+            flags: 0,
+        }
+    }
+    /// Set the SYNC_FILE_RANGE_* flags e.g. libc::SYNC_FILE_RANGE_WRITE
+    pub fn with_flags(mut self, flags: u32) -> Self {
+        self.flags = flags;
+        self
+    }
+}
+
+impl OpCompletion for SyncFileRange {
+    type Error = OpError;
+    type Output = ();
+    fn entry(&self) -> io_uring::squeue::Entry {
+        io_uring_opcode::with_fd_ref!(self.fd, |fd| {
+            io_uring::opcode::SyncFileRange::new(fd, self.len)
+                .offset(self.offset)
+                .flags(self.flags)
+                .build()
+        })
+    }
+    fn opcode(&self) -> u8 {
+        io_uring::opcode::SyncFileRange::CODE
+    }
+    fn owner(&self) -> Owner {
+        self.owner.clone()
+    }
+    fn force_owner_kernel(&mut self) -> bool {
+        self.owner = Owner::Kernel;
+        true
+    }
+    fn fixed_fd(&self) -> Option<u32> {
+        self.fd.fixed()
+    }
+    fn completion(&mut self, cqe: &io_uring::cqueue::Entry) -> Result<(), OpError> {
+        self.owner = Owner::Returned;
+        match cqe.result() {
+            res if res < 0 => Err(OpError::from_cqe_result("SyncFileRange", res)),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl OpCode<SyncFileRange> for SyncFileRange {
+    fn submission(self) -> Result<SyncFileRange, OpError> {
+        Ok(self)
+    }
+}
+
+impl OpExtFs for SyncFileRange {
+    fn fd(&self) -> Option<FdRef> {
+        Some(self.fd)
+    }
+}
@@ -0,0 +1,78 @@
+use std::os::unix::fs::MetadataExt;
+
+use io_uring_bearer::Completion;
+use io_uring_bearer::UringBearer;
+
+use capacity::{Capacity, Setting};
+use io_uring_bearer::BearerCapacityKind;
+
+use io_uring_op_path::{LinkAt, MkdirAt, RenameAt, SymlinkAt, UnlinkAt};
+use io_uring_opcode::{OpCode, OpCompletion, OpExtPath};
+
+#[derive(Clone, Debug)]
+pub struct MyCapacity;
+
+impl Setting<BearerCapacityKind> for MyCapacity {
+    fn setting(&self, v: &BearerCapacityKind) -> usize {
+        match v {
+            BearerCapacityKind::CoreQueue => 1,
+            BearerCapacityKind::RegisteredFd => 1,
+            BearerCapacityKind::PendingCompletions => 1,
+            BearerCapacityKind::Buffers => 0,
+            BearerCapacityKind::Futexes => 0,
+        }
+    }
+}
+
+/// Each bearer carries a single record type, run the given op to completion on its own.
+fn complete<C>(op: C)
+where
+    C: core::fmt::Debug + Clone + OpCompletion<Output = ()> + OpCode<C> + OpExtPath,
+    C::Error: core::fmt::Debug,
+{
+    let my_cap = Capacity::<MyCapacity, BearerCapacityKind>::with_planned(MyCapacity {});
+    let mut bearer = UringBearer::with_capacity(my_cap).unwrap();
+    bearer.push_path(op, None).unwrap();
+    bearer.submit_and_wait(1).unwrap();
+
+    bearer
+        .typed_completions(&mut (), |_, entry, rec, res| match rec {
+            Completion::Path(p) => {
+                println!("{:?} Q<{:?}> = {:?}", p.path(), entry, res);
+                res.unwrap().unwrap();
+            }
+            _ => panic!("Queue had something else than Path?"),
+        })
+        .unwrap();
+}
+
+fn main() {
+    let spool = std::env::temp_dir().join(format!("io-uring-op-path-{}", std::process::id()));
+    let cwd = libc::AT_FDCWD;
+
+    complete(MkdirAt::new(cwd, &spool, 0o700).unwrap());
+    let log = spool.join("app.log");
+    std::fs::write(&log, b"line\n").unwrap();
+
+    // Rotate app.log into app.log.1 and point current at it
+    let rotated = spool.join("app.log.1");
+    complete(RenameAt::new(cwd, &log, cwd, &rotated).unwrap());
+    let current = spool.join("current");
+    complete(SymlinkAt::new("app.log.1", cwd, &current).unwrap());
+    let archived = spool.join("archived.log");
+    complete(
+        LinkAt::new(cwd, &current, cwd, &archived)
+            .unwrap()
+            .follow_symlink(),
+    );
+
+    assert!(!log.exists());
+    assert_eq!(std::fs::read(&current).unwrap(), b"line\n");
+    assert_eq!(std::fs::metadata(&rotated).unwrap().nlink(), 2);
+
+    for path in [&current, &archived, &rotated] {
+        complete(UnlinkAt::new(cwd, path).unwrap());
+    }
+    complete(UnlinkAt::new(cwd, &spool).unwrap().remove_dir());
+    assert!(!spool.exists());
+}
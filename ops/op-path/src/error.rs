@@ -0,0 +1,31 @@
+//! Path op Errors
+
+use core::fmt;
+use core::fmt::Display;
+
+use io_uring_opcode::OpError;
+
+/// Path Errors
+#[derive(Debug)]
+pub enum PathError {
+    /// Path contains an interior nul byte
+    InvalidPath(std::ffi::NulError),
+}
+
+impl Display for PathError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidPath(e) => write!(f, "Invalid path: {}", e),
+        }
+    }
+}
+
+impl From<PathError> for OpError {
+    fn from(e: PathError) -> OpError {
+        match e {
+            PathError::InvalidPath(_) => OpError::InvalidParameter("Path contains a nul byte"),
+        }
+    }
+}
+
+impl std::error::Error for PathError {}
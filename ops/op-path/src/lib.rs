@@ -0,0 +1,49 @@
+#![warn(
+    clippy::unwrap_used,
+    missing_docs,
+    rust_2018_idioms,
+    unused_lifetimes,
+    unused_qualifications
+)]
+#![doc = include_str!("../README.md")]
+
+//-----------------------------------------------
+// All Errors
+//-----------------------------------------------
+mod error;
+#[doc(inline)]
+pub use error::*;
+
+//-----------------------------------------------
+// RenameAt Record Types
+//-----------------------------------------------
+mod rename;
+pub use rename::RenameAt;
+
+//-----------------------------------------------
+// UnlinkAt Record Types
+//-----------------------------------------------
+mod unlink;
+pub use unlink::UnlinkAt;
+
+//-----------------------------------------------
+// MkdirAt Record Types
+//-----------------------------------------------
+mod mkdir;
+pub use mkdir::MkdirAt;
+
+//-----------------------------------------------
+// SymlinkAt / LinkAt Record Types
+//-----------------------------------------------
+mod link;
+pub use link::{LinkAt, SymlinkAt};
+
+use std::ffi::CString;
+use std::os::unix::ffi::OsStrExt;
+use std::path::Path;
+
+/// Path as the nul terminated C string the kernel takes
+#[inline]
+pub(crate) fn path_cstring<P: AsRef<Path>>(path: P) -> Result<CString, PathError> {
+    CString::new(path.as_ref().as_os_str().as_bytes()).map_err(PathError::InvalidPath)
+}
@@ -0,0 +1,175 @@
+//! SymlinkAt / LinkAt Records
+
+use crate::error::PathError;
+use crate::path_cstring;
+
+use std::ffi::{CStr, CString};
+use std::os::fd::RawFd;
+use std::path::Path;
+
+use io_uring_opcode::OpExtPath;
+use io_uring_opcode::{OpCode, OpCompletion, OpError};
+use io_uring_owner::Owner;
+
+/// SymlinkAt Record
+#[derive(Clone, Debug)]
+pub struct SymlinkAt {
+    /// Current owner of the record
+    owner: Owner,
+    target: CString,
+    newdirfd: RawFd,
+    linkpath: CString,
+}
+
+impl SymlinkAt {
+    /// Construct a new SymlinkAt creating the link path resolved against the directory
+    /// e.g. libc::AT_FDCWD pointing to the target which is stored as-is.
+    pub fn new<P: AsRef<Path>, Q: AsRef<Path>>(
+        target: P,
+        newdirfd: RawFd,
+        linkpath: Q,
+    ) -> Result<Self, PathError> {
+        Ok(SymlinkAt {
+            owner: Owner::Created,
+            target: path_cstring(target)?,
+            newdirfd,
+            linkpath: path_cstring(linkpath)?,
+        })
+    }
+    /// Underlying target of the link
+    pub fn target(&self) -> &CStr {
+        &self.target
+    }
+}
+
+impl OpCompletion for SymlinkAt {
+    type Error = OpError;
+    type Output = ();
+    fn entry(&self) -> io_uring::squeue::Entry {
+        io_uring::opcode::SymlinkAt::new(
+            io_uring::types::Fd(self.newdirfd),
+            self.target.as_ptr(),
+            self.linkpath.as_ptr(),
+        )
+        .build()
+    }
+    fn opcode(&self) -> u8 {
+        io_uring::opcode::SymlinkAt::CODE
+    }
+    fn owner(&self) -> Owner {
+        self.owner.clone()
+    }
+    fn force_owner_kernel(&mut self) -> bool {
+        self.owner = Owner::Kernel;
+        true
+    }
+    fn completion(&mut self, cqe: &io_uring::cqueue::Entry) -> Result<(), OpError> {
+        self.owner = Owner::Returned;
+        match cqe.result() {
+            res if res < 0 => Err(OpError::from_cqe_result("SymlinkAt", res)),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl OpCode<SymlinkAt> for SymlinkAt {
+    fn submission(self) -> Result<SymlinkAt, OpError> {
+        Ok(self)
+    }
+}
+
+impl OpExtPath for SymlinkAt {
+    fn dirfd(&self) -> RawFd {
+        self.newdirfd
+    }
+    fn path(&self) -> &CStr {
+        &self.linkpath
+    }
+}
+
+/// LinkAt Record
+#[derive(Clone, Debug)]
+pub struct LinkAt {
+    /// Current owner of the record
+    owner: Owner,
+    olddirfd: RawFd,
+    oldpath: CString,
+    newdirfd: RawFd,
+    newpath: CString,
+    flags: i32,
+}
+
+impl LinkAt {
+    /// Construct a new LinkAt creating the new path as hard link of the old path,
+    /// each resolved against their directory e.g. libc::AT_FDCWD.
+    pub fn new<P: AsRef<Path>, Q: AsRef<Path>>(
+        olddirfd: RawFd,
+        oldpath: P,
+        newdirfd: RawFd,
+        newpath: Q,
+    ) -> Result<Self, PathError> {
+        Ok(LinkAt {
+            owner: Owner::Created,
+            olddirfd,
+            oldpath: path_cstring(oldpath)?,
+            newdirfd,
+            newpath: path_cstring(newpath)?,
+            flags: 0,
+        })
+    }
+    /// Dereference the old path if it is a symbolic link, AT_SYMLINK_FOLLOW
+    pub fn follow_symlink(mut self) -> Self {
+        self.flags |= libc::AT_SYMLINK_FOLLOW;
+        self
+    }
+}
+
+impl OpCompletion for LinkAt {
+    type Error = OpError;
+    type Output = ();
+    fn entry(&self) -> io_uring::squeue::Entry {
+        io_uring::opcode::LinkAt::new(
+            io_uring::types::Fd(self.olddirfd),
+            self.oldpath.as_ptr(),
+            io_uring::types::Fd(self.newdirfd),
+            self.newpath.as_ptr(),
+        )
+        .flags(self.flags)
+        .build()
+    }
+    fn opcode(&self) -> u8 {
+        io_uring::opcode::LinkAt::CODE
+    }
+    fn owner(&self) -> Owner {
+        self.owner.clone()
+    }
+    fn force_owner_kernel(&mut self) -> bool {
+        self.owner = Owner::Kernel;
+        true
+    }
+    fn completion(&mut self, cqe: &io_uring::cqueue::Entry) -> Result<(), OpError> {
+        self.owner = Owner::Returned;
+        match cqe.result() {
+            res if res < 0 => Err(OpError::from_cqe_result("LinkAt", res)),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl OpCode<LinkAt> for LinkAt {
+    fn submission(self) -> Result<LinkAt, OpError> {
+        Ok(self)
+    }
+}
+
+impl OpExtPath for LinkAt {
+    fn dirfd(&self) -> RawFd {
+        self.olddirfd
+    }
+    fn path(&self) -> &CStr {
+        &self.oldpath
+    }
+    fn new_path(&self) -> Option<(RawFd, &CStr)> {
+        Some((self.newdirfd, &self.newpath))
+    }
+}
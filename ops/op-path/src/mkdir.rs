@@ -0,0 +1,81 @@
+//! MkdirAt Record
+
+use crate::error::PathError;
+use crate::path_cstring;
+
+use std::ffi::{CStr, CString};
+use std::os::fd::RawFd;
+use std::path::Path;
+
+use io_uring_opcode::OpExtPath;
+use io_uring_opcode::{OpCode, OpCompletion, OpError};
+use io_uring_owner::Owner;
+
+/// MkdirAt Record
+#[derive(Clone, Debug)]
+pub struct MkdirAt {
+    /// Current owner of the record
+    owner: Owner,
+    dirfd: RawFd,
+    path: CString,
+    mode: libc::mode_t,
+}
+
+impl MkdirAt {
+    /// Construct a new MkdirAt of the path resolved against the directory e.g. libc::AT_FDCWD
+    /// with the given permissions subject to the umask.
+    pub fn new<P: AsRef<Path>>(
+        dirfd: RawFd,
+        path: P,
+        mode: libc::mode_t,
+    ) -> Result<Self, PathError> {
+        Ok(MkdirAt {
+            owner: Owner::Created,
+            dirfd,
+            path: path_cstring(path)?,
+            mode,
+        })
+    }
+}
+
+impl OpCompletion for MkdirAt {
+    type Error = OpError;
+    type Output = ();
+    fn entry(&self) -> io_uring::squeue::Entry {
+        io_uring::opcode::MkDirAt::new(io_uring::types::Fd(self.dirfd), self.path.as_ptr())
+            .mode(self.mode)
+            .build()
+    }
+    fn opcode(&self) -> u8 {
+        io_uring::opcode::MkDirAt::CODE
+    }
+    fn owner(&self) -> Owner {
+        self.owner.clone()
+    }
+    fn force_owner_kernel(&mut self) -> bool {
+        self.owner = Owner::Kernel;
+        true
+    }
+    fn completion(&mut self, cqe: &io_uring::cqueue::Entry) -> Result<(), OpError> {
+        self.owner = Owner::Returned;
+        match cqe.result() {
+            res if res < 0 => Err(OpError::from_cqe_result("MkdirAt", res)),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl OpCode<MkdirAt> for MkdirAt {
+    fn submission(self) -> Result<MkdirAt, OpError> {
+        Ok(self)
+    }
+}
+
+impl OpExtPath for MkdirAt {
+    fn dirfd(&self) -> RawFd {
+        self.dirfd
+    }
+    fn path(&self) -> &CStr {
+        &self.path
+    }
+}
@@ -0,0 +1,116 @@
+//! RenameAt Record
+
+use crate::error::PathError;
+use crate::path_cstring;
+
+use std::ffi::{CStr, CString};
+use std::os::fd::RawFd;
+use std::path::Path;
+
+use io_uring_opcode::OpExtPath;
+use io_uring_opcode::{OpCode, OpCompletion, OpError};
+use io_uring_owner::Owner;
+
+/// RenameAt Record
+#[derive(Clone, Debug)]
+pub struct RenameAt {
+    /// Current owner of the record
+    owner: Owner,
+    olddirfd: RawFd,
+    oldpath: CString,
+    newdirfd: RawFd,
+    newpath: CString,
+    flags: u32,
+}
+
+impl RenameAt {
+    /// Construct a new RenameAt of the old path into the new path, each resolved
+    /// against their directory e.g. libc::AT_FDCWD.
+    pub fn new<P: AsRef<Path>, Q: AsRef<Path>>(
+        olddirfd: RawFd,
+        oldpath: P,
+        newdirfd: RawFd,
+        newpath: Q,
+    ) -> Result<Self, PathError> {
+        Ok(RenameAt {
+            owner: Owner::Created,
+            olddirfd,
+            oldpath: path_cstring(oldpath)?,
+            newdirfd,
+            newpath: path_cstring(newpath)?,
+            flags: 0,
+        })
+    }
+    /// Set the RENAME_* flags e.g. libc::RENAME_NOREPLACE
+    pub fn with_flags(mut self, flags: u32) -> Self {
+        self.flags = flags;
+        self
+    }
+}
+
+impl OpCompletion for RenameAt {
+    type Error = OpError;
+    type Output = ();
+    fn entry(&self) -> io_uring::squeue::Entry {
+        io_uring::opcode::RenameAt::new(
+            io_uring::types::Fd(self.olddirfd),
+            self.oldpath.as_ptr(),
+            io_uring::types::Fd(self.newdirfd),
+            self.newpath.as_ptr(),
+        )
+        .flags(self.flags)
+        .build()
+    }
+    fn opcode(&self) -> u8 {
+        io_uring::opcode::RenameAt::CODE
+    }
+    fn owner(&self) -> Owner {
+        self.owner.clone()
+    }
+    fn force_owner_kernel(&mut self) -> bool {
+        self.owner = Owner::Kernel;
+        true
+    }
+    fn completion(&mut self, cqe: &io_uring::cqueue::Entry) -> Result<(), OpError> {
+        self.owner = Owner::Returned;
+        match cqe.result() {
+            res if res < 0 => Err(OpError::from_cqe_result("RenameAt", res)),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl OpCode<RenameAt> for RenameAt {
+    fn submission(self) -> Result<RenameAt, OpError> {
+        Ok(self)
+    }
+}
+
+impl OpExtPath for RenameAt {
+    fn dirfd(&self) -> RawFd {
+        self.olddirfd
+    }
+    fn path(&self) -> &CStr {
+        &self.oldpath
+    }
+    fn new_path(&self) -> Option<(RawFd, &CStr)> {
+        Some((self.newdirfd, &self.newpath))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn paths_held_as_cstring() {
+        let r = RenameAt::new(libc::AT_FDCWD, "a.log", 3, "a.log.1").expect("rename");
+        assert_eq!(r.path().to_bytes(), b"a.log");
+        let (newdirfd, newpath) = r.new_path().expect("new_path");
+        assert_eq!((newdirfd, newpath.to_bytes()), (3, &b"a.log.1"[..]));
+        assert!(matches!(
+            RenameAt::new(libc::AT_FDCWD, "a", libc::AT_FDCWD, "b\0"),
+            Err(PathError::InvalidPath(_))
+        ));
+    }
+}
@@ -0,0 +1,81 @@
+//! UnlinkAt Record
+
+use crate::error::PathError;
+use crate::path_cstring;
+
+use std::ffi::{CStr, CString};
+use std::os::fd::RawFd;
+use std::path::Path;
+
+use io_uring_opcode::OpExtPath;
+use io_uring_opcode::{OpCode, OpCompletion, OpError};
+use io_uring_owner::Owner;
+
+/// UnlinkAt Record
+#[derive(Clone, Debug)]
+pub struct UnlinkAt {
+    /// Current owner of the record
+    owner: Owner,
+    dirfd: RawFd,
+    path: CString,
+    flags: i32,
+}
+
+impl UnlinkAt {
+    /// Construct a new UnlinkAt of the path resolved against the directory e.g. libc::AT_FDCWD
+    pub fn new<P: AsRef<Path>>(dirfd: RawFd, path: P) -> Result<Self, PathError> {
+        Ok(UnlinkAt {
+            owner: Owner::Created,
+            dirfd,
+            path: path_cstring(path)?,
+            flags: 0,
+        })
+    }
+    /// Remove the empty directory instead, AT_REMOVEDIR
+    pub fn remove_dir(mut self) -> Self {
+        self.flags |= libc::AT_REMOVEDIR;
+        self
+    }
+}
+
+impl OpCompletion for UnlinkAt {
+    type Error = OpError;
+    type Output = ();
+    fn entry(&self) -> io_uring::squeue::Entry {
+        io_uring::opcode::UnlinkAt::new(io_uring::types::Fd(self.dirfd), self.path.as_ptr())
+            .flags(self.flags)
+            .build()
+    }
+    fn opcode(&self) -> u8 {
+        io_uring::opcode::UnlinkAt::CODE
+    }
+    fn owner(&self) -> Owner {
+        self.owner.clone()
+    }
+    fn force_owner_kernel(&mut self) -> bool {
+        self.owner = Owner::Kernel;
+        true
+    }
+    fn completion(&mut self, cqe: &io_uring::cqueue::Entry) -> Result<(), OpError> {
+        self.owner = Owner::Returned;
+        match cqe.result() {
+            res if res < 0 => Err(OpError::from_cqe_result("UnlinkAt", res)),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl OpCode<UnlinkAt> for UnlinkAt {
+    fn submission(self) -> Result<UnlinkAt, OpError> {
+        Ok(self)
+    }
+}
+
+impl OpExtPath for UnlinkAt {
+    fn dirfd(&self) -> RawFd {
+        self.dirfd
+    }
+    fn path(&self) -> &CStr {
+        &self.path
+    }
+}
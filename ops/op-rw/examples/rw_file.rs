@@ -0,0 +1,101 @@
+use std::num::NonZero;
+use std::os::fd::AsRawFd;
+
+use io_uring_bearer::Completion;
+use io_uring_bearer::UringBearer;
+
+use capacity::{Capacity, Setting};
+use io_uring_bearer::BearerCapacityKind;
+
+use io_uring_op_rw::{Read, ReadBuf, Readv, Write};
+use io_uring_opcode::types::FdRef;
+
+#[derive(Clone, Debug)]
+pub struct MyCapacity;
+
+impl Setting<BearerCapacityKind> for MyCapacity {
+    fn setting(&self, v: &BearerCapacityKind) -> usize {
+        match v {
+            BearerCapacityKind::CoreQueue => 2,
+            BearerCapacityKind::RegisteredFd => 1,
+            BearerCapacityKind::PendingCompletions => 2,
+            BearerCapacityKind::Buffers => 1,
+            BearerCapacityKind::Futexes => 0,
+        }
+    }
+}
+
+fn new_bearer<C: core::fmt::Debug + Clone + io_uring_opcode::OpCompletion>() -> UringBearer<C> {
+    let my_cap = Capacity::<MyCapacity, BearerCapacityKind>::with_planned(MyCapacity {});
+    UringBearer::with_capacity(my_cap).unwrap()
+}
+
+fn main() {
+    let path = std::env::temp_dir().join(format!("io-uring-op-rw-{}", std::process::id()));
+    let file = std::fs::OpenOptions::new()
+        .create(true)
+        .truncate(true)
+        .read(true)
+        .write(true)
+        .open(&path)
+        .unwrap();
+    let fd = FdRef::Regular(file.as_raw_fd());
+
+    // Each bearer carries a single record type
+    let mut bearer = new_bearer();
+    // Owned buffer written at the start of the file
+    let write = Write::new(fd, b"hello world".to_vec(), 0).unwrap();
+    bearer.push_rw(write, None).unwrap();
+    bearer.submit_and_wait(1).unwrap();
+    bearer
+        .typed_completions(&mut (), |_, entry, rec, res| match rec {
+            Completion::Rw(_) => {
+                println!("Write Q<{:?}> = {:?}", entry, res);
+                assert_eq!(res.unwrap().unwrap(), 11);
+            }
+            _ => panic!("Queue had something else than Rw?"),
+        })
+        .unwrap();
+
+    // Bearer managed buffer read from offset 6
+    let mut bearer = new_bearer();
+    let buf_idx = bearer.create_buffers(NonZero::new(1).unwrap(), 5).unwrap();
+    let taken = bearer.take_one_mutable_buffer(buf_idx).unwrap();
+    let read = Read::new(fd, taken, 6).unwrap();
+    bearer.push_rw(read, None).unwrap();
+    bearer.submit_and_wait(1).unwrap();
+    bearer
+        .typed_completions(&mut (), |_, entry, rec, res| match rec {
+            Completion::Rw(read) => {
+                println!("Read Q<{:?}> = {:?}", entry, res);
+                assert_eq!(res.unwrap().unwrap(), 5);
+                assert_eq!(read.filled(), b"world");
+            }
+            _ => panic!("Queue had something else than Rw?"),
+        })
+        .unwrap();
+    // The bearer managed buffer is returned for re-use upon completion
+    bearer.take_one_mutable_buffer(buf_idx).unwrap();
+    // and may be given back when it ends up never pushed
+    bearer.return_taken_buffer(buf_idx).unwrap();
+    bearer.take_one_mutable_buffer(buf_idx).unwrap();
+
+    // Owned buffers read from the start of the file
+    let mut bearer = new_bearer();
+    let readv = Readv::new(fd, vec![ReadBuf::owned(6), ReadBuf::owned(16)], 0).unwrap();
+    bearer.push_rw(readv, None).unwrap();
+    bearer.submit_and_wait(1).unwrap();
+    bearer
+        .typed_completions(&mut (), |_, entry, rec, res| match rec {
+            Completion::Rw(readv) => {
+                println!("Readv Q<{:?}> = {:?}", entry, res);
+                assert_eq!(res.unwrap().unwrap(), 11);
+                let filled: Vec<&[u8]> = readv.filled().collect();
+                assert_eq!(filled, vec![&b"hello "[..], &b"world"[..]]);
+            }
+            _ => panic!("Queue had something else than Rw?"),
+        })
+        .unwrap();
+
+    std::fs::remove_file(&path).unwrap();
+}
@@ -0,0 +1,39 @@
+//! Read / Write op Errors
+
+use core::fmt;
+use core::fmt::Display;
+
+use io_uring_opcode::OpError;
+
+/// Read / Write Errors
+#[derive(Debug)]
+pub enum RwError {
+    /// Buffer is longer than the u32 length the kernel accepts
+    BufferTooLong(usize),
+    /// Offset must be either -1 for the current position or positive
+    InvalidOffset(i64),
+    /// Readv / Writev need at least one and at most UIO_MAXIOV buffers
+    InvalidIovecCount(usize),
+}
+
+impl Display for RwError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::BufferTooLong(len) => write!(f, "Buffer of {} bytes is too long", len),
+            Self::InvalidOffset(o) => write!(f, "Invalid file offset {}", o),
+            Self::InvalidIovecCount(c) => write!(f, "Invalid count of {} iovecs", c),
+        }
+    }
+}
+
+impl From<RwError> for OpError {
+    fn from(e: RwError) -> OpError {
+        match e {
+            RwError::BufferTooLong(_) => OpError::InvalidParameter("Buffer is too long"),
+            RwError::InvalidOffset(_) => OpError::InvalidParameter("Invalid file offset"),
+            RwError::InvalidIovecCount(_) => OpError::InvalidParameter("Invalid count of iovecs"),
+        }
+    }
+}
+
+impl std::error::Error for RwError {}
@@ -0,0 +1,52 @@
+#![warn(
+    clippy::unwrap_used,
+    missing_docs,
+    rust_2018_idioms,
+    unused_lifetimes,
+    unused_qualifications
+)]
+#![doc = include_str!("../README.md")]
+
+//-----------------------------------------------
+// All Errors
+//-----------------------------------------------
+mod error;
+#[doc(inline)]
+pub use error::*;
+
+//-----------------------------------------------
+// Buffer Types
+//-----------------------------------------------
+mod rw_buf;
+pub use rw_buf::{ReadBuf, WriteBuf};
+
+//-----------------------------------------------
+// Read Record Types
+//-----------------------------------------------
+mod read;
+pub use read::{Read, ReadFixed};
+
+//-----------------------------------------------
+// Write Record Types
+//-----------------------------------------------
+mod write;
+pub use write::{Write, WriteFixed};
+
+//-----------------------------------------------
+// Vectored Record Types
+//-----------------------------------------------
+mod vectored;
+pub use vectored::{Readv, Writev};
+
+/// File offset referring to the current file position
+pub const CURRENT_POSITION: i64 = -1;
+
+/// Map the given offset into the u64 the kernel takes, -1 being the current position.
+#[inline]
+pub(crate) fn offset_u64(offset: i64) -> Result<u64, RwError> {
+    match offset {
+        CURRENT_POSITION => Ok(u64::MAX),
+        o if o < 0 => Err(RwError::InvalidOffset(o)),
+        o => Ok(o as u64),
+    }
+}
@@ -0,0 +1,270 @@
+//! Read Records
+
+use crate::error::RwError;
+use crate::offset_u64;
+use crate::ReadBuf;
+
+use io_uring_bearer::slab::TakenMutableBuffer;
+use io_uring_opcode::types::FdRef;
+use io_uring_opcode::OpExtRw;
+use io_uring_opcode::{OpCode, OpCompletion, OpError};
+use io_uring_owner::Owner;
+
+/// Read Record
+///
+/// The buffer is kept behind a stable heap address so the record can be moved until
+/// submitted.
+#[derive(Debug)]
+pub struct Read {
+    /// Current owner of the record
+    owner: Owner,
+    /// Related filehandle
+    fd: FdRef,
+    buf: ReadBuf,
+    /// Taken once through the exclusive access to the buffer
+    buf_ptr: *mut u8,
+    buf_idx: Option<usize>,
+    offset: u64,
+    rw_flags: i32,
+    filled: usize,
+}
+
+impl Read {
+    /// Construct a new Read into the given buffer from the given file offset,
+    /// -1 reading from the current file position.
+    pub fn new<B: Into<ReadBuf>>(fd: FdRef, buf: B, offset: i64) -> Result<Self, RwError> {
+        let mut buf = buf.into();
+        if buf.len() > u32::MAX as usize {
+            return Err(RwError::BufferTooLong(buf.len()));
+        }
+        Ok(Read {
+            owner: Owner::Created,
+            fd,
+            buf_idx: buf.buf_idx(),
+            buf_ptr: buf.as_mut_ptr(),
+            buf,
+            offset: offset_u64(offset)?,
+            rw_flags: 0,
+            filled: 0,
+        })
+    }
+    /// Set the RWF_* flags e.g. libc::RWF_NOWAIT
+    pub fn with_rw_flags(mut self, rw_flags: i32) -> Self {
+        self.rw_flags = rw_flags;
+        self
+    }
+    /// Underlying buffer
+    pub fn buf(&self) -> &ReadBuf {
+        &self.buf
+    }
+    /// Bytes read into the buffer upon completion
+    pub fn filled(&self) -> &[u8] {
+        self.buf.filled(self.filled)
+    }
+}
+
+impl Clone for Read {
+    fn clone(&self) -> Self {
+        // Owned buffer is cloned into a new allocation
+        let mut buf = self.buf.clone();
+        Read {
+            owner: self.owner.clone(),
+            fd: self.fd,
+            buf_ptr: buf.as_mut_ptr(),
+            buf,
+            buf_idx: self.buf_idx,
+            offset: self.offset,
+            rw_flags: self.rw_flags,
+            filled: self.filled,
+        }
+    }
+}
+
+impl OpCompletion for Read {
+    type Error = OpError;
+    type Output = usize;
+    fn entry(&self) -> io_uring::squeue::Entry {
+        io_uring_opcode::with_fd_ref!(self.fd, |fd| {
+            io_uring::opcode::Read::new(fd, self.buf_ptr, self.buf.len() as u32)
+                .offset(self.offset)
+                .rw_flags(self.rw_flags)
+                .build()
+        })
+    }
+    fn opcode(&self) -> u8 {
+        io_uring::opcode::Read::CODE
+    }
+    fn owner(&self) -> Owner {
+        self.owner.clone()
+    }
+    fn force_owner_kernel(&mut self) -> bool {
+        self.owner = Owner::Kernel;
+        true
+    }
+    fn fixed_fd(&self) -> Option<u32> {
+        self.fd.fixed()
+    }
+    fn buf_idxs(&self) -> &[usize] {
+        match &self.buf_idx {
+            Some(buf_idx) => core::slice::from_ref(buf_idx),
+            None => &[],
+        }
+    }
+    /// Count of bytes read, zero at end of file, otherwise the negative errno.
+    fn completion(&mut self, cqe: &io_uring::cqueue::Entry) -> Result<usize, OpError> {
+        self.owner = Owner::Returned;
+        match cqe.result() {
+            res if res < 0 => Err(OpError::from_cqe_result("Read", res)),
+            res => {
+                self.filled = res as usize;
+                Ok(res as usize)
+            }
+        }
+    }
+}
+
+impl OpCode<Read> for Read {
+    fn submission(self) -> Result<Read, OpError> {
+        Ok(self)
+    }
+}
+
+impl OpExtRw for Read {
+    fn fd(&self) -> FdRef {
+        self.fd
+    }
+    fn offset(&self) -> u64 {
+        self.offset
+    }
+    fn len(&self) -> usize {
+        self.buf.len()
+    }
+    fn rw_flags(&self) -> i32 {
+        self.rw_flags
+    }
+    fn is_write(&self) -> bool {
+        false
+    }
+}
+
+/// ReadFixed Record reading into a registered buffer
+#[derive(Clone, Debug)]
+pub struct ReadFixed {
+    /// Current owner of the record
+    owner: Owner,
+    /// Related filehandle
+    fd: FdRef,
+    buf: ReadBuf,
+    /// Taken once through the exclusive access to the bearer managed buffer which
+    /// stays in place when the record is cloned
+    buf_ptr: *mut u8,
+    buf_idx: usize,
+    buf_kernel_index: u16,
+    offset: u64,
+    rw_flags: i32,
+    filled: usize,
+}
+
+impl ReadFixed {
+    /// Construct a new ReadFixed into the registered buffer taken via
+    /// UringBearer::take_one_fixed_mutable_buffer from the given file offset,
+    /// -1 reading from the current file position.
+    pub fn new(fd: FdRef, buf: TakenMutableBuffer, offset: i64) -> Result<Self, RwError> {
+        Ok(ReadFixed {
+            owner: Owner::Created,
+            fd,
+            buf_idx: buf.buf_idx(),
+            buf_kernel_index: buf.buf_kernel_index(),
+            buf_ptr: buf.as_mut_ptr(),
+            buf: ReadBuf::Bearer(buf),
+            offset: offset_u64(offset)?,
+            rw_flags: 0,
+            filled: 0,
+        })
+    }
+    /// Set the RWF_* flags e.g. libc::RWF_NOWAIT
+    pub fn with_rw_flags(mut self, rw_flags: i32) -> Self {
+        self.rw_flags = rw_flags;
+        self
+    }
+    /// Index of the registered buffer in the kernel fixed buffers table
+    pub fn buf_kernel_index(&self) -> u16 {
+        self.buf_kernel_index
+    }
+    /// Bytes read into the buffer upon completion
+    pub fn filled(&self) -> &[u8] {
+        self.buf.filled(self.filled)
+    }
+}
+
+impl OpCompletion for ReadFixed {
+    type Error = OpError;
+    type Output = usize;
+    fn entry(&self) -> io_uring::squeue::Entry {
+        io_uring_opcode::with_fd_ref!(self.fd, |fd| {
+            io_uring::opcode::ReadFixed::new(
+                fd,
+                self.buf_ptr,
+                self.buf.len() as u32,
+                self.buf_kernel_index,
+            )
+            .offset(self.offset)
+            .rw_flags(self.rw_flags)
+            .build()
+        })
+    }
+    fn opcode(&self) -> u8 {
+        io_uring::opcode::ReadFixed::CODE
+    }
+    fn owner(&self) -> Owner {
+        self.owner.clone()
+    }
+    fn force_owner_kernel(&mut self) -> bool {
+        self.owner = Owner::Kernel;
+        true
+    }
+    fn fixed_fd(&self) -> Option<u32> {
+        self.fd.fixed()
+    }
+    fn buf_idxs(&self) -> &[usize] {
+        core::slice::from_ref(&self.buf_idx)
+    }
+    /// Count of bytes read, zero at end of file, otherwise the negative errno.
+    fn completion(&mut self, cqe: &io_uring::cqueue::Entry) -> Result<usize, OpError> {
+        self.owner = Owner::Returned;
+        match cqe.result() {
+            res if res < 0 => Err(OpError::from_cqe_result("ReadFixed", res)),
+            res => {
+                self.filled = res as usize;
+                Ok(res as usize)
+            }
+        }
+    }
+}
+
+impl OpCode<ReadFixed> for ReadFixed {
+    fn submission(self) -> Result<ReadFixed, OpError> {
+        Ok(self)
+    }
+}
+
+impl OpExtRw for ReadFixed {
+    fn fd(&self) -> FdRef {
+        self.fd
+    }
+    fn offset(&self) -> u64 {
+        self.offset
+    }
+    fn len(&self) -> usize {
+        self.buf.len()
+    }
+    fn rw_flags(&self) -> i32 {
+        self.rw_flags
+    }
+    fn is_write(&self) -> bool {
+        false
+    }
+    fn fixed_buf(&self) -> Option<(usize, u16)> {
+        Some((self.buf_idx, self.buf_kernel_index))
+    }
+}
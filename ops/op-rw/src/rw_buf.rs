@@ -0,0 +1,124 @@
+//! Read / Write Buffers
+
+use io_uring_bearer::slab::{TakenImmutableBuffer, TakenMutableBuffer};
+
+/// Buffer the kernel reads into, held by the record until the completion
+#[derive(Clone, Debug)]
+pub enum ReadBuf {
+    /// Bearer managed buffer taken via UringBearer::take_one_mutable_buffer
+    Bearer(TakenMutableBuffer),
+    /// Owned buffer, read into up to its length
+    Owned(Vec<u8>),
+}
+
+impl ReadBuf {
+    /// Owned zeroed buffer of the given length
+    #[inline]
+    pub fn owned(len: usize) -> Self {
+        Self::Owned(vec![0; len])
+    }
+    /// Raw pointer to the start of the buffer the kernel writes through
+    #[inline]
+    pub fn as_mut_ptr(&mut self) -> *mut u8 {
+        match self {
+            Self::Bearer(b) => b.as_mut_ptr(),
+            Self::Owned(v) => v.as_mut_ptr(),
+        }
+    }
+    /// Length of the buffer
+    #[inline]
+    pub fn len(&self) -> usize {
+        match self {
+            Self::Bearer(b) => b.buf_size() as usize,
+            Self::Owned(v) => v.len(),
+        }
+    }
+    /// Whether the buffer is empty
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    /// Index of the bearer managed buffer if any
+    #[inline]
+    pub fn buf_idx(&self) -> Option<usize> {
+        match self {
+            Self::Bearer(b) => Some(b.buf_idx()),
+            Self::Owned(_) => None,
+        }
+    }
+    /// The first len bytes read into the buffer
+    pub(crate) fn filled(&self, len: usize) -> &[u8] {
+        let len = len.min(self.len());
+        match self {
+            // SAFETY: The bearer managed buffer cannot be taken again until the
+            //         completion has been handled while the record is borrowed.
+            Self::Bearer(b) => unsafe { core::slice::from_raw_parts(b.as_mut_ptr(), len) },
+            Self::Owned(v) => &v[..len],
+        }
+    }
+}
+
+impl From<Vec<u8>> for ReadBuf {
+    fn from(v: Vec<u8>) -> Self {
+        Self::Owned(v)
+    }
+}
+
+impl From<TakenMutableBuffer> for ReadBuf {
+    fn from(b: TakenMutableBuffer) -> Self {
+        Self::Bearer(b)
+    }
+}
+
+/// Data to be written, held by the record until the completion
+#[derive(Clone, Debug)]
+pub enum WriteBuf {
+    /// Bearer managed buffer taken via UringBearer::take_one_immutable_buffer
+    Bearer(TakenImmutableBuffer),
+    /// Owned buffer
+    Owned(Vec<u8>),
+}
+
+impl WriteBuf {
+    /// Raw pointer to the start of the data
+    #[inline]
+    pub fn as_ptr(&self) -> *const u8 {
+        match self {
+            Self::Bearer(b) => b.as_ptr(),
+            Self::Owned(v) => v.as_ptr(),
+        }
+    }
+    /// Length of the data
+    #[inline]
+    pub fn len(&self) -> usize {
+        match self {
+            Self::Bearer(b) => b.buf_size() as usize,
+            Self::Owned(v) => v.len(),
+        }
+    }
+    /// Whether there is no data
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    /// Index of the bearer managed buffer if any
+    #[inline]
+    pub fn buf_idx(&self) -> Option<usize> {
+        match self {
+            Self::Bearer(b) => Some(b.buf_idx()),
+            Self::Owned(_) => None,
+        }
+    }
+}
+
+impl From<Vec<u8>> for WriteBuf {
+    fn from(v: Vec<u8>) -> Self {
+        Self::Owned(v)
+    }
+}
+
+impl From<TakenImmutableBuffer> for WriteBuf {
+    fn from(b: TakenImmutableBuffer) -> Self {
+        Self::Bearer(b)
+    }
+}
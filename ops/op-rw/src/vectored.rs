@@ -0,0 +1,328 @@
+//! Readv / Writev Records
+
+use crate::error::RwError;
+use crate::offset_u64;
+use crate::{ReadBuf, WriteBuf};
+
+use io_uring_opcode::types::FdRef;
+use io_uring_opcode::OpExtRw;
+use io_uring_opcode::{OpCode, OpCompletion, OpError};
+use io_uring_owner::Owner;
+
+/// Max iovecs accepted by readv(2) / writev(2)
+const UIO_MAXIOV: usize = 1024;
+
+/// Readv Record
+///
+/// The iovecs refer to the buffers which are kept behind stable heap addresses
+/// so the record can be moved until submitted.
+#[derive(Debug)]
+pub struct Readv {
+    /// Current owner of the record
+    owner: Owner,
+    /// Related filehandle
+    fd: FdRef,
+    bufs: Vec<ReadBuf>,
+    buf_idxs: Vec<usize>,
+    offset: u64,
+    rw_flags: i32,
+    iovecs: Vec<libc::iovec>,
+    filled: usize,
+}
+
+impl Readv {
+    /// Construct a new Readv into the given buffers filled in the given order from
+    /// the given file offset, -1 reading from the current file position.
+    pub fn new(fd: FdRef, mut bufs: Vec<ReadBuf>, offset: i64) -> Result<Self, RwError> {
+        if bufs.is_empty() || bufs.len() > UIO_MAXIOV {
+            return Err(RwError::InvalidIovecCount(bufs.len()));
+        }
+        Ok(Readv {
+            owner: Owner::Created,
+            fd,
+            buf_idxs: bufs.iter().filter_map(|b| b.buf_idx()).collect(),
+            iovecs: read_iovecs(&mut bufs),
+            bufs,
+            offset: offset_u64(offset)?,
+            rw_flags: 0,
+            filled: 0,
+        })
+    }
+    /// Set the RWF_* flags e.g. libc::RWF_NOWAIT
+    pub fn with_rw_flags(mut self, rw_flags: i32) -> Self {
+        self.rw_flags = rw_flags;
+        self
+    }
+    /// Underlying buffers
+    pub fn bufs(&self) -> &[ReadBuf] {
+        &self.bufs
+    }
+    /// Bytes read into each of the buffers upon completion
+    pub fn filled(&self) -> impl Iterator<Item = &[u8]> + '_ {
+        let mut remaining = self.filled;
+        self.bufs.iter().map(move |b| {
+            let filled = b.filled(remaining);
+            remaining -= filled.len();
+            filled
+        })
+    }
+}
+
+fn read_iovecs(bufs: &mut [ReadBuf]) -> Vec<libc::iovec> {
+    bufs.iter_mut()
+        .map(|b| libc::iovec {
+            iov_base: b.as_mut_ptr() as *mut libc::c_void,
+            iov_len: b.len(),
+        })
+        .collect()
+}
+
+impl Clone for Readv {
+    fn clone(&self) -> Self {
+        // Owned buffers are cloned into new allocations
+        let mut bufs = self.bufs.clone();
+        Readv {
+            owner: self.owner.clone(),
+            fd: self.fd,
+            iovecs: read_iovecs(&mut bufs),
+            bufs,
+            buf_idxs: self.buf_idxs.clone(),
+            offset: self.offset,
+            rw_flags: self.rw_flags,
+            filled: self.filled,
+        }
+    }
+}
+
+impl OpCompletion for Readv {
+    type Error = OpError;
+    type Output = usize;
+    fn entry(&self) -> io_uring::squeue::Entry {
+        io_uring_opcode::with_fd_ref!(self.fd, |fd| {
+            io_uring::opcode::Readv::new(fd, self.iovecs.as_ptr(), self.iovecs.len() as u32)
+                .offset(self.offset)
+                .rw_flags(self.rw_flags)
+                .build()
+        })
+    }
+    fn opcode(&self) -> u8 {
+        io_uring::opcode::Readv::CODE
+    }
+    fn owner(&self) -> Owner {
+        self.owner.clone()
+    }
+    fn force_owner_kernel(&mut self) -> bool {
+        self.owner = Owner::Kernel;
+        true
+    }
+    fn fixed_fd(&self) -> Option<u32> {
+        self.fd.fixed()
+    }
+    fn buf_idxs(&self) -> &[usize] {
+        &self.buf_idxs
+    }
+    /// Count of bytes read, zero at end of file, otherwise the negative errno.
+    fn completion(&mut self, cqe: &io_uring::cqueue::Entry) -> Result<usize, OpError> {
+        self.owner = Owner::Returned;
+        match cqe.result() {
+            res if res < 0 => Err(OpError::from_cqe_result("Readv", res)),
+            res => {
+                self.filled = res as usize;
+                Ok(res as usize)
+            }
+        }
+    }
+}
+
+impl OpCode<Readv> for Readv {
+    fn submission(self) -> Result<Readv, OpError> {
+        Ok(self)
+    }
+}
+
+impl OpExtRw for Readv {
+    fn fd(&self) -> FdRef {
+        self.fd
+    }
+    fn offset(&self) -> u64 {
+        self.offset
+    }
+    fn len(&self) -> usize {
+        self.bufs.iter().map(|b| b.len()).sum()
+    }
+    fn rw_flags(&self) -> i32 {
+        self.rw_flags
+    }
+    fn is_write(&self) -> bool {
+        false
+    }
+}
+
+/// Writev Record
+///
+/// The iovecs refer to the buffers which are kept behind stable heap addresses
+/// so the record can be moved until submitted.
+#[derive(Debug)]
+pub struct Writev {
+    /// Current owner of the record
+    owner: Owner,
+    /// Related filehandle
+    fd: FdRef,
+    bufs: Vec<WriteBuf>,
+    buf_idxs: Vec<usize>,
+    offset: u64,
+    rw_flags: i32,
+    iovecs: Vec<libc::iovec>,
+}
+
+impl Writev {
+    /// Construct a new Writev of the given buffers written in the given order at
+    /// the given file offset, -1 writing at the current file position.
+    pub fn new(fd: FdRef, bufs: Vec<WriteBuf>, offset: i64) -> Result<Self, RwError> {
+        if bufs.is_empty() || bufs.len() > UIO_MAXIOV {
+            return Err(RwError::InvalidIovecCount(bufs.len()));
+        }
+        Ok(Writev {
+            owner: Owner::Created,
+            fd,
+            buf_idxs: bufs.iter().filter_map(|b| b.buf_idx()).collect(),
+            iovecs: write_iovecs(&bufs),
+            bufs,
+            offset: offset_u64(offset)?,
+            rw_flags: 0,
+        })
+    }
+    /// Set the RWF_* flags e.g. libc::RWF_APPEND
+    pub fn with_rw_flags(mut self, rw_flags: i32) -> Self {
+        self.rw_flags = rw_flags;
+        self
+    }
+    /// Underlying buffers
+    pub fn bufs(&self) -> &[WriteBuf] {
+        &self.bufs
+    }
+}
+
+fn write_iovecs(bufs: &[WriteBuf]) -> Vec<libc::iovec> {
+    bufs.iter()
+        .map(|b| libc::iovec {
+            iov_base: b.as_ptr() as *mut libc::c_void,
+            iov_len: b.len(),
+        })
+        .collect()
+}
+
+impl Clone for Writev {
+    fn clone(&self) -> Self {
+        // Owned buffers are cloned into new allocations
+        let bufs = self.bufs.clone();
+        Writev {
+            owner: self.owner.clone(),
+            fd: self.fd,
+            iovecs: write_iovecs(&bufs),
+            bufs,
+            buf_idxs: self.buf_idxs.clone(),
+            offset: self.offset,
+            rw_flags: self.rw_flags,
+        }
+    }
+}
+
+impl OpCompletion for Writev {
+    type Error = OpError;
+    type Output = usize;
+    fn entry(&self) -> io_uring::squeue::Entry {
+        io_uring_opcode::with_fd_ref!(self.fd, |fd| {
+            io_uring::opcode::Writev::new(fd, self.iovecs.as_ptr(), self.iovecs.len() as u32)
+                .offset(self.offset)
+                .rw_flags(self.rw_flags)
+                .build()
+        })
+    }
+    fn opcode(&self) -> u8 {
+        io_uring::opcode::Writev::CODE
+    }
+    fn owner(&self) -> Owner {
+        self.owner.clone()
+    }
+    fn force_owner_kernel(&mut self) -> bool {
+        self.owner = Owner::Kernel;
+        true
+    }
+    fn fixed_fd(&self) -> Option<u32> {
+        self.fd.fixed()
+    }
+    fn buf_idxs(&self) -> &[usize] {
+        &self.buf_idxs
+    }
+    /// Count of bytes written, otherwise the negative errno.
+    fn completion(&mut self, cqe: &io_uring::cqueue::Entry) -> Result<usize, OpError> {
+        self.owner = Owner::Returned;
+        match cqe.result() {
+            res if res < 0 => Err(OpError::from_cqe_result("Writev", res)),
+            res => Ok(res as usize),
+        }
+    }
+}
+
+impl OpCode<Writev> for Writev {
+    fn submission(self) -> Result<Writev, OpError> {
+        Ok(self)
+    }
+}
+
+impl OpExtRw for Writev {
+    fn fd(&self) -> FdRef {
+        self.fd
+    }
+    fn offset(&self) -> u64 {
+        self.offset
+    }
+    fn len(&self) -> usize {
+        self.bufs.iter().map(|b| b.len()).sum()
+    }
+    fn rw_flags(&self) -> i32 {
+        self.rw_flags
+    }
+    fn is_write(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn iovec_count_bounds() {
+        assert!(matches!(
+            Writev::new(FdRef::Regular(0), vec![], -1),
+            Err(RwError::InvalidIovecCount(0))
+        ));
+        assert!(matches!(
+            Readv::new(FdRef::Fixed(0), vec![ReadBuf::owned(1); UIO_MAXIOV + 1], 0),
+            Err(RwError::InvalidIovecCount(_))
+        ));
+        assert!(matches!(
+            Readv::new(FdRef::Fixed(0), vec![ReadBuf::owned(1)], -2),
+            Err(RwError::InvalidOffset(-2))
+        ));
+    }
+    #[test]
+    fn clone_rebuilds_iovecs() {
+        let w = Writev::new(
+            FdRef::Regular(0),
+            vec![b"ab".to_vec().into(), b"cde".to_vec().into()],
+            -1,
+        )
+        .expect("writev");
+        assert_eq!(w.offset(), u64::MAX);
+        let c = w.clone();
+        drop(w);
+        for (iov, buf) in c.iovecs.iter().zip(c.bufs.iter()) {
+            assert_eq!(iov.iov_base as *const u8, buf.as_ptr());
+            assert_eq!(iov.iov_len, buf.len());
+        }
+        assert_eq!(c.len(), 5);
+    }
+}
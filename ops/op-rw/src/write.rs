@@ -0,0 +1,225 @@
+//! Write Records
+
+use crate::error::RwError;
+use crate::offset_u64;
+use crate::WriteBuf;
+
+use io_uring_bearer::slab::TakenImmutableBuffer;
+use io_uring_opcode::types::FdRef;
+use io_uring_opcode::OpExtRw;
+use io_uring_opcode::{OpCode, OpCompletion, OpError};
+use io_uring_owner::Owner;
+
+/// Write Record
+#[derive(Clone, Debug)]
+pub struct Write {
+    /// Current owner of the record
+    owner: Owner,
+    /// Related filehandle
+    fd: FdRef,
+    buf: WriteBuf,
+    buf_idx: Option<usize>,
+    offset: u64,
+    rw_flags: i32,
+}
+
+impl Write {
+    /// Construct a new Write of the given buffer at the given file offset,
+    /// -1 writing at the current file position.
+    pub fn new<B: Into<WriteBuf>>(fd: FdRef, buf: B, offset: i64) -> Result<Self, RwError> {
+        let buf = buf.into();
+        if buf.len() > u32::MAX as usize {
+            return Err(RwError::BufferTooLong(buf.len()));
+        }
+        Ok(Write {
+            owner: Owner::Created,
+            fd,
+            buf_idx: buf.buf_idx(),
+            buf,
+            offset: offset_u64(offset)?,
+            rw_flags: 0,
+        })
+    }
+    /// Set the RWF_* flags e.g. libc::RWF_NOWAIT
+    pub fn with_rw_flags(mut self, rw_flags: i32) -> Self {
+        self.rw_flags = rw_flags;
+        self
+    }
+    /// Underlying buffer
+    pub fn buf(&self) -> &WriteBuf {
+        &self.buf
+    }
+}
+
+impl OpCompletion for Write {
+    type Error = OpError;
+    type Output = usize;
+    fn entry(&self) -> io_uring::squeue::Entry {
+        io_uring_opcode::with_fd_ref!(self.fd, |fd| {
+            io_uring::opcode::Write::new(fd, self.buf.as_ptr(), self.buf.len() as u32)
+                .offset(self.offset)
+                .rw_flags(self.rw_flags)
+                .build()
+        })
+    }
+    fn opcode(&self) -> u8 {
+        io_uring::opcode::Write::CODE
+    }
+    fn owner(&self) -> Owner {
+        self.owner.clone()
+    }
+    fn force_owner_kernel(&mut self) -> bool {
+        self.owner = Owner::Kernel;
+        true
+    }
+    fn fixed_fd(&self) -> Option<u32> {
+        self.fd.fixed()
+    }
+    fn buf_idxs(&self) -> &[usize] {
+        match &self.buf_idx {
+            Some(buf_idx) => core::slice::from_ref(buf_idx),
+            None => &[],
+        }
+    }
+    /// Count of bytes written, otherwise the negative errno.
+    fn completion(&mut self, cqe: &io_uring::cqueue::Entry) -> Result<usize, OpError> {
+        self.owner = Owner::Returned;
+        match cqe.result() {
+            res if res < 0 => Err(OpError::from_cqe_result("Write", res)),
+            res => Ok(res as usize),
+        }
+    }
+}
+
+impl OpCode<Write> for Write {
+    fn submission(self) -> Result<Write, OpError> {
+        Ok(self)
+    }
+}
+
+impl OpExtRw for Write {
+    fn fd(&self) -> FdRef {
+        self.fd
+    }
+    fn offset(&self) -> u64 {
+        self.offset
+    }
+    fn len(&self) -> usize {
+        self.buf.len()
+    }
+    fn rw_flags(&self) -> i32 {
+        self.rw_flags
+    }
+    fn is_write(&self) -> bool {
+        true
+    }
+}
+
+/// WriteFixed Record writing from a registered buffer
+#[derive(Clone, Debug)]
+pub struct WriteFixed {
+    /// Current owner of the record
+    owner: Owner,
+    /// Related filehandle
+    fd: FdRef,
+    buf: WriteBuf,
+    buf_idx: usize,
+    buf_kernel_index: u16,
+    offset: u64,
+    rw_flags: i32,
+}
+
+impl WriteFixed {
+    /// Construct a new WriteFixed of the registered buffer taken via
+    /// UringBearer::take_one_fixed_buffer at the given file offset,
+    /// -1 writing at the current file position.
+    pub fn new(fd: FdRef, buf: TakenImmutableBuffer, offset: i64) -> Result<Self, RwError> {
+        Ok(WriteFixed {
+            owner: Owner::Created,
+            fd,
+            buf_idx: buf.buf_idx(),
+            buf_kernel_index: buf.buf_kernel_index(),
+            buf: WriteBuf::Bearer(buf),
+            offset: offset_u64(offset)?,
+            rw_flags: 0,
+        })
+    }
+    /// Set the RWF_* flags e.g. libc::RWF_NOWAIT
+    pub fn with_rw_flags(mut self, rw_flags: i32) -> Self {
+        self.rw_flags = rw_flags;
+        self
+    }
+    /// Index of the registered buffer in the kernel fixed buffers table
+    pub fn buf_kernel_index(&self) -> u16 {
+        self.buf_kernel_index
+    }
+}
+
+impl OpCompletion for WriteFixed {
+    type Error = OpError;
+    type Output = usize;
+    fn entry(&self) -> io_uring::squeue::Entry {
+        io_uring_opcode::with_fd_ref!(self.fd, |fd| {
+            io_uring::opcode::WriteFixed::new(
+                fd,
+                self.buf.as_ptr(),
+                self.buf.len() as u32,
+                self.buf_kernel_index,
+            )
+            .offset(self.offset)
+            .rw_flags(self.rw_flags)
+            .build()
+        })
+    }
+    fn opcode(&self) -> u8 {
+        io_uring::opcode::WriteFixed::CODE
+    }
+    fn owner(&self) -> Owner {
+        self.owner.clone()
+    }
+    fn force_owner_kernel(&mut self) -> bool {
+        self.owner = Owner::Kernel;
+        true
+    }
+    fn fixed_fd(&self) -> Option<u32> {
+        self.fd.fixed()
+    }
+    fn buf_idxs(&self) -> &[usize] {
+        core::slice::from_ref(&self.buf_idx)
+    }
+    /// Count of bytes written, otherwise the negative errno.
+    fn completion(&mut self, cqe: &io_uring::cqueue::Entry) -> Result<usize, OpError> {
+        self.owner = Owner::Returned;
+        match cqe.result() {
+            res if res < 0 => Err(OpError::from_cqe_result("WriteFixed", res)),
+            res => Ok(res as usize),
+        }
+    }
+}
+
+impl OpCode<WriteFixed> for WriteFixed {
+    fn submission(self) -> Result<WriteFixed, OpError> {
+        Ok(self)
+    }
+}
+
+impl OpExtRw for WriteFixed {
+    fn fd(&self) -> FdRef {
+        self.fd
+    }
+    fn offset(&self) -> u64 {
+        self.offset
+    }
+    fn len(&self) -> usize {
+        self.buf.len()
+    }
+    fn rw_flags(&self) -> i32 {
+        self.rw_flags
+    }
+    fn is_write(&self) -> bool {
+        true
+    }
+    fn fixed_buf(&self) -> Option<(usize, u16)> {
+        Some((self.buf_idx, self.buf_kernel_index))
+    }
+}
@@ -0,0 +1,90 @@
+use std::net::{IpAddr, Ipv4Addr, SocketAddr, UdpSocket};
+use std::num::NonZero;
+
+use ysockaddr::YSockAddrR;
+
+use io_uring_bearer::Completion;
+use io_uring_bearer::UringBearer;
+
+use capacity::{Capacity, Setting};
+use io_uring_bearer::io_uring_fd::{FdKind, RegisteredFd};
+use io_uring_bearer::BearerCapacityKind;
+
+use io_uring_op_send::SendMsg;
+
+#[derive(Clone, Debug)]
+pub struct MyCapacity;
+
+impl Setting<BearerCapacityKind> for MyCapacity {
+    fn setting(&self, v: &BearerCapacityKind) -> usize {
+        match v {
+            BearerCapacityKind::CoreQueue => 2,
+            BearerCapacityKind::RegisteredFd => 1,
+            BearerCapacityKind::PendingCompletions => 2,
+            BearerCapacityKind::Buffers => 1,
+            BearerCapacityKind::Futexes => 0,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct UserData {
+    sent: usize,
+    completed: u32,
+}
+
+fn main() {
+    // Bring up a receiver
+    let receiver = UdpSocket::bind("127.0.0.1:0").unwrap();
+    let receiver_port = receiver.local_addr().unwrap().port();
+    let dest = YSockAddrR::from_sockaddr(SocketAddr::new(
+        IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1)),
+        receiver_port,
+    ));
+
+    let my_cap = Capacity::<MyCapacity, BearerCapacityKind>::with_planned(MyCapacity {});
+    let mut bearer = UringBearer::with_capacity(my_cap).unwrap();
+
+    let sock = unsafe { libc::socket(libc::AF_INET, libc::SOCK_DGRAM, 0) };
+    let slot = bearer
+        .add_registered_fd(RegisteredFd::from_raw(sock, FdKind::Send))
+        .unwrap();
+    bearer.commit_registered_init().unwrap();
+
+    // Bearer managed buffer sent through SendMsg together with an owned one
+    let buf_idx = bearer.create_buffers(NonZero::new(1).unwrap(), 5).unwrap();
+    bearer
+        .buffer_prepare_fill(buf_idx)
+        .unwrap()
+        .copy_from_slice(b"hello");
+    let taken = bearer.take_one_immutable_buffer(buf_idx, 0).unwrap();
+
+    let send_msg = SendMsg::new(slot, vec![taken.into(), b" world".to_vec().into()])
+        .unwrap()
+        .with_dest(dest.as_c());
+    bearer.push_send(send_msg, None).unwrap();
+
+    bearer.submit_and_wait(1).unwrap();
+
+    let mut user = UserData::default();
+    bearer
+        .typed_completions(&mut user, |user, entry, rec, res| match rec {
+            Completion::Send(_) => {
+                println!("Send Q<{:?}> = {:?}", entry, res);
+                user.sent += res.unwrap().unwrap();
+                user.completed += 1;
+            }
+            _ => panic!("Queue had something else than SendMsg?"),
+        })
+        .unwrap();
+    assert_eq!(user.completed, 1);
+    assert_eq!(user.sent, 11);
+
+    let mut recv_buf = [0u8; 32];
+    let len = receiver.recv(&mut recv_buf).unwrap();
+    assert_eq!(&recv_buf[..len], b"hello world");
+    println!("std UdpSocket received the datagram");
+
+    // The bearer managed buffer is returned for re-use upon completion
+    bearer.take_one_immutable_buffer(buf_idx, 0).unwrap();
+}
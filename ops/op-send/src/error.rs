@@ -0,0 +1,35 @@
+//! Send op Errors
+
+use core::fmt;
+use core::fmt::Display;
+
+use io_uring_opcode::OpError;
+
+/// Send Errors
+#[derive(Debug)]
+pub enum SendError {
+    /// Buffer is longer than the u32 length the kernel accepts
+    BufferTooLong(usize),
+    /// SendMsg needs at least one and at most UIO_MAXIOV buffers
+    InvalidIovecCount(usize),
+}
+
+impl Display for SendError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::BufferTooLong(len) => write!(f, "Buffer of {} bytes is too long", len),
+            Self::InvalidIovecCount(c) => write!(f, "Invalid count of {} iovecs", c),
+        }
+    }
+}
+
+impl From<SendError> for OpError {
+    fn from(e: SendError) -> OpError {
+        match e {
+            SendError::BufferTooLong(_) => OpError::InvalidParameter("Buffer is too long"),
+            SendError::InvalidIovecCount(_) => OpError::InvalidParameter("Invalid count of iovecs"),
+        }
+    }
+}
+
+impl std::error::Error for SendError {}
@@ -0,0 +1,33 @@
+#![warn(
+    clippy::unwrap_used,
+    missing_docs,
+    rust_2018_idioms,
+    unused_lifetimes,
+    unused_qualifications
+)]
+#![doc = include_str!("../README.md")]
+
+//-----------------------------------------------
+// All Errors
+//-----------------------------------------------
+mod error;
+#[doc(inline)]
+pub use error::*;
+
+//-----------------------------------------------
+// Buffer Types
+//-----------------------------------------------
+mod send_buf;
+pub use send_buf::SendBuf;
+
+//-----------------------------------------------
+// Send Record Types
+//-----------------------------------------------
+mod send;
+pub use send::Send;
+
+//-----------------------------------------------
+// SendMsg Record Types
+//-----------------------------------------------
+mod send_msg;
+pub use send_msg::SendMsg;
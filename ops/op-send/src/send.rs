@@ -0,0 +1,137 @@
+//! Send Record
+
+use crate::error::SendError;
+use crate::SendBuf;
+
+use io_uring_opcode::OpExtSend;
+use io_uring_opcode::{OpCode, OpCompletion, OpError};
+use io_uring_owner::Owner;
+
+use ysockaddr::YSockAddrC;
+
+/// Send Record
+#[derive(Clone, Debug)]
+pub struct Send {
+    /// Current owner of the record
+    owner: Owner,
+    /// Related filehandle
+    fixed_fd: u32,
+    buf: SendBuf,
+    buf_idx: Option<usize>,
+    dest: Option<YSockAddrC>,
+    msg_flags: i32,
+}
+
+impl Send {
+    /// Construct a new Send of the given buffer
+    pub fn new<B: Into<SendBuf>>(fixed_fd: u32, buf: B) -> Result<Self, SendError> {
+        let buf = buf.into();
+        if buf.len() > u32::MAX as usize {
+            return Err(SendError::BufferTooLong(buf.len()));
+        }
+        Ok(Send {
+            owner: Owner::Created,
+            fixed_fd,
+            buf_idx: buf.buf_idx(),
+            buf,
+            dest: None,
+            msg_flags: 0,
+        })
+    }
+    /// Send to the given destination, turning send(2) into sendto(2)
+    pub fn with_dest(mut self, dest: YSockAddrC) -> Self {
+        self.dest = Some(dest);
+        self
+    }
+    /// Set the MSG_* flags e.g. libc::MSG_DONTWAIT
+    pub fn with_msg_flags(mut self, msg_flags: i32) -> Self {
+        self.msg_flags = msg_flags;
+        self
+    }
+    /// More data will follow, MSG_MORE
+    pub fn more(mut self) -> Self {
+        self.msg_flags |= libc::MSG_MORE;
+        self
+    }
+    /// Do not raise SIGPIPE on a broken stream, MSG_NOSIGNAL
+    pub fn no_signal(mut self) -> Self {
+        self.msg_flags |= libc::MSG_NOSIGNAL;
+        self
+    }
+    /// Underlying buffer
+    pub fn buf(&self) -> &SendBuf {
+        &self.buf
+    }
+}
+
+impl OpCompletion for Send {
+    type Error = OpError;
+    type Output = usize;
+    fn entry(&self) -> io_uring::squeue::Entry {
+        let (dest_addr, dest_addr_len) = match &self.dest {
+            Some(dest) => dest.as_c_sockaddr_len(),
+            None => (core::ptr::null(), 0),
+        };
+        io_uring::opcode::Send::new(
+            io_uring::types::Fixed(self.fixed_fd),
+            self.buf.as_ptr(),
+            self.buf.len() as u32,
+        )
+        .flags(self.msg_flags)
+        .dest_addr(dest_addr)
+        .dest_addr_len(dest_addr_len)
+        .build()
+    }
+    fn opcode(&self) -> u8 {
+        io_uring::opcode::Send::CODE
+    }
+    fn owner(&self) -> Owner {
+        self.owner.clone()
+    }
+    fn force_owner_kernel(&mut self) -> bool {
+        self.owner = Owner::Kernel;
+        true
+    }
+    fn fixed_fd(&self) -> Option<u32> {
+        Some(self.fixed_fd)
+    }
+    fn buf_idxs(&self) -> &[usize] {
+        match &self.buf_idx {
+            Some(buf_idx) => core::slice::from_ref(buf_idx),
+            None => &[],
+        }
+    }
+    /// Count of bytes sent, otherwise the negative errno.
+    fn completion(&mut self, cqe: &io_uring::cqueue::Entry) -> Result<usize, OpError> {
+        self.owner = Owner::Returned;
+        match cqe.result() {
+            res if res < 0 => Err(OpError::from_cqe_result("Send", res)),
+            res => Ok(res as usize),
+        }
+    }
+}
+
+impl OpCode<Send> for Send {
+    fn submission(self) -> Result<Send, OpError> {
+        Ok(self)
+    }
+}
+
+impl OpExtSend for Send {
+    /// Underlying Fixed Fd
+    fn fixed_fd(&self) -> u32 {
+        self.fixed_fd
+    }
+    /// Length of the buffer
+    fn len(&self) -> usize {
+        self.buf.len()
+    }
+    /// Underlying destination YSockAddrC
+    fn dest(&self) -> Option<&YSockAddrC> {
+        self.dest.as_ref()
+    }
+    /// Underlying MSG_* flags
+    fn msg_flags(&self) -> i32 {
+        self.msg_flags
+    }
+}
@@ -0,0 +1,56 @@
+//! Send Buffer
+
+use io_uring_bearer::slab::TakenImmutableBuffer;
+
+/// Data to be sent, held by the record until the completion
+#[derive(Clone, Debug)]
+pub enum SendBuf {
+    /// Bearer managed buffer taken via UringBearer::take_one_immutable_buffer
+    Bearer(TakenImmutableBuffer),
+    /// Owned buffer
+    Owned(Vec<u8>),
+}
+
+impl SendBuf {
+    /// Raw pointer to the start of the data
+    #[inline]
+    pub fn as_ptr(&self) -> *const u8 {
+        match self {
+            Self::Bearer(b) => b.as_ptr(),
+            Self::Owned(v) => v.as_ptr(),
+        }
+    }
+    /// Length of the data
+    #[inline]
+    pub fn len(&self) -> usize {
+        match self {
+            Self::Bearer(b) => b.buf_size() as usize,
+            Self::Owned(v) => v.len(),
+        }
+    }
+    /// Whether there is no data
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    /// Index of the bearer managed buffer if any
+    #[inline]
+    pub fn buf_idx(&self) -> Option<usize> {
+        match self {
+            Self::Bearer(b) => Some(b.buf_idx()),
+            Self::Owned(_) => None,
+        }
+    }
+}
+
+impl From<Vec<u8>> for SendBuf {
+    fn from(v: Vec<u8>) -> Self {
+        Self::Owned(v)
+    }
+}
+
+impl From<TakenImmutableBuffer> for SendBuf {
+    fn from(b: TakenImmutableBuffer) -> Self {
+        Self::Bearer(b)
+    }
+}
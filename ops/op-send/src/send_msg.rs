@@ -0,0 +1,203 @@
+//! SendMsg Record
+
+use crate::error::SendError;
+use crate::SendBuf;
+
+use io_uring_opcode::OpExtSend;
+use io_uring_opcode::{OpCode, OpCompletion, OpError};
+use io_uring_owner::Owner;
+
+use ysockaddr::YSockAddrC;
+
+/// Max iovecs accepted by sendmsg(2)
+const UIO_MAXIOV: usize = 1024;
+
+/// SendMsg Record
+///
+/// The msghdr refers to the iovecs and the destination which are all kept
+/// behind stable heap addresses so the record can be moved until submitted.
+#[derive(Debug)]
+pub struct SendMsg {
+    /// Current owner of the record
+    owner: Owner,
+    /// Related filehandle
+    fixed_fd: u32,
+    bufs: Vec<SendBuf>,
+    buf_idxs: Vec<usize>,
+    dest: Option<Box<YSockAddrC>>,
+    msg_flags: i32,
+    iovecs: Vec<libc::iovec>,
+    msghdr: Box<libc::msghdr>,
+}
+
+impl SendMsg {
+    /// Construct a new SendMsg of the given buffers sent in the given order
+    pub fn new(fixed_fd: u32, bufs: Vec<SendBuf>) -> Result<Self, SendError> {
+        if bufs.is_empty() || bufs.len() > UIO_MAXIOV {
+            return Err(SendError::InvalidIovecCount(bufs.len()));
+        }
+        Ok(Self::with_parts(fixed_fd, bufs, None, 0))
+    }
+    fn with_parts(
+        fixed_fd: u32,
+        bufs: Vec<SendBuf>,
+        dest: Option<Box<YSockAddrC>>,
+        msg_flags: i32,
+    ) -> Self {
+        let iovecs = bufs
+            .iter()
+            .map(|b| libc::iovec {
+                iov_base: b.as_ptr() as *mut libc::c_void,
+                iov_len: b.len(),
+            })
+            .collect();
+        let mut s = SendMsg {
+            owner: Owner::Created,
+            fixed_fd,
+            buf_idxs: bufs.iter().filter_map(|b| b.buf_idx()).collect(),
+            bufs,
+            dest,
+            msg_flags,
+            iovecs,
+            // SAFETY: All-zero msghdr is valid, the pointers are set below
+            msghdr: Box::new(unsafe { core::mem::zeroed() }),
+        };
+        s.set_msghdr();
+        s
+    }
+    fn set_msghdr(&mut self) {
+        self.msghdr.msg_iov = self.iovecs.as_mut_ptr();
+        self.msghdr.msg_iovlen = self.iovecs.len() as _;
+        match &self.dest {
+            Some(dest) => {
+                let (saddr, slen) = dest.as_c_sockaddr_len();
+                self.msghdr.msg_name = saddr as *mut libc::c_void;
+                self.msghdr.msg_namelen = slen;
+            }
+            None => {
+                self.msghdr.msg_name = core::ptr::null_mut();
+                self.msghdr.msg_namelen = 0;
+            }
+        }
+    }
+    /// Send to the given destination for unconnected sockets
+    pub fn with_dest(mut self, dest: YSockAddrC) -> Self {
+        self.dest = Some(Box::new(dest));
+        self.set_msghdr();
+        self
+    }
+    /// Set the MSG_* flags e.g. libc::MSG_DONTWAIT
+    pub fn with_msg_flags(mut self, msg_flags: i32) -> Self {
+        self.msg_flags = msg_flags;
+        self
+    }
+    /// More data will follow, MSG_MORE
+    pub fn more(mut self) -> Self {
+        self.msg_flags |= libc::MSG_MORE;
+        self
+    }
+    /// Do not raise SIGPIPE on a broken stream, MSG_NOSIGNAL
+    pub fn no_signal(mut self) -> Self {
+        self.msg_flags |= libc::MSG_NOSIGNAL;
+        self
+    }
+    /// Underlying buffers
+    pub fn bufs(&self) -> &[SendBuf] {
+        &self.bufs
+    }
+}
+
+impl Clone for SendMsg {
+    /// The msghdr of the clone refers to the cloned iovecs and destination
+    fn clone(&self) -> Self {
+        let mut s = Self::with_parts(
+            self.fixed_fd,
+            self.bufs.clone(),
+            self.dest.clone(),
+            self.msg_flags,
+        );
+        s.owner = self.owner.clone();
+        s
+    }
+}
+
+impl OpCompletion for SendMsg {
+    type Error = OpError;
+    type Output = usize;
+    fn entry(&self) -> io_uring::squeue::Entry {
+        io_uring::opcode::SendMsg::new(io_uring::types::Fixed(self.fixed_fd), &*self.msghdr)
+            .flags(self.msg_flags as u32)
+            .build()
+    }
+    fn opcode(&self) -> u8 {
+        io_uring::opcode::SendMsg::CODE
+    }
+    fn owner(&self) -> Owner {
+        self.owner.clone()
+    }
+    fn force_owner_kernel(&mut self) -> bool {
+        self.owner = Owner::Kernel;
+        true
+    }
+    fn fixed_fd(&self) -> Option<u32> {
+        Some(self.fixed_fd)
+    }
+    fn buf_idxs(&self) -> &[usize] {
+        &self.buf_idxs
+    }
+    /// Count of bytes sent, otherwise the negative errno.
+    fn completion(&mut self, cqe: &io_uring::cqueue::Entry) -> Result<usize, OpError> {
+        self.owner = Owner::Returned;
+        match cqe.result() {
+            res if res < 0 => Err(OpError::from_cqe_result("SendMsg", res)),
+            res => Ok(res as usize),
+        }
+    }
+}
+
+impl OpCode<SendMsg> for SendMsg {
+    fn submission(self) -> Result<SendMsg, OpError> {
+        Ok(self)
+    }
+}
+
+impl OpExtSend for SendMsg {
+    /// Underlying Fixed Fd
+    fn fixed_fd(&self) -> u32 {
+        self.fixed_fd
+    }
+    /// Total length of the buffers
+    fn len(&self) -> usize {
+        self.bufs.iter().map(|b| b.len()).sum()
+    }
+    /// Underlying destination YSockAddrC
+    fn dest(&self) -> Option<&YSockAddrC> {
+        self.dest.as_deref()
+    }
+    /// Underlying MSG_* flags
+    fn msg_flags(&self) -> i32 {
+        self.msg_flags
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn msghdr_follows_clone() {
+        let orig = SendMsg::new(0, vec![vec![1, 2].into(), vec![3].into()]).expect("SendMsg");
+        let cloned = orig.clone();
+        assert_eq!(cloned.msghdr.msg_iov, cloned.iovecs.as_ptr() as *mut _);
+        assert_ne!(cloned.msghdr.msg_iov, orig.msghdr.msg_iov);
+        assert_eq!(cloned.msghdr.msg_iovlen, 2);
+        assert_eq!(OpExtSend::len(&cloned), 3);
+    }
+    #[test]
+    fn iovec_count() {
+        assert!(matches!(
+            SendMsg::new(0, vec![]),
+            Err(SendError::InvalidIovecCount(0))
+        ));
+    }
+}
@@ -2,8 +2,10 @@ use io_uring_bearer::Completion;
 use io_uring_bearer::UringBearer;
 
 use capacity::{Capacity, Setting};
+use io_uring_bearer::io_uring_fd::{FdKind, RegisteredFd};
 use io_uring_bearer::BearerCapacityKind;
 
+use io_uring_op_socket::CompletedFd;
 use io_uring_op_socket::Socket;
 use io_uring_op_socket::TargetFdType;
 use io_uring_opcode::OpExtSocket;
@@ -15,7 +17,7 @@ impl Setting<BearerCapacityKind> for MyCapacity {
     fn setting(&self, v: &BearerCapacityKind) -> usize {
         match v {
             BearerCapacityKind::CoreQueue => 1,
-            BearerCapacityKind::RegisteredFd => 1,
+            BearerCapacityKind::RegisteredFd => 3,
             BearerCapacityKind::PendingCompletions => 1,
             BearerCapacityKind::Buffers => 0,
             BearerCapacityKind::Futexes => 0,
@@ -29,15 +31,15 @@ fn main() {
 
     // Make room for three fixed Fd where we will assign the 3rd or no 2 (first entry is zero idx) manually
     bearer
-        .io_uring()
-        .submitter()
-        .register_files(&[1, -1, -1])
+        .add_registered_fd(RegisteredFd::from_raw(1, FdKind::Send))
         .unwrap();
+    bearer.commit_registered_init().unwrap();
 
     let _op_idx = bearer
         .push_socket(
             Socket::with_fixed_fd(Some(2), libc::AF_INET, libc::SOCK_STREAM, libc::IPPROTO_TCP)
                 .unwrap(),
+            None,
         )
         .unwrap();
 
@@ -53,12 +55,13 @@ fn main() {
 
     loop {
         bearer
-            .completions(&mut user, |user, entry, rec| match rec {
+            .typed_completions(&mut user, |user, entry, rec, res| match rec {
                 Completion::Socket(c) => {
                     user.e += 1;
                     println!("Socketed Q<{:?}> ", entry,);
                     // no error - manual assignments always return zero for no error here
                     assert_eq!(entry.result(), 0);
+                    assert_eq!(res.unwrap().unwrap(), CompletedFd::Fixed(2));
                     assert_eq!(c.target_fd(), TargetFdType::FixedManual(2));
                     assert_eq!(c.domain(), libc::AF_INET);
                     assert_eq!(c.socket_type(), libc::SOCK_STREAM);
@@ -69,6 +72,8 @@ fn main() {
             .unwrap();
 
         if user.e != 0 {
+            // The kernel installed slot is tracked as registered
+            assert!(bearer.registered_fd(2).unwrap().is_kernel_installed());
             break;
         }
 
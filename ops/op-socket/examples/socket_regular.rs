@@ -4,6 +4,7 @@ use io_uring_bearer::UringBearer;
 use capacity::{Capacity, Setting};
 use io_uring_bearer::BearerCapacityKind;
 
+use io_uring_op_socket::CompletedFd;
 use io_uring_op_socket::Socket;
 use io_uring_op_socket::TargetFdType;
 use io_uring_opcode::OpExtSocket;
@@ -30,6 +31,7 @@ fn main() {
     let _op_idx = bearer
         .push_socket(
             Socket::with_regular_fd(libc::AF_INET, libc::SOCK_STREAM, libc::IPPROTO_TCP).unwrap(),
+            None,
         )
         .unwrap();
 
@@ -45,12 +47,13 @@ fn main() {
 
     loop {
         bearer
-            .completions(&mut user, |user, entry, rec| match rec {
+            .typed_completions(&mut user, |user, entry, rec, res| match rec {
                 Completion::Socket(c) => {
                     user.e += 1;
                     println!("Socketed Q<{:?}> ", entry,);
                     // no error - return here is kernel assigned filehandle number
                     assert!(entry.result() > 0);
+                    assert_eq!(res.unwrap().unwrap(), CompletedFd::Regular(entry.result()));
                     assert_eq!(c.target_fd(), TargetFdType::Regular);
                     assert_eq!(c.domain(), libc::AF_INET);
                     assert_eq!(c.socket_type(), libc::SOCK_STREAM);
@@ -3,6 +3,8 @@
 use core::fmt;
 use core::fmt::Display;
 
+use io_uring_opcode::OpError;
+
 /// Socket Errors
 #[derive(Debug)]
 pub enum SocketError {
@@ -11,8 +13,18 @@ pub enum SocketError {
 }
 
 impl Display for SocketError {
-    fn fmt(&self, _: &mut fmt::Formatter<'_>) -> fmt::Result {
-        todo!()
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidTarget(slot) => write!(f, "Invalid fixed fd target slot {}", slot),
+        }
+    }
+}
+
+impl From<SocketError> for OpError {
+    fn from(e: SocketError) -> OpError {
+        match e {
+            SocketError::InvalidTarget(slot) => OpError::InvalidTarget(slot),
+        }
     }
 }
 
@@ -25,6 +25,8 @@ pub use socket::Socket;
 // Re-export associated types
 //-----------------------------------------------
 #[doc(inline)]
+pub use io_uring_opcode::types::CompletedFd;
+#[doc(inline)]
 pub use io_uring_opcode::types::TargetFdType;
 
 //-----------------------------------------------
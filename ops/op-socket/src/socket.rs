@@ -1,10 +1,8 @@
 //! Socket Record
 
-use core::pin::Pin;
-
 use crate::error::SocketError;
 
-use io_uring_opcode::types::TargetFdType;
+use io_uring_opcode::types::{CompletedFd, TargetFdType};
 use io_uring_opcode::OpExtSocket;
 use io_uring_opcode::{OpCode, OpCompletion, OpError};
 use io_uring_owner::Owner;
@@ -78,11 +76,15 @@ impl Socket {
 
 impl OpCompletion for Socket {
     type Error = OpError;
+    type Output = CompletedFd;
     fn entry(&self) -> io_uring::squeue::Entry {
         io_uring::opcode::Socket::new(self.domain, self.socket_type, self.protocol)
             .file_index(self.target_destination_slot)
             .build()
     }
+    fn opcode(&self) -> u8 {
+        io_uring::opcode::Socket::CODE
+    }
     fn owner(&self) -> Owner {
         self.owner.clone()
     }
@@ -90,15 +92,26 @@ impl OpCompletion for Socket {
         self.owner = Owner::Kernel;
         true
     }
+    /// Regular and automatically assigned fixed filehandles are returned in the result
+    /// whilst the manually pinned slot is returned as zero upon success.
+    fn completion(&mut self, cqe: &io_uring::cqueue::Entry) -> Result<CompletedFd, OpError> {
+        self.owner = Owner::Returned;
+        let res = cqe.result();
+        if res < 0 {
+            return Err(OpError::from_cqe_result("Socket", res));
+        }
+        match self.target_fd_type {
+            TargetFdType::Regular => Ok(CompletedFd::Regular(res)),
+            TargetFdType::FixedAuto => Ok(CompletedFd::Fixed(res as u32)),
+            TargetFdType::FixedManual(slot) => Ok(CompletedFd::Fixed(slot)),
+        }
+    }
 }
 
 impl OpCode<Socket> for Socket {
     fn submission(self) -> Result<Socket, OpError> {
         Ok(self)
     }
-    fn completion(&mut self, _: Pin<&mut Socket>) -> Result<(), OpError> {
-        todo!()
-    }
 }
 
 impl OpExtSocket for Socket {
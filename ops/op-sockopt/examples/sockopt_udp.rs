@@ -0,0 +1,159 @@
+use std::net::UdpSocket;
+
+use io_uring_bearer::Completion;
+use io_uring_bearer::UringBearer;
+
+use capacity::{Capacity, Setting};
+use io_uring_bearer::BearerCapacityKind;
+
+use io_uring_fd::FdKind;
+use io_uring_op_sockopt::{GetSockOpt, QueuedBytes, SetSockOpt, SockOpt, SockOptName, SockQueue};
+use io_uring_opcode::{OpCode, OpCompletion, OpError};
+use io_uring_owner::Owner;
+
+#[derive(Clone, Debug)]
+pub struct MyCapacity;
+
+impl Setting<BearerCapacityKind> for MyCapacity {
+    fn setting(&self, v: &BearerCapacityKind) -> usize {
+        match v {
+            BearerCapacityKind::CoreQueue => 4,
+            BearerCapacityKind::RegisteredFd => 1,
+            BearerCapacityKind::PendingCompletions => 4,
+            BearerCapacityKind::Buffers => 0,
+            BearerCapacityKind::Futexes => 0,
+        }
+    }
+}
+
+/// The socket option OpCodes within the one bearer
+#[derive(Clone, Debug)]
+enum SockOptOp {
+    Set(SetSockOpt),
+    Get(GetSockOpt),
+    Queued(QueuedBytes),
+}
+
+#[derive(Debug)]
+enum SockOptOutput {
+    Set,
+    Get(SockOpt),
+    Queued(usize),
+}
+
+impl OpCompletion for SockOptOp {
+    type Error = OpError;
+    type Output = SockOptOutput;
+    fn entry(&self) -> io_uring::squeue::Entry {
+        match self {
+            Self::Set(i) => i.entry(),
+            Self::Get(i) => i.entry(),
+            Self::Queued(i) => i.entry(),
+        }
+    }
+    fn opcode(&self) -> u8 {
+        match self {
+            Self::Set(i) => i.opcode(),
+            Self::Get(i) => i.opcode(),
+            Self::Queued(i) => i.opcode(),
+        }
+    }
+    fn owner(&self) -> Owner {
+        match self {
+            Self::Set(i) => i.owner(),
+            Self::Get(i) => i.owner(),
+            Self::Queued(i) => i.owner(),
+        }
+    }
+    fn force_owner_kernel(&mut self) -> bool {
+        match self {
+            Self::Set(i) => i.force_owner_kernel(),
+            Self::Get(i) => i.force_owner_kernel(),
+            Self::Queued(i) => i.force_owner_kernel(),
+        }
+    }
+    fn completion(&mut self, cqe: &io_uring::cqueue::Entry) -> Result<Self::Output, OpError> {
+        match self {
+            Self::Set(i) => i.completion(cqe).map(|_| SockOptOutput::Set),
+            Self::Get(i) => i.completion(cqe).map(SockOptOutput::Get),
+            Self::Queued(i) => i.completion(cqe).map(SockOptOutput::Queued),
+        }
+    }
+}
+
+impl OpCode<SockOptOp> for SetSockOpt {
+    fn submission(self) -> Result<SockOptOp, OpError> {
+        Ok(SockOptOp::Set(self))
+    }
+}
+
+impl OpCode<SockOptOp> for GetSockOpt {
+    fn submission(self) -> Result<SockOptOp, OpError> {
+        Ok(SockOptOp::Get(self))
+    }
+}
+
+impl OpCode<SockOptOp> for QueuedBytes {
+    fn submission(self) -> Result<SockOptOp, OpError> {
+        Ok(SockOptOp::Queued(self))
+    }
+}
+
+/// Push the single op and wait for its output
+fn round_trip<Op: OpCode<SockOptOp> + io_uring_opcode::OpExtSockOpt>(
+    bearer: &mut UringBearer<SockOptOp>,
+    op: Op,
+) -> SockOptOutput {
+    bearer.push_sockopt(op, None).unwrap();
+    bearer.submit_and_wait(1).unwrap();
+
+    let mut output = None;
+    bearer
+        .typed_completions(&mut output, |output, entry, rec, res| match rec {
+            Completion::SockOpt(_) => {
+                println!("Q<{:?}> = {:?}", entry, res);
+                *output = Some(res.unwrap().unwrap());
+            }
+            _ => panic!("Queue had something else than SockOpt?"),
+        })
+        .unwrap();
+    output.unwrap()
+}
+
+fn main() {
+    let receiver = UdpSocket::bind("127.0.0.1:0").unwrap();
+    let sender = UdpSocket::bind("127.0.0.1:0").unwrap();
+
+    let my_cap = Capacity::<MyCapacity, BearerCapacityKind>::with_planned(MyCapacity {});
+    let mut bearer: UringBearer<SockOptOp> = UringBearer::with_capacity(my_cap).unwrap();
+    bearer.commit_registered_init().unwrap();
+    let slot = bearer.register_fd(&receiver, FdKind::Recv).unwrap();
+
+    round_trip(&mut bearer, SetSockOpt::new(slot, SockOpt::ReuseAddr(true)));
+    match round_trip(
+        &mut bearer,
+        GetSockOpt::new(slot, SockOptName::ReuseAddr).unwrap(),
+    ) {
+        SockOptOutput::Get(opt) => assert_eq!(opt, SockOpt::ReuseAddr(true)),
+        other => panic!("Unexpected {:?}", other),
+    }
+
+    // The kernel doubles the requested buffer size for its bookkeeping
+    round_trip(&mut bearer, SetSockOpt::new(slot, SockOpt::RcvBuf(8192)));
+    match round_trip(
+        &mut bearer,
+        GetSockOpt::new(slot, SockOptName::RcvBuf).unwrap(),
+    ) {
+        SockOptOutput::Get(opt) => assert_eq!(opt, SockOpt::RcvBuf(16384)),
+        other => panic!("Unexpected {:?}", other),
+    }
+
+    sender
+        .send_to(b"hello", receiver.local_addr().unwrap())
+        .unwrap();
+    match round_trip(&mut bearer, QueuedBytes::new(slot, SockQueue::Inbound)) {
+        SockOptOutput::Queued(queued) => assert_eq!(queued, 5),
+        other => panic!("Unexpected {:?}", other),
+    }
+    println!("Receiver has 5 bytes pending");
+}
@@ -0,0 +1,36 @@
+//! Socket option op Errors
+
+use core::fmt;
+use core::fmt::Display;
+
+use crate::SockOptName;
+use io_uring_opcode::OpError;
+
+/// Socket option Errors
+#[derive(Debug)]
+pub enum SockOptError {
+    /// The kernel only supports getting SOL_SOCKET level options through io_uring
+    GetNotSolSocket(SockOptName),
+}
+
+impl Display for SockOptError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::GetNotSolSocket(name) => {
+                write!(f, "Getting {:?} requires a SOL_SOCKET level option", name)
+            }
+        }
+    }
+}
+
+impl From<SockOptError> for OpError {
+    fn from(e: SockOptError) -> OpError {
+        match e {
+            SockOptError::GetNotSolSocket(_) => {
+                OpError::InvalidParameter("GetSockOpt requires a SOL_SOCKET level option")
+            }
+        }
+    }
+}
+
+impl std::error::Error for SockOptError {}
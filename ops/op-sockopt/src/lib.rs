@@ -0,0 +1,67 @@
+#![warn(
+    clippy::unwrap_used,
+    missing_docs,
+    rust_2018_idioms,
+    unused_lifetimes,
+    unused_qualifications
+)]
+#![doc = include_str!("../README.md")]
+
+//-----------------------------------------------
+// All Errors
+//-----------------------------------------------
+mod error;
+#[doc(inline)]
+pub use error::*;
+
+//-----------------------------------------------
+// Typed socket options
+//-----------------------------------------------
+mod opt;
+pub use opt::{SockOpt, SockOptName};
+
+//-----------------------------------------------
+// SetSockOpt / GetSockOpt Record Types
+//-----------------------------------------------
+mod sockopt;
+pub use sockopt::{GetSockOpt, SetSockOpt};
+
+//-----------------------------------------------
+// SIOCINQ / SIOCOUTQ Record Types
+//-----------------------------------------------
+mod queued;
+pub use queued::{QueuedBytes, SockQueue};
+
+//-----------------------------------------------
+// Misc crate-wide private types
+//-----------------------------------------------
+
+// include/uapi/linux/io_uring.h socket command ops, not exposed by io-uring
+pub(crate) const SOCKET_URING_OP_SIOCINQ: u32 = 0;
+pub(crate) const SOCKET_URING_OP_SIOCOUTQ: u32 = 1;
+pub(crate) const SOCKET_URING_OP_GETSOCKOPT: u32 = 2;
+pub(crate) const SOCKET_URING_OP_SETSOCKOPT: u32 = 3;
+
+/// UringCmd16 socket command carrying the option level, name and value buffer.
+#[inline]
+pub(crate) fn sockopt_entry(
+    fixed_fd: u32,
+    cmd_op: u32,
+    (level, optname): (i32, i32),
+    optval: *const libc::c_void,
+    optlen: u32,
+) -> io_uring::squeue::Entry {
+    let mut cmd = [0u8; 16];
+    cmd[0..8].copy_from_slice(&(optval as u64).to_ne_bytes());
+    let entry = io_uring::opcode::UringCmd16::new(io_uring::types::Fixed(fixed_fd), cmd_op)
+        .cmd(cmd)
+        .build();
+    // SAFETY: Entry is repr(C) over the 64 byte io_uring_sqe of which UringCmd16 leaves the
+    // level (offset 16), optname (offset 20) and optlen (offset 44) of the socket commands unset.
+    let mut sqe: [u8; 64] = unsafe { core::mem::transmute(entry) };
+    sqe[16..20].copy_from_slice(&(level as u32).to_ne_bytes());
+    sqe[20..24].copy_from_slice(&(optname as u32).to_ne_bytes());
+    sqe[44..48].copy_from_slice(&optlen.to_ne_bytes());
+    // SAFETY: As above, any bit pattern is a valid io_uring_sqe
+    unsafe { core::mem::transmute::<[u8; 64], io_uring::squeue::Entry>(sqe) }
+}
@@ -0,0 +1,161 @@
+//! Typed socket options
+
+/// Socket option names supported by SetSockOpt / GetSockOpt
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SockOptName {
+    /// SOL_SOCKET SO_REUSEADDR
+    ReuseAddr,
+    /// SOL_SOCKET SO_REUSEPORT
+    ReusePort,
+    /// SOL_SOCKET SO_KEEPALIVE
+    KeepAlive,
+    /// SOL_SOCKET SO_BROADCAST
+    Broadcast,
+    /// SOL_SOCKET SO_RCVBUF
+    RcvBuf,
+    /// SOL_SOCKET SO_SNDBUF
+    SndBuf,
+    /// SOL_SOCKET SO_ERROR, get only
+    Error,
+    /// IPPROTO_TCP TCP_NODELAY
+    TcpNoDelay,
+    /// IPPROTO_TCP TCP_KEEPIDLE
+    TcpKeepIdle,
+    /// IPPROTO_TCP TCP_KEEPINTVL
+    TcpKeepIntvl,
+    /// IPPROTO_TCP TCP_KEEPCNT
+    TcpKeepCnt,
+    /// IPPROTO_IPV6 IPV6_V6ONLY
+    Ipv6Only,
+}
+
+impl SockOptName {
+    /// The (level, optname) pair given to the kernel
+    pub fn level_optname(&self) -> (i32, i32) {
+        match self {
+            Self::ReuseAddr => (libc::SOL_SOCKET, libc::SO_REUSEADDR),
+            Self::ReusePort => (libc::SOL_SOCKET, libc::SO_REUSEPORT),
+            Self::KeepAlive => (libc::SOL_SOCKET, libc::SO_KEEPALIVE),
+            Self::Broadcast => (libc::SOL_SOCKET, libc::SO_BROADCAST),
+            Self::RcvBuf => (libc::SOL_SOCKET, libc::SO_RCVBUF),
+            Self::SndBuf => (libc::SOL_SOCKET, libc::SO_SNDBUF),
+            Self::Error => (libc::SOL_SOCKET, libc::SO_ERROR),
+            Self::TcpNoDelay => (libc::IPPROTO_TCP, libc::TCP_NODELAY),
+            Self::TcpKeepIdle => (libc::IPPROTO_TCP, libc::TCP_KEEPIDLE),
+            Self::TcpKeepIntvl => (libc::IPPROTO_TCP, libc::TCP_KEEPINTVL),
+            Self::TcpKeepCnt => (libc::IPPROTO_TCP, libc::TCP_KEEPCNT),
+            Self::Ipv6Only => (libc::IPPROTO_IPV6, libc::IPV6_V6ONLY),
+        }
+    }
+    /// Whether the option is at the SOL_SOCKET level
+    pub fn is_sol_socket(&self) -> bool {
+        self.level_optname().0 == libc::SOL_SOCKET
+    }
+}
+
+/// Socket option together with its value
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SockOpt {
+    /// SOL_SOCKET SO_REUSEADDR
+    ReuseAddr(bool),
+    /// SOL_SOCKET SO_REUSEPORT
+    ReusePort(bool),
+    /// SOL_SOCKET SO_KEEPALIVE
+    KeepAlive(bool),
+    /// SOL_SOCKET SO_BROADCAST
+    Broadcast(bool),
+    /// SOL_SOCKET SO_RCVBUF in bytes, the kernel doubles the set value
+    RcvBuf(i32),
+    /// SOL_SOCKET SO_SNDBUF in bytes, the kernel doubles the set value
+    SndBuf(i32),
+    /// SOL_SOCKET SO_ERROR pending errno, get only
+    Error(i32),
+    /// IPPROTO_TCP TCP_NODELAY
+    TcpNoDelay(bool),
+    /// IPPROTO_TCP TCP_KEEPIDLE in seconds
+    TcpKeepIdle(i32),
+    /// IPPROTO_TCP TCP_KEEPINTVL in seconds
+    TcpKeepIntvl(i32),
+    /// IPPROTO_TCP TCP_KEEPCNT probes
+    TcpKeepCnt(i32),
+    /// IPPROTO_IPV6 IPV6_V6ONLY
+    Ipv6Only(bool),
+}
+
+impl SockOpt {
+    /// Name of the option
+    pub fn name(&self) -> SockOptName {
+        match self {
+            Self::ReuseAddr(_) => SockOptName::ReuseAddr,
+            Self::ReusePort(_) => SockOptName::ReusePort,
+            Self::KeepAlive(_) => SockOptName::KeepAlive,
+            Self::Broadcast(_) => SockOptName::Broadcast,
+            Self::RcvBuf(_) => SockOptName::RcvBuf,
+            Self::SndBuf(_) => SockOptName::SndBuf,
+            Self::Error(_) => SockOptName::Error,
+            Self::TcpNoDelay(_) => SockOptName::TcpNoDelay,
+            Self::TcpKeepIdle(_) => SockOptName::TcpKeepIdle,
+            Self::TcpKeepIntvl(_) => SockOptName::TcpKeepIntvl,
+            Self::TcpKeepCnt(_) => SockOptName::TcpKeepCnt,
+            Self::Ipv6Only(_) => SockOptName::Ipv6Only,
+        }
+    }
+    /// The int option value given to the kernel
+    pub fn value(&self) -> i32 {
+        match *self {
+            Self::ReuseAddr(b)
+            | Self::ReusePort(b)
+            | Self::KeepAlive(b)
+            | Self::Broadcast(b)
+            | Self::TcpNoDelay(b)
+            | Self::Ipv6Only(b) => b as i32,
+            Self::RcvBuf(v)
+            | Self::SndBuf(v)
+            | Self::Error(v)
+            | Self::TcpKeepIdle(v)
+            | Self::TcpKeepIntvl(v)
+            | Self::TcpKeepCnt(v) => v,
+        }
+    }
+    /// Typed option of the name from the int value returned by the kernel
+    pub fn from_name_value(name: SockOptName, value: i32) -> Self {
+        match name {
+            SockOptName::ReuseAddr => Self::ReuseAddr(value != 0),
+            SockOptName::ReusePort => Self::ReusePort(value != 0),
+            SockOptName::KeepAlive => Self::KeepAlive(value != 0),
+            SockOptName::Broadcast => Self::Broadcast(value != 0),
+            SockOptName::RcvBuf => Self::RcvBuf(value),
+            SockOptName::SndBuf => Self::SndBuf(value),
+            SockOptName::Error => Self::Error(value),
+            SockOptName::TcpNoDelay => Self::TcpNoDelay(value != 0),
+            SockOptName::TcpKeepIdle => Self::TcpKeepIdle(value),
+            SockOptName::TcpKeepIntvl => Self::TcpKeepIntvl(value),
+            SockOptName::TcpKeepCnt => Self::TcpKeepCnt(value),
+            SockOptName::Ipv6Only => Self::Ipv6Only(value != 0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn name_value_roundtrip() {
+        let opts = [
+            SockOpt::ReuseAddr(true),
+            SockOpt::TcpNoDelay(false),
+            SockOpt::RcvBuf(4096),
+            SockOpt::TcpKeepCnt(3),
+        ];
+        for opt in opts {
+            assert_eq!(SockOpt::from_name_value(opt.name(), opt.value()), opt);
+        }
+    }
+
+    #[test]
+    fn sol_socket_level() {
+        assert!(SockOptName::ReusePort.is_sol_socket());
+        assert!(!SockOptName::TcpNoDelay.is_sol_socket());
+    }
+}
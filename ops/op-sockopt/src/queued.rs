@@ -0,0 +1,88 @@
+//! SIOCINQ / SIOCOUTQ Record
+
+use crate::sockopt_entry;
+use crate::{SOCKET_URING_OP_SIOCINQ, SOCKET_URING_OP_SIOCOUTQ};
+
+use io_uring_opcode::OpExtSockOpt;
+use io_uring_opcode::{OpCode, OpCompletion, OpError};
+use io_uring_owner::Owner;
+
+/// Socket queue queried by QueuedBytes
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SockQueue {
+    /// SIOCINQ unread bytes in the receive queue, for datagrams the size of the next one
+    Inbound,
+    /// SIOCOUTQ unsent bytes in the send queue
+    Outbound,
+}
+
+/// QueuedBytes Record of SIOCINQ / SIOCOUTQ. Requires Linux 6.7.
+#[derive(Clone, Debug)]
+pub struct QueuedBytes {
+    /// Current owner of the record
+    owner: Owner,
+    /// Related filehandle
+    fixed_fd: u32,
+    queue: SockQueue,
+}
+
+impl QueuedBytes {
+    /// Construct a new QueuedBytes of the given queue on the fixed socket filehandle
+    pub fn new(fixed_fd: u32, queue: SockQueue) -> Self {
+        QueuedBytes {
+            owner: Owner::Created,
+            fixed_fd,
+            queue,
+        }
+    }
+    /// The queue being queried
+    pub fn queue(&self) -> SockQueue {
+        self.queue
+    }
+}
+
+impl OpCompletion for QueuedBytes {
+    type Error = OpError;
+    type Output = usize;
+    fn entry(&self) -> io_uring::squeue::Entry {
+        let cmd_op = match self.queue {
+            SockQueue::Inbound => SOCKET_URING_OP_SIOCINQ,
+            SockQueue::Outbound => SOCKET_URING_OP_SIOCOUTQ,
+        };
+        sockopt_entry(self.fixed_fd, cmd_op, (0, 0), core::ptr::null(), 0)
+    }
+    fn opcode(&self) -> u8 {
+        io_uring::opcode::UringCmd16::CODE
+    }
+    fn owner(&self) -> Owner {
+        self.owner.clone()
+    }
+    fn force_owner_kernel(&mut self) -> bool {
+        self.owner = Owner::Kernel;
+        true
+    }
+    fn fixed_fd(&self) -> Option<u32> {
+        Some(self.fixed_fd)
+    }
+    /// The queued bytes when the result is positive, otherwise the negative errno.
+    fn completion(&mut self, cqe: &io_uring::cqueue::Entry) -> Result<usize, OpError> {
+        self.owner = Owner::Returned;
+        match cqe.result() {
+            res if res < 0 => Err(OpError::from_cqe_result("QueuedBytes", res)),
+            res => Ok(res as usize),
+        }
+    }
+}
+
+impl OpCode<QueuedBytes> for QueuedBytes {
+    fn submission(self) -> Result<QueuedBytes, OpError> {
+        Ok(self)
+    }
+}
+
+impl OpExtSockOpt for QueuedBytes {
+    /// Underlying Fixed Fd
+    fn fixed_fd(&self) -> u32 {
+        self.fixed_fd
+    }
+}
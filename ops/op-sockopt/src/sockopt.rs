@@ -0,0 +1,198 @@
+//! SetSockOpt and GetSockOpt Records
+
+use crate::error::SockOptError;
+use crate::{sockopt_entry, SockOpt, SockOptName};
+use crate::{SOCKET_URING_OP_GETSOCKOPT, SOCKET_URING_OP_SETSOCKOPT};
+
+use io_uring_opcode::OpExtSockOpt;
+use io_uring_opcode::{OpCode, OpCompletion, OpError};
+use io_uring_owner::Owner;
+
+use core::cell::UnsafeCell;
+
+const OPTLEN: u32 = size_of::<i32>() as u32;
+
+/// SetSockOpt Record
+///
+/// The option value is owned by the record behind stable heap address which the
+/// kernel reads from until completion. Requires Linux 6.7.
+#[derive(Clone, Debug)]
+pub struct SetSockOpt {
+    /// Current owner of the record
+    owner: Owner,
+    /// Related filehandle
+    fixed_fd: u32,
+    opt: SockOpt,
+    optval: Box<i32>,
+}
+
+impl SetSockOpt {
+    /// Construct a new SetSockOpt of the typed option on the fixed socket filehandle
+    pub fn new(fixed_fd: u32, opt: SockOpt) -> Self {
+        SetSockOpt {
+            owner: Owner::Created,
+            fixed_fd,
+            opt,
+            optval: Box::new(opt.value()),
+        }
+    }
+    /// The option being set
+    pub fn opt(&self) -> SockOpt {
+        self.opt
+    }
+}
+
+impl OpCompletion for SetSockOpt {
+    type Error = OpError;
+    type Output = ();
+    fn entry(&self) -> io_uring::squeue::Entry {
+        sockopt_entry(
+            self.fixed_fd,
+            SOCKET_URING_OP_SETSOCKOPT,
+            self.opt.name().level_optname(),
+            // Kernel only reads the value being set through the shared reference
+            &*self.optval as *const i32 as *const libc::c_void,
+            OPTLEN,
+        )
+    }
+    fn opcode(&self) -> u8 {
+        io_uring::opcode::UringCmd16::CODE
+    }
+    fn owner(&self) -> Owner {
+        self.owner.clone()
+    }
+    fn force_owner_kernel(&mut self) -> bool {
+        self.owner = Owner::Kernel;
+        true
+    }
+    fn fixed_fd(&self) -> Option<u32> {
+        Some(self.fixed_fd)
+    }
+    /// Set when the result is zero, otherwise the negative errno.
+    fn completion(&mut self, cqe: &io_uring::cqueue::Entry) -> Result<(), OpError> {
+        self.owner = Owner::Returned;
+        match cqe.result() {
+            res if res < 0 => Err(OpError::from_cqe_result("SetSockOpt", res)),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl OpCode<SetSockOpt> for SetSockOpt {
+    fn submission(self) -> Result<SetSockOpt, OpError> {
+        Ok(self)
+    }
+}
+
+impl OpExtSockOpt for SetSockOpt {
+    /// Underlying Fixed Fd
+    fn fixed_fd(&self) -> u32 {
+        self.fixed_fd
+    }
+    /// Option set
+    fn level_optname(&self) -> Option<(i32, i32)> {
+        Some(self.opt.name().level_optname())
+    }
+}
+
+/// GetSockOpt Record
+///
+/// The option value is written by the kernel into the record behind stable heap
+/// address. Only SOL_SOCKET level options are supported by the kernel. Requires Linux 6.7.
+#[derive(Debug)]
+pub struct GetSockOpt {
+    /// Current owner of the record
+    owner: Owner,
+    /// Related filehandle
+    fixed_fd: u32,
+    name: SockOptName,
+    /// Written by the kernel through the pointer taken from the shared record
+    optval: Box<UnsafeCell<i32>>,
+}
+
+impl Clone for GetSockOpt {
+    fn clone(&self) -> Self {
+        GetSockOpt {
+            owner: self.owner.clone(),
+            fixed_fd: self.fixed_fd,
+            name: self.name,
+            optval: Box::new(UnsafeCell::new(match self.owner {
+                // Kernel may be writing into the value of the pending record
+                Owner::Kernel => 0,
+                // SAFETY: Kernel no longer writes into the value once completed
+                _ => unsafe { *self.optval.get() },
+            })),
+        }
+    }
+}
+
+impl GetSockOpt {
+    /// Construct a new GetSockOpt of the SOL_SOCKET level option on the fixed socket filehandle
+    pub fn new(fixed_fd: u32, name: SockOptName) -> Result<Self, SockOptError> {
+        if !name.is_sol_socket() {
+            return Err(SockOptError::GetNotSolSocket(name));
+        }
+        Ok(GetSockOpt {
+            owner: Owner::Created,
+            fixed_fd,
+            name,
+            optval: Box::new(UnsafeCell::new(0)),
+        })
+    }
+    /// The option being got
+    pub fn name(&self) -> SockOptName {
+        self.name
+    }
+}
+
+impl OpCompletion for GetSockOpt {
+    type Error = OpError;
+    type Output = SockOpt;
+    fn entry(&self) -> io_uring::squeue::Entry {
+        sockopt_entry(
+            self.fixed_fd,
+            SOCKET_URING_OP_GETSOCKOPT,
+            self.name.level_optname(),
+            self.optval.get() as *const libc::c_void,
+            OPTLEN,
+        )
+    }
+    fn opcode(&self) -> u8 {
+        io_uring::opcode::UringCmd16::CODE
+    }
+    fn owner(&self) -> Owner {
+        self.owner.clone()
+    }
+    fn force_owner_kernel(&mut self) -> bool {
+        self.owner = Owner::Kernel;
+        true
+    }
+    fn fixed_fd(&self) -> Option<u32> {
+        Some(self.fixed_fd)
+    }
+    /// The typed option read back when the result is the option length, otherwise the negative errno.
+    fn completion(&mut self, cqe: &io_uring::cqueue::Entry) -> Result<SockOpt, OpError> {
+        self.owner = Owner::Returned;
+        match cqe.result() {
+            res if res < 0 => Err(OpError::from_cqe_result("GetSockOpt", res)),
+            _ => Ok(SockOpt::from_name_value(self.name, *self.optval.get_mut())),
+        }
+    }
+}
+
+impl OpCode<GetSockOpt> for GetSockOpt {
+    fn submission(self) -> Result<GetSockOpt, OpError> {
+        Ok(self)
+    }
+}
+
+impl OpExtSockOpt for GetSockOpt {
+    /// Underlying Fixed Fd
+    fn fixed_fd(&self) -> u32 {
+        self.fixed_fd
+    }
+    /// Option got
+    fn level_optname(&self) -> Option<(i32, i32)> {
+        Some(self.name.level_optname())
+    }
+}
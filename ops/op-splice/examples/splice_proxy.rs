@@ -0,0 +1,91 @@
+use std::io::{Read, Write};
+use std::os::fd::AsRawFd;
+use std::os::unix::net::UnixStream;
+
+use io_uring_bearer::Completion;
+use io_uring_bearer::UringBearer;
+
+use capacity::{Capacity, Setting};
+use io_uring_bearer::io_uring_fd::{FdKind, RegisteredFd};
+use io_uring_bearer::BearerCapacityKind;
+
+use io_uring_op_splice::{FdRef, Splice, NO_OFFSET};
+
+#[derive(Clone, Debug)]
+pub struct MyCapacity;
+
+impl Setting<BearerCapacityKind> for MyCapacity {
+    fn setting(&self, v: &BearerCapacityKind) -> usize {
+        match v {
+            BearerCapacityKind::CoreQueue => 2,
+            BearerCapacityKind::RegisteredFd => 1,
+            BearerCapacityKind::PendingCompletions => 2,
+            BearerCapacityKind::Buffers => 0,
+            BearerCapacityKind::Futexes => 0,
+        }
+    }
+}
+
+fn main() {
+    // Downstream peer writes into the proxy which forwards it to the upstream peer
+    let (mut downstream, proxy_in) = UnixStream::pair().unwrap();
+    let (proxy_out, mut upstream) = UnixStream::pair().unwrap();
+    downstream.write_all(b"hello proxy").unwrap();
+
+    let my_cap = Capacity::<MyCapacity, BearerCapacityKind>::with_planned(MyCapacity {});
+    let mut bearer = UringBearer::with_capacity(my_cap).unwrap();
+
+    // Make the upstream side fixed
+    let slot = bearer
+        .add_registered_fd(RegisteredFd::from_raw(proxy_out.as_raw_fd(), FdKind::Send))
+        .unwrap();
+    bearer.commit_registered_init().unwrap();
+
+    let (_to_pipe, _from_pipe, pipe) = bearer
+        .push_splice_through_pipe(
+            |pipe| {
+                (
+                    Splice::new(
+                        FdRef::Regular(proxy_in.as_raw_fd()),
+                        NO_OFFSET,
+                        FdRef::Regular(pipe.write_fd()),
+                        NO_OFFSET,
+                        64,
+                    )
+                    .unwrap(),
+                    Splice::new(
+                        FdRef::Regular(pipe.read_fd()),
+                        NO_OFFSET,
+                        FdRef::Fixed(slot),
+                        NO_OFFSET,
+                        64,
+                    )
+                    .unwrap()
+                    .with_flags(libc::SPLICE_F_NONBLOCK),
+                )
+            },
+            None,
+        )
+        .unwrap();
+
+    bearer.submit_and_wait(2).unwrap();
+
+    let mut moved = Vec::new();
+    bearer
+        .typed_completions(&mut moved, |moved, entry, rec, res| match rec {
+            Completion::Splice(_) => {
+                println!("Splice Q<{:?}> = {:?}", entry, res);
+                moved.push(res.unwrap().unwrap());
+            }
+            _ => panic!("Queue had something else than Splice?"),
+        })
+        .unwrap();
+    assert_eq!(moved, vec![11, 11]);
+    // Both splices have completed
+    drop(pipe);
+
+    let mut buf = [0u8; 11];
+    upstream.read_exact(&mut buf).unwrap();
+    assert_eq!(&buf, b"hello proxy");
+    println!("upstream received {:?}", std::str::from_utf8(&buf).unwrap());
+}
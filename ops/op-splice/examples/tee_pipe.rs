@@ -0,0 +1,66 @@
+use std::io::{Read, Write};
+use std::os::fd::FromRawFd;
+
+use io_uring_bearer::Completion;
+use io_uring_bearer::UringBearer;
+
+use capacity::{Capacity, Setting};
+use io_uring_bearer::BearerCapacityKind;
+
+use io_uring_op_splice::{FdRef, SplicePipe, Tee};
+
+#[derive(Clone, Debug)]
+pub struct MyCapacity;
+
+impl Setting<BearerCapacityKind> for MyCapacity {
+    fn setting(&self, v: &BearerCapacityKind) -> usize {
+        match v {
+            BearerCapacityKind::CoreQueue => 1,
+            BearerCapacityKind::RegisteredFd => 1,
+            BearerCapacityKind::PendingCompletions => 1,
+            BearerCapacityKind::Buffers => 0,
+            BearerCapacityKind::Futexes => 0,
+        }
+    }
+}
+
+fn main() {
+    let source = SplicePipe::new().unwrap();
+    let copy = SplicePipe::new().unwrap();
+
+    // SAFETY: Borrowed only for writing while the SplicePipe owns the filehandle
+    let mut source_write =
+        std::mem::ManuallyDrop::new(unsafe { std::fs::File::from_raw_fd(source.write_fd()) });
+    source_write.write_all(b"tee'd").unwrap();
+
+    let my_cap = Capacity::<MyCapacity, BearerCapacityKind>::with_planned(MyCapacity {});
+    let mut bearer = UringBearer::with_capacity(my_cap).unwrap();
+
+    let tee = Tee::new(
+        FdRef::Regular(source.read_fd()),
+        FdRef::Regular(copy.write_fd()),
+        64,
+    );
+    bearer.push_splice(tee, None).unwrap();
+    bearer.submit_and_wait(1).unwrap();
+
+    bearer
+        .typed_completions(&mut (), |_, entry, rec, res| match rec {
+            Completion::Splice(_) => {
+                println!("Tee Q<{:?}> = {:?}", entry, res);
+                assert_eq!(res.unwrap().unwrap(), 5);
+            }
+            _ => panic!("Queue had something else than Tee?"),
+        })
+        .unwrap();
+
+    // Data is in both of the pipes as tee does not consume the source
+    for pipe in [&source, &copy] {
+        // SAFETY: Borrowed only for reading while the SplicePipe owns the filehandle
+        let mut read =
+            std::mem::ManuallyDrop::new(unsafe { std::fs::File::from_raw_fd(pipe.read_fd()) });
+        let mut buf = [0u8; 5];
+        read.read_exact(&mut buf).unwrap();
+        assert_eq!(&buf, b"tee'd");
+    }
+}
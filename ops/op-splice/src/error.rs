@@ -0,0 +1,31 @@
+//! Splice op Errors
+
+use core::fmt;
+use core::fmt::Display;
+
+use io_uring_opcode::OpError;
+
+/// Splice Errors
+#[derive(Debug)]
+pub enum SpliceError {
+    /// Offset must be either -1 for pipes and the current position or positive
+    InvalidOffset(i64),
+}
+
+impl Display for SpliceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidOffset(o) => write!(f, "Invalid offset {}", o),
+        }
+    }
+}
+
+impl From<SpliceError> for OpError {
+    fn from(e: SpliceError) -> OpError {
+        match e {
+            SpliceError::InvalidOffset(_) => OpError::InvalidParameter("Invalid offset"),
+        }
+    }
+}
+
+impl std::error::Error for SpliceError {}
@@ -0,0 +1,36 @@
+#![warn(
+    clippy::unwrap_used,
+    missing_docs,
+    rust_2018_idioms,
+    unused_lifetimes,
+    unused_qualifications
+)]
+#![doc = include_str!("../README.md")]
+
+//-----------------------------------------------
+// All Errors
+//-----------------------------------------------
+mod error;
+#[doc(inline)]
+pub use error::*;
+
+//-----------------------------------------------
+// Re-Exports
+//-----------------------------------------------
+pub use io_uring_bearer::SplicePipe;
+pub use io_uring_opcode::types::FdRef;
+
+//-----------------------------------------------
+// Splice Record Types
+//-----------------------------------------------
+mod splice;
+pub use splice::Splice;
+
+//-----------------------------------------------
+// Tee Record Types
+//-----------------------------------------------
+mod tee;
+pub use tee::Tee;
+
+/// Offset of the pipe ends and the current file position
+pub const NO_OFFSET: i64 = -1;
@@ -0,0 +1,132 @@
+//! Splice Record
+
+use crate::error::SpliceError;
+use crate::NO_OFFSET;
+
+use io_uring_opcode::types::FdRef;
+use io_uring_opcode::OpExtSplice;
+use io_uring_opcode::{OpCode, OpCompletion, OpError};
+use io_uring_owner::Owner;
+
+/// Splice Record
+#[derive(Clone, Debug)]
+pub struct Splice {
+    /// Current owner of the record
+    owner: Owner,
+    fd_in: FdRef,
+    off_in: i64,
+    fd_out: FdRef,
+    off_out: i64,
+    len: u32,
+    flags: u32,
+}
+
+impl Splice {
+    /// Construct a new Splice of up to len bytes where either end must be a pipe.
+    /// The offset of a pipe end must be -1 whilst -1 moves from / into the current
+    /// file position otherwise.
+    pub fn new(
+        fd_in: FdRef,
+        off_in: i64,
+        fd_out: FdRef,
+        off_out: i64,
+        len: u32,
+    ) -> Result<Self, SpliceError> {
+        for off in [off_in, off_out] {
+            if off < NO_OFFSET {
+                return Err(SpliceError::InvalidOffset(off));
+            }
+        }
+        Ok(Splice {
+            owner: Owner::Created,
+            fd_in,
+            off_in,
+            fd_out,
+            off_out,
+            len,
+            flags: 0,
+        })
+    }
+    /// Set the SPLICE_F_* flags e.g. libc::SPLICE_F_MOVE
+    pub fn with_flags(mut self, flags: u32) -> Self {
+        self.flags = flags;
+        self
+    }
+}
+
+impl OpCompletion for Splice {
+    type Error = OpError;
+    type Output = usize;
+    fn entry(&self) -> io_uring::squeue::Entry {
+        io_uring_opcode::with_fd_ref!(self.fd_in, |fd_in| {
+            io_uring_opcode::with_fd_ref!(self.fd_out, |fd_out| {
+                io_uring::opcode::Splice::new(fd_in, self.off_in, fd_out, self.off_out, self.len)
+                    .flags(self.flags)
+                    .build()
+            })
+        })
+    }
+    fn opcode(&self) -> u8 {
+        io_uring::opcode::Splice::CODE
+    }
+    fn owner(&self) -> Owner {
+        self.owner.clone()
+    }
+    fn force_owner_kernel(&mut self) -> bool {
+        self.owner = Owner::Kernel;
+        true
+    }
+    fn fixed_fd(&self) -> Option<u32> {
+        self.fd_in.fixed().or(self.fd_out.fixed())
+    }
+    /// Count of bytes moved, zero at end of input, otherwise the negative errno.
+    fn completion(&mut self, cqe: &io_uring::cqueue::Entry) -> Result<usize, OpError> {
+        self.owner = Owner::Returned;
+        match cqe.result() {
+            res if res < 0 => Err(OpError::from_cqe_result("Splice", res)),
+            res => Ok(res as usize),
+        }
+    }
+}
+
+impl OpCode<Splice> for Splice {
+    fn submission(self) -> Result<Splice, OpError> {
+        Ok(self)
+    }
+}
+
+impl OpExtSplice for Splice {
+    fn fd_in(&self) -> FdRef {
+        self.fd_in
+    }
+    fn off_in(&self) -> i64 {
+        self.off_in
+    }
+    fn fd_out(&self) -> FdRef {
+        self.fd_out
+    }
+    fn off_out(&self) -> i64 {
+        self.off_out
+    }
+    fn len(&self) -> u32 {
+        self.len
+    }
+    fn splice_flags(&self) -> u32 {
+        self.flags
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn offset_bounds() {
+        assert!(matches!(
+            Splice::new(FdRef::Regular(0), -2, FdRef::Fixed(0), -1, 1),
+            Err(SpliceError::InvalidOffset(-2))
+        ));
+        let s = Splice::new(FdRef::Regular(0), 10, FdRef::Fixed(1), -1, 1).expect("splice");
+        assert_eq!(s.fixed_fd(), Some(1));
+    }
+}
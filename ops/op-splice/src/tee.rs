@@ -0,0 +1,100 @@
+//! Tee Record
+
+use crate::NO_OFFSET;
+
+use io_uring_opcode::types::FdRef;
+use io_uring_opcode::OpExtSplice;
+use io_uring_opcode::{OpCode, OpCompletion, OpError};
+use io_uring_owner::Owner;
+
+/// Tee Record
+#[derive(Clone, Debug)]
+pub struct Tee {
+    /// Current owner of the record
+    owner: Owner,
+    fd_in: FdRef,
+    fd_out: FdRef,
+    len: u32,
+    flags: u32,
+}
+
+impl Tee {
+    /// Construct a new Tee duplicating up to len bytes from the input pipe into the
+    /// output pipe without consuming them from the input.
+    pub fn new(fd_in: FdRef, fd_out: FdRef, len: u32) -> Self {
+        Tee {
+            owner: Owner::Created,
+            fd_in,
+            fd_out,
+            len,
+            flags: 0,
+        }
+    }
+    /// Set the SPLICE_F_* flags e.g. libc::SPLICE_F_NONBLOCK
+    pub fn with_flags(mut self, flags: u32) -> Self {
+        self.flags = flags;
+        self
+    }
+}
+
+impl OpCompletion for Tee {
+    type Error = OpError;
+    type Output = usize;
+    fn entry(&self) -> io_uring::squeue::Entry {
+        io_uring_opcode::with_fd_ref!(self.fd_in, |fd_in| {
+            io_uring_opcode::with_fd_ref!(self.fd_out, |fd_out| {
+                io_uring::opcode::Tee::new(fd_in, fd_out, self.len)
+                    .flags(self.flags)
+                    .build()
+            })
+        })
+    }
+    fn opcode(&self) -> u8 {
+        io_uring::opcode::Tee::CODE
+    }
+    fn owner(&self) -> Owner {
+        self.owner.clone()
+    }
+    fn force_owner_kernel(&mut self) -> bool {
+        self.owner = Owner::Kernel;
+        true
+    }
+    fn fixed_fd(&self) -> Option<u32> {
+        self.fd_in.fixed().or(self.fd_out.fixed())
+    }
+    /// Count of bytes duplicated, zero at end of input, otherwise the negative errno.
+    fn completion(&mut self, cqe: &io_uring::cqueue::Entry) -> Result<usize, OpError> {
+        self.owner = Owner::Returned;
+        match cqe.result() {
+            res if res < 0 => Err(OpError::from_cqe_result("Tee", res)),
+            res => Ok(res as usize),
+        }
+    }
+}
+
+impl OpCode<Tee> for Tee {
+    fn submission(self) -> Result<Tee, OpError> {
+        Ok(self)
+    }
+}
+
+impl OpExtSplice for Tee {
+    fn fd_in(&self) -> FdRef {
+        self.fd_in
+    }
+    fn off_in(&self) -> i64 {
+        NO_OFFSET
+    }
+    fn fd_out(&self) -> FdRef {
+        self.fd_out
+    }
+    fn off_out(&self) -> i64 {
+        NO_OFFSET
+    }
+    fn len(&self) -> u32 {
+        self.len
+    }
+    fn splice_flags(&self) -> u32 {
+        self.flags
+    }
+}
@@ -0,0 +1,57 @@
+use std::time::{Duration, Instant};
+
+use io_uring_bearer::Completion;
+use io_uring_bearer::UringBearer;
+
+use capacity::{Capacity, Setting};
+use io_uring_bearer::BearerCapacityKind;
+
+use io_uring_op_timeout::{Timeout, TimeoutFired};
+
+#[derive(Clone, Debug)]
+pub struct MyCapacity;
+
+impl Setting<BearerCapacityKind> for MyCapacity {
+    fn setting(&self, v: &BearerCapacityKind) -> usize {
+        match v {
+            BearerCapacityKind::CoreQueue => 1,
+            BearerCapacityKind::RegisteredFd => 1,
+            BearerCapacityKind::PendingCompletions => 1,
+            BearerCapacityKind::Buffers => 0,
+            BearerCapacityKind::Futexes => 0,
+        }
+    }
+}
+
+fn main() {
+    let my_cap = Capacity::<MyCapacity, BearerCapacityKind>::with_planned(MyCapacity {});
+    let mut bearer = UringBearer::with_capacity(my_cap).unwrap();
+
+    let started = Instant::now();
+    let _op_idx = bearer
+        .push_timeout(Timeout::relative(Duration::from_millis(100)), None)
+        .unwrap();
+
+    bearer.submit_and_wait(1).unwrap();
+
+    #[derive(Debug)]
+    struct UserData {
+        fired: u32,
+    }
+
+    let mut user = UserData { fired: 0 };
+
+    bearer
+        .typed_completions(&mut user, |user, entry, rec, res| match rec {
+            Completion::Timeout(_) => {
+                user.fired += 1;
+                println!("Timeout Q<{:?}> after {:?}", entry, started.elapsed());
+                assert_eq!(res.unwrap().unwrap(), TimeoutFired::Expired { more: false });
+            }
+            _ => panic!("Queue had something else than Timeout?"),
+        })
+        .unwrap();
+
+    assert_eq!(user.fired, 1);
+    assert!(started.elapsed() >= Duration::from_millis(100));
+}
@@ -0,0 +1,78 @@
+use std::mem::MaybeUninit;
+use std::time::Duration;
+
+use io_uring_bearer::completion::SubmissionRecordStatus;
+use io_uring_bearer::Completion;
+use io_uring_bearer::UringBearer;
+
+use capacity::{Capacity, Setting};
+use io_uring_bearer::BearerCapacityKind;
+
+use io_uring_op_timeout::{Timeout, TimeoutFired};
+
+#[derive(Clone, Debug)]
+pub struct MyCapacity;
+
+impl Setting<BearerCapacityKind> for MyCapacity {
+    fn setting(&self, v: &BearerCapacityKind) -> usize {
+        match v {
+            BearerCapacityKind::CoreQueue => 4,
+            BearerCapacityKind::RegisteredFd => 1,
+            BearerCapacityKind::PendingCompletions => 4,
+            BearerCapacityKind::Buffers => 0,
+            BearerCapacityKind::Futexes => 0,
+        }
+    }
+}
+
+fn main() {
+    let my_cap = Capacity::<MyCapacity, BearerCapacityKind>::with_planned(MyCapacity {});
+    let mut bearer = UringBearer::with_capacity(my_cap).unwrap();
+
+    for _ in 0..4 {
+        bearer
+            .push_timeout(Timeout::relative(Duration::from_millis(10)), None)
+            .unwrap();
+    }
+    bearer.submit_and_wait(4).unwrap();
+
+    // Bounded: only the one completion is handled and the rest remain on the ring
+    let mut fired = 0;
+    // SAFETY: Timeouts are not referred to by the kernel after their completion.
+    unsafe {
+        bearer
+            .handle_completions(&mut fired, Some(1), |fired, _entry, rec| match rec {
+                Completion::Timeout(_) => {
+                    *fired += 1;
+                    SubmissionRecordStatus::Forget
+                }
+                _ => panic!("Queue had something else than Timeout?"),
+            })
+            .unwrap();
+    }
+    assert_eq!(fired, 1);
+
+    // Batched: up to two completions are copied out of the ring at once
+    let mut batch = [MaybeUninit::uninit(), MaybeUninit::uninit()];
+    let mut handled = Vec::new();
+    loop {
+        let count = bearer
+            .lifecycle_completions_batch(&mut fired, &mut batch, |fired, entry, rec, res, _| {
+                match rec {
+                    Completion::Timeout(_) => {
+                        println!("Timeout Q<{:?}>", entry);
+                        assert_eq!(res.unwrap().unwrap(), TimeoutFired::Expired { more: false });
+                        *fired += 1;
+                    }
+                    _ => panic!("Queue had something else than Timeout?"),
+                }
+            })
+            .unwrap();
+        if count == 0 {
+            break;
+        }
+        handled.push(count);
+    }
+    assert_eq!(handled, vec![2, 1]);
+    assert_eq!(fired, 4);
+}
@@ -0,0 +1,90 @@
+use std::time::Duration;
+
+use io_uring_bearer::Completion;
+use io_uring_bearer::UringBearer;
+
+use capacity::{Capacity, Setting};
+use io_uring_bearer::BearerCapacityKind;
+
+use io_uring_op_timeout::{Timeout, TimeoutFired};
+
+#[derive(Clone, Debug)]
+pub struct MyCapacity;
+
+impl Setting<BearerCapacityKind> for MyCapacity {
+    fn setting(&self, v: &BearerCapacityKind) -> usize {
+        match v {
+            BearerCapacityKind::CoreQueue => 2,
+            BearerCapacityKind::RegisteredFd => 1,
+            BearerCapacityKind::PendingCompletions => 2,
+            BearerCapacityKind::Buffers => 0,
+            BearerCapacityKind::Futexes => 0,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct UserData {
+    fired: u32,
+    canceled: u32,
+    cancel_done: u32,
+}
+
+fn main() {
+    let my_cap = Capacity::<MyCapacity, BearerCapacityKind>::with_planned(MyCapacity {});
+    let mut bearer = UringBearer::with_capacity(my_cap).unwrap();
+
+    // Indefinitely repeating Timeout which can only be stopped by cancelling it
+    let timeout = Timeout::relative(Duration::from_millis(10))
+        .multishot(0)
+        .unwrap();
+    let timeout_key = bearer.push_timeout(timeout, None).unwrap();
+
+    let mut user = UserData::default();
+
+    while user.fired < 2 {
+        bearer.submit_and_wait(1).unwrap();
+        bearer
+            .typed_completions(&mut user, |user, _entry, rec, res| match rec {
+                Completion::Timeout(_) => {
+                    assert_eq!(res.unwrap().unwrap(), TimeoutFired::Expired { more: true });
+                    user.fired += 1;
+                }
+                _ => panic!("Queue had something else than Timeout?"),
+            })
+            .unwrap();
+    }
+
+    println!("Timeout fired {} times, cancelling ..", user.fired);
+    let _cancel_key = bearer.cancel_by_key(timeout_key, None).unwrap();
+
+    while user.canceled == 0 || user.cancel_done == 0 {
+        bearer.submit_and_wait(1).unwrap();
+        bearer
+            .typed_completions(&mut user, |user, entry, rec, res| match rec {
+                Completion::Timeout(_) => match res.unwrap() {
+                    Ok(TimeoutFired::Expired { more: true }) => user.fired += 1,
+                    Err(e) => {
+                        println!("Timeout canceled: {e}");
+                        user.canceled += 1;
+                    }
+                    Ok(other) => panic!("Unexpected Timeout completion {:?}", other),
+                },
+                Completion::AsyncCancel(c) => {
+                    println!("AsyncCancel {:?} result {}", c.target(), entry.result());
+                    assert_eq!(entry.result(), 0);
+                    user.cancel_done += 1;
+                }
+                _ => panic!("Queue had something else than Timeout or AsyncCancel?"),
+            })
+            .unwrap();
+    }
+
+    // Both records were released so both of the slots are free to be taken again
+    bearer
+        .push_timeout(Timeout::relative(Duration::from_millis(1)), None)
+        .unwrap();
+    bearer
+        .push_timeout(Timeout::relative(Duration::from_millis(1)), None)
+        .unwrap();
+}
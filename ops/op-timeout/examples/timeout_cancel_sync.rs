@@ -0,0 +1,61 @@
+use std::time::Duration;
+
+use io_uring_bearer::slab::CancelTarget;
+use io_uring_bearer::Completion;
+use io_uring_bearer::UringBearer;
+
+use capacity::{Capacity, Setting};
+use io_uring_bearer::BearerCapacityKind;
+
+use io_uring_op_timeout::Timeout;
+
+#[derive(Clone, Debug)]
+pub struct MyCapacity;
+
+impl Setting<BearerCapacityKind> for MyCapacity {
+    fn setting(&self, v: &BearerCapacityKind) -> usize {
+        match v {
+            BearerCapacityKind::CoreQueue => 2,
+            BearerCapacityKind::RegisteredFd => 1,
+            BearerCapacityKind::PendingCompletions => 2,
+            BearerCapacityKind::Buffers => 0,
+            BearerCapacityKind::Futexes => 0,
+        }
+    }
+}
+
+fn main() {
+    let my_cap = Capacity::<MyCapacity, BearerCapacityKind>::with_planned(MyCapacity {});
+    let mut bearer = UringBearer::with_capacity(my_cap).unwrap();
+
+    // Timeout far in the future which would keep the record owned by the kernel
+    let timeout = Timeout::relative(Duration::from_secs(3600));
+    let timeout_key = bearer.push_timeout(timeout, None).unwrap();
+
+    let reconciled = bearer
+        .cancel_sync(CancelTarget::Key(timeout_key), Some(Duration::from_secs(1)))
+        .unwrap();
+    assert_eq!(reconciled, 1);
+
+    // The canceled completion is already waiting and the record is released upon handling it
+    let mut canceled = 0;
+    bearer
+        .typed_completions(&mut canceled, |canceled, entry, rec, res| match rec {
+            Completion::Timeout(_) => {
+                println!("Timeout Q<{:?}> = {:?}", entry, res);
+                assert!(res.unwrap().is_err());
+                *canceled += 1;
+            }
+            _ => panic!("Queue had something else than Timeout?"),
+        })
+        .unwrap();
+    assert_eq!(canceled, 1);
+
+    // Both of the slots are free to be taken again
+    bearer
+        .push_timeout(Timeout::relative(Duration::from_millis(1)), None)
+        .unwrap();
+    bearer
+        .push_timeout(Timeout::relative(Duration::from_millis(1)), None)
+        .unwrap();
+}
@@ -0,0 +1,95 @@
+use std::time::Duration;
+
+use io_uring_bearer::error::UringBearerError;
+use io_uring_bearer::Completion;
+use io_uring_bearer::CompletionFlow;
+use io_uring_bearer::UringBearer;
+
+use capacity::{Capacity, Setting};
+use io_uring_bearer::BearerCapacityKind;
+
+use io_uring_op_timeout::{Timeout, TimeoutFired};
+use io_uring_opcode::OpError;
+
+#[derive(Clone, Debug)]
+pub struct MyCapacity;
+
+impl Setting<BearerCapacityKind> for MyCapacity {
+    fn setting(&self, v: &BearerCapacityKind) -> usize {
+        match v {
+            BearerCapacityKind::CoreQueue => 2,
+            BearerCapacityKind::RegisteredFd => 1,
+            BearerCapacityKind::PendingCompletions => 8,
+            BearerCapacityKind::Buffers => 0,
+            BearerCapacityKind::Futexes => 0,
+        }
+    }
+}
+
+/// Errors of the completion handler
+#[derive(Debug)]
+#[allow(dead_code)]
+enum ExampleError {
+    Bearer(UringBearerError),
+    Op(OpError),
+    Unexpected,
+}
+
+impl From<UringBearerError> for ExampleError {
+    fn from(e: UringBearerError) -> Self {
+        Self::Bearer(e)
+    }
+}
+
+impl From<OpError> for ExampleError {
+    fn from(e: OpError) -> Self {
+        Self::Op(e)
+    }
+}
+
+fn main() -> Result<(), ExampleError> {
+    let my_cap = Capacity::<MyCapacity, BearerCapacityKind>::with_planned(MyCapacity {});
+    let mut bearer = UringBearer::with_capacity(my_cap)?;
+
+    // Each expired Timeout enqueues the next one from within the handler
+    bearer.push_timeout(Timeout::relative(Duration::from_millis(5)), None)?;
+    let mut fired = 0;
+    while fired < 3 {
+        bearer.submit_and_wait(1)?;
+        bearer.try_completions(&mut (), None, |_, enqueue, _entry, rec, res, _| {
+            let Completion::Timeout(_) = rec else {
+                return Err(ExampleError::Unexpected);
+            };
+            assert_eq!(
+                res.ok_or(ExampleError::Unexpected)??,
+                TimeoutFired::Expired { more: false }
+            );
+            fired += 1;
+            if fired < 3 {
+                enqueue.push_with(|bearer| {
+                    bearer.push_timeout(Timeout::relative(Duration::from_millis(5)), None)
+                });
+            }
+            Ok(CompletionFlow::Continue)
+        })?;
+    }
+    println!("Timeout re-enqueued and fired {} times", fired);
+
+    // Stopping after the first leaves the second for the next spin
+    for _ in 0..2 {
+        bearer.push_timeout(Timeout::relative(Duration::from_millis(5)), None)?;
+    }
+    bearer.submit_and_wait(2)?;
+    for _ in 0..2 {
+        let handled = bearer.try_completions(&mut (), None, |_, _, entry, _, _, _| {
+            println!("Stopping after Q<{:?}>", entry);
+            Ok::<_, ExampleError>(CompletionFlow::Stop)
+        })?;
+        assert_eq!(handled, 1);
+    }
+    let handled = bearer.try_completions(&mut (), None, |_, _, _, _, _, _| {
+        Ok::<_, ExampleError>(CompletionFlow::Continue)
+    })?;
+    assert_eq!(handled, 0);
+    Ok(())
+}
@@ -0,0 +1,77 @@
+use std::time::{Duration, Instant};
+
+use io_uring_bearer::Completion;
+use io_uring_bearer::UringBearer;
+use io_uring_bearer::{MultishotStatus, RearmPolicy};
+
+use capacity::{Capacity, Setting};
+use io_uring_bearer::BearerCapacityKind;
+
+use io_uring_op_timeout::{Timeout, TimeoutFired};
+
+#[derive(Clone, Debug)]
+pub struct MyCapacity;
+
+impl Setting<BearerCapacityKind> for MyCapacity {
+    fn setting(&self, v: &BearerCapacityKind) -> usize {
+        match v {
+            BearerCapacityKind::CoreQueue => 1,
+            BearerCapacityKind::RegisteredFd => 1,
+            BearerCapacityKind::PendingCompletions => 1,
+            BearerCapacityKind::Buffers => 0,
+            BearerCapacityKind::Futexes => 0,
+        }
+    }
+}
+
+fn main() {
+    let my_cap = Capacity::<MyCapacity, BearerCapacityKind>::with_planned(MyCapacity {});
+    let mut bearer = UringBearer::with_capacity(my_cap).unwrap();
+
+    // Fires twice and is re-armed once by the bearer upon terminating
+    let started = Instant::now();
+    let timeout = Timeout::relative(Duration::from_millis(20))
+        .multishot(2)
+        .unwrap();
+    let op_idx = bearer.push_timeout(timeout, None).unwrap();
+    bearer
+        .set_rearm_policy(op_idx, RearmPolicy::UpTo(1))
+        .unwrap();
+
+    let mut statuses: Vec<MultishotStatus> = Vec::new();
+    while statuses.last() != Some(&MultishotStatus::Terminated) {
+        bearer.submit_and_wait(1).unwrap();
+        bearer
+            .lifecycle_completions(
+                &mut statuses,
+                |statuses, entry, rec, res, status| match rec {
+                    Completion::Timeout(_) => {
+                        println!(
+                            "Timeout Q<{:?}> {:?} after {:?}",
+                            entry,
+                            status,
+                            started.elapsed()
+                        );
+                        assert!(matches!(
+                            res.unwrap().unwrap(),
+                            TimeoutFired::Expired { .. }
+                        ));
+                        statuses.push(status);
+                    }
+                    _ => panic!("Queue had something else than Timeout?"),
+                },
+            )
+            .unwrap();
+    }
+
+    assert_eq!(
+        statuses,
+        vec![
+            MultishotStatus::More,
+            MultishotStatus::Rearmed,
+            MultishotStatus::More,
+            MultishotStatus::Terminated,
+        ]
+    );
+    assert!(started.elapsed() >= Duration::from_millis(80));
+}
@@ -0,0 +1,52 @@
+use std::time::{Duration, Instant};
+
+use io_uring_bearer::UringBearer;
+
+use capacity::{Capacity, Setting};
+use io_uring_bearer::BearerCapacityKind;
+
+use io_uring_op_timeout::Timeout;
+
+#[derive(Clone, Debug)]
+pub struct MyCapacity;
+
+impl Setting<BearerCapacityKind> for MyCapacity {
+    fn setting(&self, v: &BearerCapacityKind) -> usize {
+        match v {
+            BearerCapacityKind::CoreQueue => 2,
+            BearerCapacityKind::RegisteredFd => 1,
+            BearerCapacityKind::PendingCompletions => 2,
+            BearerCapacityKind::Buffers => 0,
+            BearerCapacityKind::Futexes => 0,
+        }
+    }
+}
+
+fn main() {
+    let my_cap = Capacity::<MyCapacity, BearerCapacityKind>::with_planned(MyCapacity {});
+    let mut bearer = UringBearer::with_capacity(my_cap).unwrap();
+
+    // Timeouts far in the future which would keep the records owned by the kernel
+    for _ in 0..2 {
+        bearer
+            .push_timeout(Timeout::relative(Duration::from_secs(3600)), None)
+            .unwrap();
+    }
+    bearer.submit().unwrap();
+    assert_eq!(bearer.in_flight(), 2);
+
+    let started = Instant::now();
+    bearer.shutdown(Duration::from_secs(1)).unwrap();
+    println!("Shutdown in {:?}", started.elapsed());
+    assert_eq!(bearer.in_flight(), 0);
+
+    // Dropping after the shutdown has nothing left to wait for
+    let mut bearer = UringBearer::with_capacity(
+        Capacity::<MyCapacity, BearerCapacityKind>::with_planned(MyCapacity {}),
+    )
+    .unwrap();
+    bearer
+        .push_timeout(Timeout::relative(Duration::from_secs(3600)), None)
+        .unwrap();
+    drop(bearer);
+}
@@ -0,0 +1,78 @@
+use std::time::Duration;
+
+use io_uring_bearer::completion::SubmissionRecordStatus;
+use io_uring_bearer::error::UringBearerError;
+use io_uring_bearer::UringBearer;
+
+use capacity::{Capacity, Setting};
+use io_uring_bearer::BearerCapacityKind;
+
+use io_uring_op_timeout::Timeout;
+
+#[derive(Clone, Debug)]
+pub struct MyCapacity;
+
+impl Setting<BearerCapacityKind> for MyCapacity {
+    fn setting(&self, v: &BearerCapacityKind) -> usize {
+        match v {
+            BearerCapacityKind::CoreQueue => 4,
+            BearerCapacityKind::RegisteredFd => 1,
+            BearerCapacityKind::PendingCompletions => 16,
+            BearerCapacityKind::Buffers => 0,
+            BearerCapacityKind::Futexes => 0,
+        }
+    }
+}
+
+fn timeouts(count: usize) -> Vec<Timeout> {
+    (0..count)
+        .map(|_| Timeout::relative(Duration::from_millis(10)))
+        .collect()
+}
+
+fn main() {
+    let my_cap = Capacity::<MyCapacity, BearerCapacityKind>::with_planned(MyCapacity {});
+    let mut bearer = UringBearer::with_capacity(my_cap).unwrap();
+
+    // More than the queue holds is refused without leaving any records behind
+    let mut batch = bearer.submission_batch();
+    match batch.push_multiple(timeouts(3), None) {
+        Ok(keys) => assert_eq!(keys.len(), 3),
+        Err(e) => panic!("Could not push the first three: {}", e),
+    }
+    match batch.push_multiple(timeouts(2), None) {
+        Err(UringBearerError::SubmissionQueueFull(2, 1)) => (),
+        other => panic!("Expected the queue to be full, got {:?}", other),
+    }
+    assert_eq!(batch.keys().len(), 3);
+    batch.submit().unwrap();
+    assert_eq!(bearer.in_flight(), 3);
+
+    // Auto submit makes room by submitting the staged ones
+    let mut batch = bearer.submission_batch().auto_submit(true);
+    batch.reserve(4).unwrap();
+    batch.push_multiple(timeouts(3), None).unwrap();
+    batch.push_multiple(timeouts(3), None).unwrap();
+    batch
+        .push_op(Timeout::relative(Duration::from_millis(10)), None)
+        .unwrap();
+    let keys = batch.submit().unwrap();
+    assert_eq!(keys.len(), 7);
+    assert_eq!(bearer.in_flight(), 10);
+
+    let mut fired = 0;
+    while fired < 10 {
+        bearer.submit_and_wait(1).unwrap();
+        // SAFETY: Timeouts are not referred to by the kernel after their completion.
+        unsafe {
+            bearer
+                .handle_completions(&mut fired, None, |fired, entry, _rec| {
+                    assert_eq!(entry.result(), -62);
+                    *fired += 1;
+                    SubmissionRecordStatus::Forget
+                })
+                .unwrap();
+        }
+    }
+    println!("All {} batched Timeouts fired", fired);
+}
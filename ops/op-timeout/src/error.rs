@@ -0,0 +1,33 @@
+//! Timeout op Errors
+
+use core::fmt;
+use core::fmt::Display;
+
+use io_uring_opcode::OpError;
+
+/// Timeout Errors
+#[derive(Debug)]
+pub enum TimeoutError {
+    /// Multishot Timeout must be relative, kernel rejects absolute ones
+    MultishotAbsolute,
+}
+
+impl Display for TimeoutError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MultishotAbsolute => write!(f, "Multishot Timeout cannot be absolute"),
+        }
+    }
+}
+
+impl From<TimeoutError> for OpError {
+    fn from(e: TimeoutError) -> OpError {
+        match e {
+            TimeoutError::MultishotAbsolute => {
+                OpError::InvalidParameter("Multishot Timeout cannot be absolute")
+            }
+        }
+    }
+}
+
+impl std::error::Error for TimeoutError {}
@@ -0,0 +1,27 @@
+#![warn(
+    clippy::unwrap_used,
+    missing_docs,
+    rust_2018_idioms,
+    unused_lifetimes,
+    unused_qualifications
+)]
+#![doc = include_str!("../README.md")]
+
+//-----------------------------------------------
+// All Errors
+//-----------------------------------------------
+mod error;
+#[doc(inline)]
+pub use error::*;
+
+//-----------------------------------------------
+// Timeout Record Types
+//-----------------------------------------------
+mod timeout;
+pub use timeout::{Timeout, TimeoutClock, TimeoutFired};
+
+//-----------------------------------------------
+// LinkTimeout Record Types
+//-----------------------------------------------
+mod link_timeout;
+pub use link_timeout::{LinkTimeout, LinkTimeoutFired};
@@ -0,0 +1,98 @@
+//! LinkTimeout Record
+
+use crate::TimeoutClock;
+
+use core::time::Duration;
+
+use io_uring::types::{TimeoutFlags, Timespec};
+use io_uring_opcode::OpExtTimeout;
+use io_uring_opcode::{OpCode, OpCompletion, OpError};
+use io_uring_owner::Owner;
+
+/// How the LinkTimeout completed
+#[derive(Clone, Debug, PartialEq)]
+pub enum LinkTimeoutFired {
+    /// The time expired and the linked submission was canceled
+    Expired,
+    /// The linked submission completed in time and the LinkTimeout was disarmed
+    Disarmed,
+}
+
+/// LinkTimeout Record, must follow a submission pushed with SubmissionFlags::on_io_link
+#[derive(Clone, Debug)]
+pub struct LinkTimeout {
+    /// Current owner of the record
+    owner: Owner,
+    duration: Duration,
+    /// Kernel refers to this within the slab record
+    ts: Timespec,
+    flags: TimeoutFlags,
+}
+
+impl LinkTimeout {
+    /// Construct a LinkTimeout expiring after the given duration
+    pub fn relative(duration: Duration) -> Self {
+        LinkTimeout {
+            owner: Owner::Created,
+            duration,
+            ts: Timespec::from(duration),
+            flags: TimeoutFlags::empty(),
+        }
+    }
+    /// Construct a LinkTimeout expiring at the given duration since the epoch of the clock
+    pub fn absolute(since_epoch: Duration, clock: TimeoutClock) -> Self {
+        LinkTimeout {
+            owner: Owner::Created,
+            duration: since_epoch,
+            ts: Timespec::from(since_epoch),
+            flags: clock.as_timeout_flags(),
+        }
+    }
+}
+
+impl OpCompletion for LinkTimeout {
+    type Error = OpError;
+    type Output = LinkTimeoutFired;
+    fn entry(&self) -> io_uring::squeue::Entry {
+        io_uring::opcode::LinkTimeout::new(&self.ts as *const Timespec)
+            .flags(self.flags)
+            .build()
+    }
+    fn opcode(&self) -> u8 {
+        io_uring::opcode::LinkTimeout::CODE
+    }
+    fn owner(&self) -> Owner {
+        self.owner.clone()
+    }
+    fn force_owner_kernel(&mut self) -> bool {
+        self.owner = Owner::Kernel;
+        true
+    }
+    /// Expiry is signalled by -ETIME whilst -ECANCELED means the linked submission won.
+    fn completion(&mut self, cqe: &io_uring::cqueue::Entry) -> Result<LinkTimeoutFired, OpError> {
+        self.owner = Owner::Returned;
+        match cqe.result() {
+            res if res == -libc::ETIME => Ok(LinkTimeoutFired::Expired),
+            res if res == -libc::ECANCELED => Ok(LinkTimeoutFired::Disarmed),
+            res if res < 0 => Err(OpError::from_cqe_result("LinkTimeout", res)),
+            _ => Ok(LinkTimeoutFired::Disarmed),
+        }
+    }
+}
+
+impl OpCode<LinkTimeout> for LinkTimeout {
+    fn submission(self) -> Result<LinkTimeout, OpError> {
+        Ok(self)
+    }
+}
+
+impl OpExtTimeout for LinkTimeout {
+    /// Underlying duration
+    fn duration(&self) -> Duration {
+        self.duration
+    }
+    /// LinkTimeout always follows a linked submission
+    fn is_linked(&self) -> bool {
+        true
+    }
+}
@@ -0,0 +1,170 @@
+//! Timeout Record
+
+use crate::error::TimeoutError;
+
+use core::time::Duration;
+
+use io_uring::types::{TimeoutFlags, Timespec};
+use io_uring_opcode::OpExtTimeout;
+use io_uring_opcode::{OpCode, OpCompletion, OpError};
+use io_uring_owner::Owner;
+
+/// Clock used to interpret an absolute timeout
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TimeoutClock {
+    /// CLOCK_MONOTONIC, the default
+    Monotonic,
+    /// CLOCK_BOOTTIME
+    Boottime,
+    /// CLOCK_REALTIME
+    Realtime,
+}
+
+impl TimeoutClock {
+    #[inline]
+    pub(crate) fn as_timeout_flags(&self) -> TimeoutFlags {
+        match self {
+            Self::Monotonic => TimeoutFlags::ABS,
+            Self::Boottime => TimeoutFlags::ABS | TimeoutFlags::BOOTTIME,
+            Self::Realtime => TimeoutFlags::ABS | TimeoutFlags::REALTIME,
+        }
+    }
+}
+
+/// Why the Timeout completed
+#[derive(Clone, Debug, PartialEq)]
+pub enum TimeoutFired {
+    /// The time expired. Multishot Timeout remains armed when more is set.
+    Expired {
+        /// Whether the multishot Timeout remains armed for more completions
+        more: bool,
+    },
+    /// The given count of other completions were posted before the time expired
+    Count,
+}
+
+/// Timeout Record
+#[derive(Clone, Debug)]
+pub struct Timeout {
+    /// Current owner of the record
+    owner: Owner,
+    duration: Duration,
+    /// Kernel refers to this within the slab record
+    ts: Timespec,
+    count: u32,
+    flags: TimeoutFlags,
+}
+
+impl Timeout {
+    /// Construct a Timeout expiring after the given duration
+    pub fn relative(duration: Duration) -> Self {
+        Timeout {
+            owner: Owner::Created,
+            duration,
+            ts: Timespec::from(duration),
+            count: 0,
+            flags: TimeoutFlags::empty(),
+        }
+    }
+    /// Construct a Timeout expiring at the given duration since the epoch of the clock
+    pub fn absolute(since_epoch: Duration, clock: TimeoutClock) -> Self {
+        Timeout {
+            owner: Owner::Created,
+            duration: since_epoch,
+            ts: Timespec::from(since_epoch),
+            count: 0,
+            flags: clock.as_timeout_flags(),
+        }
+    }
+    /// Complete also after the given count of other completions were posted
+    pub fn count(mut self, count: u32) -> Self {
+        self.count = count;
+        self
+    }
+    /// Repeat the relative Timeout given times or indefinitely with zero until canceled
+    pub fn multishot(mut self, repeats: u32) -> Result<Self, TimeoutError> {
+        if self.flags.contains(TimeoutFlags::ABS) {
+            return Err(TimeoutError::MultishotAbsolute);
+        }
+        self.flags |= TimeoutFlags::MULTISHOT;
+        self.count = repeats;
+        Ok(self)
+    }
+    /// Whether the Timeout repeats
+    #[inline]
+    pub fn is_multishot(&self) -> bool {
+        self.flags.contains(TimeoutFlags::MULTISHOT)
+    }
+}
+
+impl OpCompletion for Timeout {
+    type Error = OpError;
+    type Output = TimeoutFired;
+    fn entry(&self) -> io_uring::squeue::Entry {
+        io_uring::opcode::Timeout::new(&self.ts as *const Timespec)
+            .count(self.count)
+            .flags(self.flags)
+            .build()
+    }
+    fn opcode(&self) -> u8 {
+        io_uring::opcode::Timeout::CODE
+    }
+    fn owner(&self) -> Owner {
+        self.owner.clone()
+    }
+    fn force_owner_kernel(&mut self) -> bool {
+        self.owner = Owner::Kernel;
+        true
+    }
+    fn is_multishot(&self) -> bool {
+        Timeout::is_multishot(self)
+    }
+    /// Expiry is signalled by -ETIME whilst reaching the count completes with zero.
+    fn completion(&mut self, cqe: &io_uring::cqueue::Entry) -> Result<TimeoutFired, OpError> {
+        let more = io_uring::cqueue::more(cqe.flags());
+        if !more {
+            self.owner = Owner::Returned;
+        }
+        match cqe.result() {
+            res if res == -libc::ETIME => Ok(TimeoutFired::Expired { more }),
+            res if res < 0 => Err(OpError::from_cqe_result("Timeout", res)),
+            _ => Ok(TimeoutFired::Count),
+        }
+    }
+}
+
+impl OpCode<Timeout> for Timeout {
+    fn submission(self) -> Result<Timeout, OpError> {
+        Ok(self)
+    }
+}
+
+impl OpExtTimeout for Timeout {
+    /// Underlying duration
+    fn duration(&self) -> Duration {
+        self.duration
+    }
+    /// Timeout is standalone
+    fn is_linked(&self) -> bool {
+        false
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn multishot_absolute_rejected() {
+        let abs = Timeout::absolute(Duration::from_secs(1), TimeoutClock::Realtime);
+        assert!(matches!(
+            abs.multishot(0),
+            Err(TimeoutError::MultishotAbsolute)
+        ));
+        let rel = Timeout::relative(Duration::from_millis(10))
+            .multishot(3)
+            .expect("relative multishot");
+        assert!(rel.is_multishot());
+        assert_eq!(rel.count, 3);
+    }
+}